@@ -0,0 +1,23 @@
+-- Add no-show detection and auto-release of unclaimed reservations
+-- A reservation that is not picked up within the guild's grace period after START
+-- is marked 'NoShow' and its slot is released.
+
+-- Grace period in minutes after reservation start (0 disables no-show detection).
+-- Off by default so existing guilds keep their reservations until an admin opts in.
+ALTER TABLE guilds ADD COLUMN no_show_grace_minutes INTEGER DEFAULT 0;
+
+-- Explicit pickup timestamp; NULL means the equipment has not been picked up yet
+ALTER TABLE reservations ADD COLUMN picked_up_at DATETIME;
+
+-- Per-user no-show counters for admin review
+CREATE TABLE user_no_show_counts (
+    guild_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    no_show_count INTEGER NOT NULL DEFAULT 0,
+    last_no_show_at DATETIME,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (guild_id, user_id),
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_user_no_show_counts_guild_count ON user_no_show_counts (guild_id, no_show_count DESC);
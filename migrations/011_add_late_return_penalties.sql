@@ -0,0 +1,36 @@
+-- Add late-return penalty points and temporary booking suspension
+-- Late returns add points; crossing the guild threshold suspends new reservations for N days.
+
+-- Penalty policy per guild (0 disables automatic suspension). Off by default so
+-- existing guilds only start suspending members once an admin opts in.
+ALTER TABLE guilds ADD COLUMN penalty_threshold_points INTEGER DEFAULT 0;
+ALTER TABLE guilds ADD COLUMN penalty_suspension_days INTEGER DEFAULT 0;
+
+-- Current penalty state per member
+CREATE TABLE user_penalties (
+    guild_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    points INTEGER NOT NULL DEFAULT 0,
+    suspended_until DATETIME, -- NULL when not suspended
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (guild_id, user_id),
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE
+);
+
+-- History of point changes (late returns and admin adjustments)
+CREATE TABLE penalty_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    reservation_id INTEGER, -- Set for late returns
+    points_delta INTEGER NOT NULL,
+    reason TEXT NOT NULL, -- LateReturn, Adjusted, Forgiven, Suspended
+    actor_user_id INTEGER, -- Admin who made the change, NULL for automatic events
+    notes TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE,
+    FOREIGN KEY (reservation_id) REFERENCES reservations (id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_user_penalties_guild_points ON user_penalties (guild_id, points DESC);
+CREATE INDEX idx_penalty_events_guild_user ON penalty_events (guild_id, user_id, created_at);
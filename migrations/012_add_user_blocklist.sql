@@ -0,0 +1,35 @@
+-- Add per-guild reservation blocklist
+-- A block stops a member from reserving or receiving transfers, either for all
+-- equipment or for a single tag, until it expires or is lifted.
+
+CREATE TABLE user_blocks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    tag_id INTEGER, -- NULL blocks all equipment
+    reason TEXT,
+    expires_at DATETIME, -- NULL means no expiry
+    created_by_user_id INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    lifted_at DATETIME,
+    lifted_by_user_id INTEGER,
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tags (id) ON DELETE CASCADE
+);
+
+-- Audit trail for blocklist changes
+CREATE TABLE user_block_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    block_id INTEGER NOT NULL,
+    guild_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    action TEXT NOT NULL, -- Blocked, Lifted
+    actor_user_id INTEGER NOT NULL,
+    notes TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (block_id) REFERENCES user_blocks (id) ON DELETE CASCADE,
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_user_blocks_guild_user ON user_blocks (guild_id, user_id, lifted_at);
+CREATE INDEX idx_user_block_events_guild ON user_block_events (guild_id, created_at);
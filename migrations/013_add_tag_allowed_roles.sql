@@ -0,0 +1,4 @@
+-- Add per-tag role restrictions on who can reserve
+-- NULL or an empty array means every member may reserve equipment under the tag.
+
+ALTER TABLE tags ADD COLUMN allowed_roles TEXT; -- JSON array of role IDs
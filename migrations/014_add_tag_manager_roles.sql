@@ -0,0 +1,5 @@
+-- Add delegated equipment managers per tag
+-- Members with one of these roles can open equipment settings, cancel reservations,
+-- and view logs for equipment under the tag, without full admin rights.
+
+ALTER TABLE tags ADD COLUMN manager_roles TEXT; -- JSON array of role IDs
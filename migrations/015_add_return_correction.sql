@@ -0,0 +1,4 @@
+-- Track who returned a reservation so only that member can correct the return
+-- within the correction window (cancel the return or fix the return location).
+
+ALTER TABLE reservations ADD COLUMN returned_by_user_id INTEGER;
@@ -0,0 +1,11 @@
+-- Restricted return locations
+-- Returns to a restricted location are recorded but stay flagged until an admin
+-- acknowledges them; the acknowledgment is kept on the reservation and in equipment_logs.
+
+ALTER TABLE locations ADD COLUMN is_restricted BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE reservations ADD COLUMN return_ack_required BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE reservations ADD COLUMN return_acknowledged_by_user_id INTEGER;
+ALTER TABLE reservations ADD COLUMN return_acknowledged_at DATETIME;
+
+CREATE INDEX idx_reservations_return_ack ON reservations (equipment_id, return_ack_required);
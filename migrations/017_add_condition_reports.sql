@@ -0,0 +1,25 @@
+-- Condition reports filed on return
+-- Every return records a condition rating (1-5) and an optional damage note. Damage reports
+-- accept photos sent to the bot by DM for a short time after the return.
+
+CREATE TABLE condition_reports (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    reservation_id INTEGER NOT NULL,
+    equipment_id INTEGER NOT NULL,
+    guild_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    rating INTEGER NOT NULL CHECK (rating BETWEEN 1 AND 5),
+    damage_note TEXT,
+    image_urls TEXT, -- JSON array of attachment URLs
+    photos_accepted_until DATETIME, -- NULL when the report does not take photos
+    alert_channel_id INTEGER,
+    alert_message_id INTEGER,
+    marked_unavailable BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (reservation_id) REFERENCES reservations (id) ON DELETE CASCADE,
+    FOREIGN KEY (equipment_id) REFERENCES equipment (id) ON DELETE CASCADE,
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_condition_reports_equipment ON condition_reports (equipment_id, created_at DESC);
+CREATE INDEX idx_condition_reports_user_photos ON condition_reports (user_id, photos_accepted_until);
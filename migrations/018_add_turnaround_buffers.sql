@@ -0,0 +1,9 @@
+-- Add turnaround (prep) time between reservations
+-- Tags carry the default buffer before and after each reservation. Equipment can override
+-- either side; NULL means the equipment uses its tag's value.
+
+ALTER TABLE tags ADD COLUMN buffer_before_minutes INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE tags ADD COLUMN buffer_after_minutes INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE equipment ADD COLUMN buffer_before_minutes INTEGER;
+ALTER TABLE equipment ADD COLUMN buffer_after_minutes INTEGER;
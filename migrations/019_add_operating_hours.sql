@@ -0,0 +1,25 @@
+-- Add per-guild opening hours and closed dates
+-- A guild without opening hours is always open. Once hours are set, weekdays without a
+-- row are closed. Times are minutes from local midnight; close_minute 1440 means 24:00.
+
+CREATE TABLE operating_hours (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id INTEGER NOT NULL,
+    weekday INTEGER NOT NULL CHECK (weekday BETWEEN 0 AND 6), -- 0 = Monday
+    open_minute INTEGER NOT NULL CHECK (open_minute BETWEEN 0 AND 1439),
+    close_minute INTEGER NOT NULL CHECK (close_minute BETWEEN 1 AND 1440),
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE
+);
+
+-- Dates the clubroom is closed, e.g. university holidays
+CREATE TABLE closed_dates (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id INTEGER NOT NULL,
+    date DATE NOT NULL,
+    reason TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (guild_id, date),
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_operating_hours_guild ON operating_hours (guild_id, weekday);
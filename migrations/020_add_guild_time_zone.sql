@@ -0,0 +1,4 @@
+-- Add a per-guild time zone for displaying and entering reservation times
+-- IANA names such as 'Asia/Tokyo' or 'Europe/Paris'; existing guilds keep JST.
+
+ALTER TABLE guilds ADD COLUMN time_zone TEXT NOT NULL DEFAULT 'Asia/Tokyo';
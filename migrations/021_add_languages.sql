@@ -0,0 +1,13 @@
+-- Add a guild default language and per-member language overrides
+-- Codes are 'ja' or 'en'. Members without a preference see the guild default.
+
+ALTER TABLE guilds ADD COLUMN language TEXT NOT NULL DEFAULT 'ja';
+
+CREATE TABLE user_preferences (
+    guild_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    language TEXT,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (guild_id, user_id),
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE
+);
@@ -0,0 +1,3 @@
+-- Optionally show a weekly timeline image per tag under the header message
+
+ALTER TABLE guilds ADD COLUMN header_timeline BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,3 @@
+-- Hash of the rendered embed and components, so unchanged messages are not edited again
+
+ALTER TABLE managed_messages ADD COLUMN content_hash TEXT;
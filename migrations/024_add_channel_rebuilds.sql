@@ -0,0 +1,18 @@
+-- Log of full teardowns and rebuilds of a reservation channel
+-- triggered_by is the admin who pressed "Rebuild Channel"; NULL when the bot found the
+-- channel out of sync with managed_messages by itself.
+
+CREATE TABLE channel_rebuilds (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id INTEGER NOT NULL,
+    channel_id INTEGER NOT NULL,
+    triggered_by INTEGER,
+    missing_count INTEGER NOT NULL DEFAULT 0, -- Tracked messages no longer in the channel
+    foreign_count INTEGER NOT NULL DEFAULT 0, -- Bot messages the database did not know
+    deleted_count INTEGER NOT NULL DEFAULT 0,
+    created_count INTEGER NOT NULL DEFAULT 0,
+    rebuilt_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (guild_id) REFERENCES guilds (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_channel_rebuilds_guild ON channel_rebuilds (guild_id, rebuilt_at);
@@ -0,0 +1,269 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use sqlx::SqlitePool;
+use tracing::info;
+
+use crate::i18n::{t, tf, Language};
+use crate::models::UserBlock;
+
+/// Human-readable scope of a block ("all equipment" or the tag name)
+pub fn block_scope_label(block: &UserBlock, lang: Language) -> String {
+    match (&block.tag_id, &block.tag_name) {
+        (None, _) => t(lang, "block.scope_all").to_string(),
+        (Some(_), Some(name)) => tf(lang, "block.scope_tag", &[("tag", name)]),
+        (Some(tag_id), None) => tf(lang, "block.scope_tag_id", &[("tag_id", tag_id)]),
+    }
+}
+
+/// Message shown to a blocked member, with the expiry in the guild's time zone
+pub fn blocked_message(block: &UserBlock, tz: Tz, lang: Language) -> String {
+    let until = block
+        .expires_at
+        .map(|dt| {
+            let time = crate::time::utc_to_local_string(dt, tz);
+            tf(lang, "block.until", &[("time", &time)])
+        })
+        .unwrap_or_default();
+    tf(
+        lang,
+        "block.message",
+        &[
+            ("scope", &block_scope_label(block, lang)),
+            ("until", &until),
+        ],
+    )
+}
+
+/// Per-guild reservation blocklist
+pub struct BlocklistService {
+    db: SqlitePool,
+}
+
+impl BlocklistService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// Find an active block that applies to a member for the given tag.
+    /// Guild-wide blocks (no tag) always apply; tag blocks apply only to that tag.
+    pub async fn find_active_block(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+        tag_id: Option<i64>,
+    ) -> Result<Option<UserBlock>> {
+        let now = Utc::now();
+        let row = sqlx::query!(
+            "SELECT b.id as \"id!\", b.tag_id, t.name as \"tag_name?\", b.reason, b.expires_at,
+                    b.created_by_user_id, b.created_at
+             FROM user_blocks b
+             LEFT JOIN tags t ON b.tag_id = t.id
+             WHERE b.guild_id = ? AND b.user_id = ? AND b.lifted_at IS NULL
+               AND (b.expires_at IS NULL OR b.expires_at > ?)
+               AND (b.tag_id IS NULL OR b.tag_id = ?)
+             ORDER BY b.tag_id IS NOT NULL, b.created_at DESC
+             LIMIT 1",
+            guild_id,
+            user_id,
+            now,
+            tag_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(row.map(|row| UserBlock {
+            id: row.id,
+            guild_id,
+            user_id,
+            tag_id: row.tag_id,
+            tag_name: row.tag_name,
+            reason: row.reason,
+            expires_at: row.expires_at.map(crate::time::naive_to_utc),
+            created_by_user_id: row.created_by_user_id,
+            created_at: crate::time::naive_to_utc(row.created_at),
+        }))
+    }
+
+    /// Find an active block that stops a member from using a specific equipment
+    pub async fn find_active_block_for_equipment(
+        &self,
+        user_id: i64,
+        equipment_id: i64,
+    ) -> Result<Option<UserBlock>> {
+        let equipment = sqlx::query!(
+            "SELECT guild_id, tag_id FROM equipment WHERE id = ?",
+            equipment_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        match equipment {
+            Some(equipment) => {
+                self.find_active_block(equipment.guild_id, user_id, equipment.tag_id)
+                    .await
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Find an active block that stops a member from holding a specific reservation
+    pub async fn find_active_block_for_reservation(
+        &self,
+        user_id: i64,
+        reservation_id: i64,
+    ) -> Result<Option<UserBlock>> {
+        let equipment_id = sqlx::query_scalar!(
+            "SELECT equipment_id FROM reservations WHERE id = ?",
+            reservation_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        match equipment_id {
+            Some(equipment_id) => {
+                self.find_active_block_for_equipment(user_id, equipment_id)
+                    .await
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Block a member; returns the new block ID
+    pub async fn block_user(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+        tag_id: Option<i64>,
+        reason: Option<String>,
+        expires_at: Option<DateTime<Utc>>,
+        actor_user_id: i64,
+    ) -> Result<i64> {
+        let now = Utc::now();
+        let mut tx = self.db.begin().await?;
+
+        let block_id = sqlx::query!(
+            "INSERT INTO user_blocks (guild_id, user_id, tag_id, reason, expires_at, created_by_user_id, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            guild_id,
+            user_id,
+            tag_id,
+            reason,
+            expires_at,
+            actor_user_id,
+            now
+        )
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+
+        sqlx::query!(
+            "INSERT INTO user_block_events (block_id, guild_id, user_id, action, actor_user_id, notes, created_at)
+             VALUES (?, ?, ?, 'Blocked', ?, ?, ?)",
+            block_id,
+            guild_id,
+            user_id,
+            actor_user_id,
+            reason,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "User {} blocked in guild {} (tag {:?}) by {}",
+            user_id, guild_id, tag_id, actor_user_id
+        );
+        Ok(block_id)
+    }
+
+    /// Lift an active block; returns false if it was already lifted or does not exist
+    pub async fn lift_block(
+        &self,
+        guild_id: i64,
+        block_id: i64,
+        actor_user_id: i64,
+    ) -> Result<bool> {
+        let now = Utc::now();
+        let mut tx = self.db.begin().await?;
+
+        let block = sqlx::query!(
+            "SELECT user_id FROM user_blocks WHERE id = ? AND guild_id = ? AND lifted_at IS NULL",
+            block_id,
+            guild_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(block) = block else {
+            tx.rollback().await?;
+            return Ok(false);
+        };
+
+        sqlx::query!(
+            "UPDATE user_blocks SET lifted_at = ?, lifted_by_user_id = ? WHERE id = ?",
+            now,
+            actor_user_id,
+            block_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO user_block_events (block_id, guild_id, user_id, action, actor_user_id, notes, created_at)
+             VALUES (?, ?, ?, 'Lifted', ?, NULL, ?)",
+            block_id,
+            guild_id,
+            block.user_id,
+            actor_user_id,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Block {} for user {} in guild {} lifted by {}",
+            block_id, block.user_id, guild_id, actor_user_id
+        );
+        Ok(true)
+    }
+
+    /// Active blocks in a guild, newest first
+    pub async fn list_active_blocks(&self, guild_id: i64, limit: i64) -> Result<Vec<UserBlock>> {
+        let now = Utc::now();
+        let rows = sqlx::query!(
+            "SELECT b.id as \"id!\", b.user_id, b.tag_id, t.name as \"tag_name?\", b.reason, b.expires_at,
+                    b.created_by_user_id, b.created_at
+             FROM user_blocks b
+             LEFT JOIN tags t ON b.tag_id = t.id
+             WHERE b.guild_id = ? AND b.lifted_at IS NULL
+               AND (b.expires_at IS NULL OR b.expires_at > ?)
+             ORDER BY b.created_at DESC
+             LIMIT ?",
+            guild_id,
+            now,
+            limit
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| UserBlock {
+                id: row.id,
+                guild_id,
+                user_id: row.user_id,
+                tag_id: row.tag_id,
+                tag_name: row.tag_name,
+                reason: row.reason,
+                expires_at: row.expires_at.map(crate::time::naive_to_utc),
+                created_by_user_id: row.created_by_user_id,
+                created_at: crate::time::naive_to_utc(row.created_at),
+            })
+            .collect())
+    }
+}
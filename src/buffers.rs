@@ -0,0 +1,194 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use tracing::info;
+
+use crate::constants::Constants;
+use crate::i18n::{t, tf, Language};
+
+/// Parse a buffer length in minutes entered in a modal
+pub fn parse_buffer_minutes(input: &str) -> Option<i64> {
+    let minutes = input.trim().parse::<i64>().ok()?;
+    (0..=Constants::MAX_BUFFER_MINUTES)
+        .contains(&minutes)
+        .then_some(minutes)
+}
+
+/// Prep time kept free before and after every reservation of an equipment
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TurnaroundBuffer {
+    pub before_minutes: i64,
+    pub after_minutes: i64,
+}
+
+impl TurnaroundBuffer {
+    pub fn new(before_minutes: i64, after_minutes: i64) -> Self {
+        Self {
+            before_minutes,
+            after_minutes,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.before_minutes == 0 && self.after_minutes == 0
+    }
+
+    /// Minimum gap between the end of one reservation and the start of the next
+    pub fn gap(&self) -> Duration {
+        Duration::minutes(self.before_minutes + self.after_minutes)
+    }
+
+    /// Range to search for conflicting reservations of a new [start, end) reservation
+    pub fn conflict_window(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> (DateTime<Utc>, DateTime<Utc>) {
+        (start_time - self.gap(), end_time + self.gap())
+    }
+
+    /// Time the equipment is held for a reservation, prep time included
+    pub fn blocked_range(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> (DateTime<Utc>, DateTime<Utc>) {
+        (
+            start_time - Duration::minutes(self.before_minutes),
+            end_time + Duration::minutes(self.after_minutes),
+        )
+    }
+
+    /// Human readable prep time, e.g. "15 min before, 30 min after"
+    pub fn describe(&self, lang: Language) -> String {
+        match (self.before_minutes, self.after_minutes) {
+            (0, 0) => t(lang, "buffer.none").to_string(),
+            (before, 0) => tf(lang, "buffer.before", &[("before", &before)]),
+            (0, after) => tf(lang, "buffer.after", &[("after", &after)]),
+            (before, after) => tf(
+                lang,
+                "buffer.both",
+                &[("before", &before), ("after", &after)],
+            ),
+        }
+    }
+
+    /// Explanation for a request that only conflicts because of prep time
+    pub fn conflict_message(&self, lang: Language) -> String {
+        tf(
+            lang,
+            "buffer.conflict",
+            &[
+                ("minutes", &self.gap().num_minutes()),
+                ("buffer", &self.describe(lang)),
+            ],
+        )
+    }
+}
+
+/// Whether two reservations overlap or are closer than the buffer allows
+pub fn conflicts_with_buffer(
+    buffer: &TurnaroundBuffer,
+    existing_start: DateTime<Utc>,
+    existing_end: DateTime<Utc>,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+) -> bool {
+    let (window_start, window_end) = buffer.conflict_window(start_time, end_time);
+    existing_start < window_end && existing_end > window_start
+}
+
+/// Message for a request that conflicts with the `existing` (start, end) reservations.
+/// Requests that only fall inside the prep time get an explanation of the buffer.
+pub fn reservation_conflict_message(
+    buffer: &TurnaroundBuffer,
+    existing: &[(DateTime<Utc>, DateTime<Utc>)],
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    lang: Language,
+) -> String {
+    let overlaps = existing.iter().any(|(existing_start, existing_end)| {
+        *existing_start < end_time && *existing_end > start_time
+    });
+    if overlaps || buffer.is_zero() {
+        t(lang, "reservation.slot_taken").to_string()
+    } else {
+        buffer.conflict_message(lang)
+    }
+}
+
+/// Turnaround buffers configured per tag and per equipment
+pub struct BufferService {
+    db: SqlitePool,
+}
+
+impl BufferService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// Effective buffer of an equipment: its own values, falling back to its tag's
+    pub async fn buffer_for_equipment(&self, equipment_id: i64) -> Result<TurnaroundBuffer> {
+        let row = sqlx::query!(
+            "SELECT COALESCE(e.buffer_before_minutes, t.buffer_before_minutes, 0) as \"before_minutes!: i64\",
+                    COALESCE(e.buffer_after_minutes, t.buffer_after_minutes, 0) as \"after_minutes!: i64\"
+             FROM equipment e
+             LEFT JOIN tags t ON e.tag_id = t.id
+             WHERE e.id = ?",
+            equipment_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(row
+            .map(|row| TurnaroundBuffer::new(row.before_minutes, row.after_minutes))
+            .unwrap_or_default())
+    }
+
+    /// Override the buffer of an equipment; None falls back to the tag's value
+    pub async fn set_equipment_buffer(
+        &self,
+        equipment_id: i64,
+        before_minutes: Option<i64>,
+        after_minutes: Option<i64>,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE equipment SET buffer_before_minutes = ?, buffer_after_minutes = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?",
+            before_minutes,
+            after_minutes,
+            equipment_id
+        )
+        .execute(&self.db)
+        .await?;
+
+        info!(
+            "Set buffer of equipment {} to {:?} min before, {:?} min after",
+            equipment_id, before_minutes, after_minutes
+        );
+        Ok(())
+    }
+
+    /// Set the default buffer for equipment under a tag
+    pub async fn set_tag_buffer(
+        &self,
+        tag_id: i64,
+        before_minutes: i64,
+        after_minutes: i64,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE tags SET buffer_before_minutes = ?, buffer_after_minutes = ? WHERE id = ?",
+            before_minutes,
+            after_minutes,
+            tag_id
+        )
+        .execute(&self.db)
+        .await?;
+
+        info!(
+            "Set buffer of tag {} to {} min before, {} min after",
+            tag_id, before_minutes, after_minutes
+        );
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use anyhow::Result;
 use serenity::all::{
-    ButtonStyle, ChannelId, CommandInteraction, ComponentInteraction, ComponentInteractionDataKind,
-    CreateActionRow, CreateButton, CreateCommand, CreateEmbed, CreateInteractionResponse,
+    ButtonStyle, ChannelId, CommandInteraction, CommandOptionType, CommandType,
+    ComponentInteraction, ComponentInteractionDataKind, CreateActionRow, CreateButton,
+    CreateCommand, CreateCommandOption, CreateEmbed, CreateInteractionResponse,
     CreateInteractionResponseMessage, CreateSelectMenu, CreateSelectMenuKind,
     CreateSelectMenuOption, Permissions,
 };
@@ -15,6 +16,7 @@ use tokio::sync::Mutex;
 use tracing::{error, info};
 
 use crate::equipment::EquipmentRenderer;
+use crate::i18n::{self, t, tf, Language};
 use crate::utils;
 
 // In-memory storage for setup wizard state
@@ -30,31 +32,63 @@ struct SetupWizardState {
     pre_end_minutes: i64,
     overdue_repeat_hours: i64,
     overdue_max_count: i64,
+    // IANA time zone name, e.g. "Asia/Tokyo"
+    time_zone: String,
+    // Guild default language
+    language: Language,
 }
 
 lazy_static::lazy_static! {
     static ref SETUP_STATES: Arc<Mutex<HashMap<UserId, SetupWizardState>>> = Arc::new(Mutex::new(HashMap::new()));
 }
 
+/// Language of the member going through a wizard
+async fn member_language(
+    db: &SqlitePool,
+    guild_id: Option<GuildId>,
+    user_id: UserId,
+) -> Result<Language> {
+    i18n::user_language(db, guild_id.map(|id| id.get() as i64), user_id.get() as i64).await
+}
+
+/// Reply that the setup wizard state is gone
+async fn respond_session_expired(
+    ctx: &Context,
+    interaction: &ComponentInteraction,
+    lang: Language,
+) -> Result<()> {
+    let response = CreateInteractionResponse::UpdateMessage(
+        CreateInteractionResponseMessage::new()
+            .content(t(lang, "setup.session_expired"))
+            .embeds(vec![])
+            .components(vec![]),
+    );
+    interaction.create_response(&ctx.http, response).await?;
+    Ok(())
+}
+
 pub struct SetupCommand;
 
 impl SetupCommand {
     pub fn register() -> CreateCommand {
         CreateCommand::new("setup")
-            .description("Set up the equipment lending bot in this channel")
+            .description(t(Language::En, "setup.command_description"))
+            .description_localized("ja", t(Language::Ja, "setup.command_description"))
             .default_member_permissions(Permissions::ADMINISTRATOR)
     }
 
     pub async fn handle(
         ctx: &Context,
         interaction: &CommandInteraction,
-        _db: &SqlitePool,
+        db: &SqlitePool,
     ) -> Result<()> {
+        let lang = member_language(db, interaction.guild_id, interaction.user.id).await?;
+
         // Check if user has admin permissions
         if !utils::is_admin(ctx, interaction.guild_id.unwrap(), interaction.user.id).await? {
             let response = CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::new()
-                    .content("❌ You need administrator permissions to use this command.")
+                    .content(t(lang, "setup.admin_required"))
                     .ephemeral(true),
             );
             interaction.create_response(&ctx.http, response).await?;
@@ -66,9 +100,10 @@ impl SetupCommand {
         if !missing_permissions.is_empty() {
             let response = CreateInteractionResponse::Message(
                 CreateInteractionResponseMessage::new()
-                    .content(format!(
-                        "❌ **Permission Error**\n\nThe bot is missing the following required permissions in this channel:\n• {}\n\nPlease grant these permissions and try again.",
-                        missing_permissions.join("\n• ")
+                    .content(tf(
+                        lang,
+                        "setup.missing_permissions",
+                        &[("permissions", &missing_permissions.join("\n• "))],
                     ))
                     .ephemeral(true),
             );
@@ -78,20 +113,20 @@ impl SetupCommand {
 
         // Create confirmation embed
         let embed = CreateEmbed::new()
-            .title("🔧 Equipment Lending Bot Setup")
-            .description(format!(
-                "Set {} as the reservation channel. Is that okay?\n\n\
-                ⚠️ **Warning**: This will delete all existing messages in this channel except bot-managed messages.",
-                interaction.channel_id.mention()
+            .title(t(lang, "setup.confirm_title"))
+            .description(tf(
+                lang,
+                "setup.confirm_description",
+                &[("channel", &interaction.channel_id.mention())],
             ))
             .color(Colour::BLURPLE);
 
         let buttons = CreateActionRow::Buttons(vec![
             CreateButton::new("setup_confirm")
-                .label("✅ Confirm")
+                .label(t(lang, "setup.confirm_button"))
                 .style(ButtonStyle::Success),
             CreateButton::new("setup_cancel")
-                .label("❌ Cancel")
+                .label(t(lang, "setup.cancel_button"))
                 .style(ButtonStyle::Danger),
         ]);
 
@@ -113,9 +148,10 @@ impl SetupCommand {
         confirmed: bool,
     ) -> Result<()> {
         if !confirmed {
+            let lang = member_language(db, interaction.guild_id, interaction.user.id).await?;
             let response = CreateInteractionResponse::UpdateMessage(
                 CreateInteractionResponseMessage::new()
-                    .content("❌ Setup cancelled.")
+                    .content(t(lang, "setup.cancelled"))
                     .embeds(vec![])
                     .components(vec![]),
             );
@@ -130,11 +166,15 @@ impl SetupCommand {
     async fn show_role_selection_step(
         ctx: &Context,
         interaction: &ComponentInteraction,
-        _db: &SqlitePool,
+        db: &SqlitePool,
     ) -> Result<()> {
         let guild_id = interaction.guild_id.unwrap();
         let channel_id = interaction.channel_id;
         let user_id = interaction.user.id;
+        let lang = member_language(db, Some(guild_id), user_id).await?;
+        // Re-running setup keeps the time zone and language picked last time
+        let time_zone = crate::time::guild_time_zone(db, guild_id.get() as i64).await?;
+        let language = i18n::guild_language(db, guild_id.get() as i64).await?;
 
         // Store wizard state
         {
@@ -152,6 +192,8 @@ impl SetupCommand {
                     pre_end_minutes: 15,
                     overdue_repeat_hours: 12,
                     overdue_max_count: 3,
+                    time_zone: time_zone.name().to_string(),
+                    language,
                 },
             );
         }
@@ -170,8 +212,8 @@ impl SetupCommand {
         };
 
         let embed = CreateEmbed::new()
-            .title("🔧 Setup - Step 1: Admin Roles")
-            .description("Select which roles should have administrative permissions for equipment management.\n\n**Optional**: You can skip this step if you only want Discord administrators to manage equipment.")
+            .title(t(lang, "setup.roles_title"))
+            .description(t(lang, "setup.roles_description"))
             .color(Colour::BLURPLE);
 
         let mut components = vec![];
@@ -184,7 +226,7 @@ impl SetupCommand {
                     default_roles: Some(vec![]),
                 },
             )
-            .placeholder("Select admin roles (optional)")
+            .placeholder(t(lang, "setup.roles_placeholder"))
             .min_values(0)
             .max_values(25);
 
@@ -194,10 +236,10 @@ impl SetupCommand {
         // Buttons row
         let buttons = CreateActionRow::Buttons(vec![
             CreateButton::new("setup_roles_skip")
-                .label("⏭️ Skip")
+                .label(t(lang, "setup.skip_button"))
                 .style(ButtonStyle::Secondary),
             CreateButton::new("setup_roles_next")
-                .label("➡️ Next")
+                .label(t(lang, "setup.next_button"))
                 .style(ButtonStyle::Primary),
         ]);
         components.push(buttons);
@@ -226,6 +268,8 @@ impl SetupCommand {
             return Ok(());
         };
 
+        let lang = i18n::guild_language(db, guild_id).await?;
+
         // Check if there's any equipment in this guild
         let equipment_count: i64 =
             sqlx::query_scalar("SELECT COUNT(*) FROM equipment WHERE guild_id = ?")
@@ -236,12 +280,12 @@ impl SetupCommand {
         if equipment_count == 0 {
             // Post guide message with Overall Management button
             let embed = CreateEmbed::new()
-                .title("📋 Equipment Lending Management")
-                .description("Please register equipment to get started.")
+                .title(t(lang, "setup.guide_title"))
+                .description(t(lang, "setup.guide_description"))
                 .color(Colour::BLUE);
 
             let buttons = CreateActionRow::Buttons(vec![CreateButton::new("overall_mgmt_open")
-                .label("⚙️ Overall Management")
+                .label(t(lang, "setup.overall_management_button"))
                 .style(ButtonStyle::Primary)]);
 
             let message = channel_id
@@ -285,7 +329,7 @@ impl SetupCommand {
     pub async fn handle_role_selection(
         ctx: &Context,
         interaction: &ComponentInteraction,
-        _db: &SqlitePool,
+        db: &SqlitePool,
     ) -> Result<()> {
         let user_id = interaction.user.id;
 
@@ -297,14 +341,8 @@ impl SetupCommand {
                 // For now, just acknowledge the selection
             } else {
                 // State not found, respond with error
-                let response = CreateInteractionResponse::UpdateMessage(
-                    CreateInteractionResponseMessage::new()
-                        .content("❌ Session expired. Please start setup again.")
-                        .embeds(vec![])
-                        .components(vec![]),
-                );
-                interaction.create_response(&ctx.http, response).await?;
-                return Ok(());
+                let lang = member_language(db, interaction.guild_id, interaction.user.id).await?;
+                return respond_session_expired(ctx, interaction, lang).await;
             }
         }
 
@@ -328,9 +366,9 @@ impl SetupCommand {
         }
 
         // Acknowledge the selection with a simple response
+        let lang = member_language(db, interaction.guild_id, user_id).await?;
         let response = CreateInteractionResponse::UpdateMessage(
-            CreateInteractionResponseMessage::new()
-                .content("✅ Roles selected. Click **Next** to continue."),
+            CreateInteractionResponseMessage::new().content(t(lang, "setup.roles_selected")),
         );
         interaction.create_response(&ctx.http, response).await?;
         Ok(())
@@ -356,14 +394,8 @@ impl SetupCommand {
                 (state.clone(), roles)
             } else {
                 // State not found, respond with error
-                let response = CreateInteractionResponse::UpdateMessage(
-                    CreateInteractionResponseMessage::new()
-                        .content("❌ Session expired. Please start setup again.")
-                        .embeds(vec![])
-                        .components(vec![]),
-                );
-                interaction.create_response(&ctx.http, response).await?;
-                return Ok(());
+                let lang = member_language(db, interaction.guild_id, interaction.user.id).await?;
+                return respond_session_expired(ctx, interaction, lang).await;
             }
         };
 
@@ -375,7 +407,7 @@ impl SetupCommand {
     async fn show_notification_preferences_step(
         ctx: &Context,
         interaction: &ComponentInteraction,
-        _db: &SqlitePool,
+        db: &SqlitePool,
         state: &SetupWizardState,
         selected_roles: &[RoleId],
     ) -> Result<()> {
@@ -393,49 +425,55 @@ impl SetupCommand {
             }
         }
 
+        let lang = member_language(db, interaction.guild_id, state.user_id).await?;
         let embed = CreateEmbed::new()
-            .title("🔧 Setup - Step 3: Notification Preferences")
-            .description("Configure reminder notifications for equipment lending:")
+            .title(t(lang, "setup.notifications_title"))
+            .description(t(lang, "setup.notifications_description"))
             .field(
-                "📱 DM Fallback",
-                "When DMs fail, send mentions in the reservation channel",
+                t(lang, "setup.dm_fallback_name"),
+                t(lang, "setup.dm_fallback_value"),
                 false,
             )
             .field(
-                "⏰ Pre-Start Reminder",
-                "Notify users before their reservation starts (default: 15 minutes)",
+                t(lang, "setup.pre_start_name"),
+                t(lang, "setup.pre_start_value"),
                 false,
             )
             .field(
-                "🔔 Pre-End Reminder",
-                "Notify users before their reservation ends (default: 15 minutes)",
+                t(lang, "setup.pre_end_name"),
+                t(lang, "setup.pre_end_value"),
                 false,
             )
             .field(
-                "⚠️ Overdue Reminders",
-                "Repeat notifications for unreturned items (default: every 12 hours, max 3 times)",
+                t(lang, "setup.overdue_name"),
+                t(lang, "setup.overdue_value"),
                 false,
             )
-            .footer(serenity::all::CreateEmbedFooter::new(
-                "Current settings shown are defaults. Adjust settings below and click Next to continue.",
-            ))
+            .footer(serenity::all::CreateEmbedFooter::new(t(
+                lang,
+                "setup.notifications_footer",
+            )))
             .color(Colour::BLURPLE);
 
+        let minutes_option =
+            |minutes: i64| tf(lang, "setup.minutes_option", &[("minutes", &minutes)]);
+        let hours_option = |hours: i64| tf(lang, "setup.hours_option", &[("hours", &hours)]);
+
         // Create separate select menus for each notification type
         let dm_fallback_select = CreateSelectMenu::new(
             "dm_fallback_select",
             CreateSelectMenuKind::String {
                 options: vec![
-                    CreateSelectMenuOption::new("Enabled", "dm_fallback_true")
-                        .description("Send channel mentions when DMs fail")
+                    CreateSelectMenuOption::new(t(lang, "common.enabled"), "dm_fallback_true")
+                        .description(t(lang, "setup.dm_fallback_enabled_description"))
                         .default_selection(state.dm_fallback_enabled),
-                    CreateSelectMenuOption::new("Disabled", "dm_fallback_false")
-                        .description("Only send DMs, no channel fallback")
+                    CreateSelectMenuOption::new(t(lang, "common.disabled"), "dm_fallback_false")
+                        .description(t(lang, "setup.dm_fallback_disabled_description"))
                         .default_selection(!state.dm_fallback_enabled),
                 ],
             },
         )
-        .placeholder("DM Fallback Setting")
+        .placeholder(t(lang, "setup.dm_fallback_placeholder"))
         .min_values(1)
         .max_values(1);
 
@@ -443,16 +481,16 @@ impl SetupCommand {
             "pre_start_select",
             CreateSelectMenuKind::String {
                 options: vec![
-                    CreateSelectMenuOption::new("5 minutes", "pre_start_5")
+                    CreateSelectMenuOption::new(minutes_option(5), "pre_start_5")
                         .default_selection(state.pre_start_minutes == 5),
-                    CreateSelectMenuOption::new("15 minutes", "pre_start_15")
+                    CreateSelectMenuOption::new(minutes_option(15), "pre_start_15")
                         .default_selection(state.pre_start_minutes == 15),
-                    CreateSelectMenuOption::new("30 minutes", "pre_start_30")
+                    CreateSelectMenuOption::new(minutes_option(30), "pre_start_30")
                         .default_selection(state.pre_start_minutes == 30),
                 ],
             },
         )
-        .placeholder("Pre-Start Reminder Time")
+        .placeholder(t(lang, "setup.pre_start_placeholder"))
         .min_values(1)
         .max_values(1);
 
@@ -460,16 +498,16 @@ impl SetupCommand {
             "pre_end_select",
             CreateSelectMenuKind::String {
                 options: vec![
-                    CreateSelectMenuOption::new("5 minutes", "pre_end_5")
+                    CreateSelectMenuOption::new(minutes_option(5), "pre_end_5")
                         .default_selection(state.pre_end_minutes == 5),
-                    CreateSelectMenuOption::new("15 minutes", "pre_end_15")
+                    CreateSelectMenuOption::new(minutes_option(15), "pre_end_15")
                         .default_selection(state.pre_end_minutes == 15),
-                    CreateSelectMenuOption::new("30 minutes", "pre_end_30")
+                    CreateSelectMenuOption::new(minutes_option(30), "pre_end_30")
                         .default_selection(state.pre_end_minutes == 30),
                 ],
             },
         )
-        .placeholder("Pre-End Reminder Time")
+        .placeholder(t(lang, "setup.pre_end_placeholder"))
         .min_values(1)
         .max_values(1);
 
@@ -477,28 +515,28 @@ impl SetupCommand {
             "overdue_select",
             CreateSelectMenuKind::String {
                 options: vec![
-                    CreateSelectMenuOption::new("Every 6 hours", "overdue_6h")
+                    CreateSelectMenuOption::new(hours_option(6), "overdue_6h")
                         .default_selection(state.overdue_repeat_hours == 6),
-                    CreateSelectMenuOption::new("Every 12 hours", "overdue_12h")
+                    CreateSelectMenuOption::new(hours_option(12), "overdue_12h")
                         .default_selection(state.overdue_repeat_hours == 12),
-                    CreateSelectMenuOption::new("Every 24 hours", "overdue_24h")
+                    CreateSelectMenuOption::new(hours_option(24), "overdue_24h")
                         .default_selection(state.overdue_repeat_hours == 24),
                 ],
             },
         )
-        .placeholder("Overdue Reminder Frequency")
+        .placeholder(t(lang, "setup.overdue_placeholder"))
         .min_values(1)
         .max_values(1);
 
         let buttons = CreateActionRow::Buttons(vec![
             CreateButton::new("setup_notification_back")
-                .label("⬅️ Back")
+                .label(t(lang, "setup.back_button"))
                 .style(ButtonStyle::Secondary),
             CreateButton::new("notification_next")
-                .label("➡️ Next")
+                .label(t(lang, "setup.next_button"))
                 .style(ButtonStyle::Primary),
             CreateButton::new("setup_cancel")
-                .label("❌ Cancel")
+                .label(t(lang, "setup.cancel_button"))
                 .style(ButtonStyle::Danger),
         ]);
 
@@ -523,7 +561,7 @@ impl SetupCommand {
     pub async fn handle_notification_preferences(
         ctx: &Context,
         interaction: &ComponentInteraction,
-        _db: &SqlitePool,
+        db: &SqlitePool,
     ) -> Result<()> {
         let user_id = interaction.user.id;
         let values =
@@ -601,9 +639,9 @@ impl SetupCommand {
         }
 
         // Acknowledge the selection
+        let lang = member_language(db, interaction.guild_id, user_id).await?;
         let response = CreateInteractionResponse::UpdateMessage(
-            CreateInteractionResponseMessage::new()
-                .content("✅ Notification preferences updated. Click **Next** to continue."),
+            CreateInteractionResponseMessage::new().content(t(lang, "setup.notifications_updated")),
         );
         interaction.create_response(&ctx.http, response).await?;
         Ok(())
@@ -623,14 +661,8 @@ impl SetupCommand {
                 (state.clone(), state.selected_roles.clone())
             } else {
                 // State not found, respond with error
-                let response = CreateInteractionResponse::UpdateMessage(
-                    CreateInteractionResponseMessage::new()
-                        .content("❌ Session expired. Please start setup again.")
-                        .embeds(vec![])
-                        .components(vec![]),
-                );
-                interaction.create_response(&ctx.http, response).await?;
-                return Ok(());
+                let lang = member_language(db, interaction.guild_id, interaction.user.id).await?;
+                return respond_session_expired(ctx, interaction, lang).await;
             }
         };
 
@@ -641,12 +673,13 @@ impl SetupCommand {
     async fn show_final_confirmation(
         ctx: &Context,
         interaction: &ComponentInteraction,
-        _db: &SqlitePool,
+        db: &SqlitePool,
         state: &SetupWizardState,
         selected_roles: &[RoleId],
     ) -> Result<()> {
+        let lang = member_language(db, interaction.guild_id, state.user_id).await?;
         let role_mentions = if selected_roles.is_empty() {
-            "None (only Discord administrators)".to_string()
+            t(lang, "setup.no_admin_roles").to_string()
         } else {
             selected_roles
                 .iter()
@@ -655,53 +688,200 @@ impl SetupCommand {
                 .join(", ")
         };
 
-        let notification_summary =
-            format!(
-            "DM Fallback: {}\nPre-Start: {} min\nPre-End: {} min\nOverdue: Every {} hrs (max {})",
-            if state.dm_fallback_enabled { "Enabled" } else { "Disabled" },
-            state.pre_start_minutes,
-            state.pre_end_minutes,
-            state.overdue_repeat_hours,
-            state.overdue_max_count
+        let dm_fallback = if state.dm_fallback_enabled {
+            t(lang, "common.enabled")
+        } else {
+            t(lang, "common.disabled")
+        };
+        let notification_summary = tf(
+            lang,
+            "setup.notification_summary",
+            &[
+                ("dm_fallback", &dm_fallback),
+                ("pre_start", &state.pre_start_minutes),
+                ("pre_end", &state.pre_end_minutes),
+                ("overdue_hours", &state.overdue_repeat_hours),
+                ("overdue_max", &state.overdue_max_count),
+            ],
         );
+        let time_zone = crate::time::time_zone_or_default(Some(&state.time_zone));
 
         let embed = CreateEmbed::new()
-            .title("🔧 Setup - Step 4: Final Confirmation")
-            .description("Please review your configuration:")
+            .title(t(lang, "setup.final_title"))
+            .description(t(lang, "setup.final_description"))
             .field(
-                "Reservation Channel",
+                t(lang, "setup.reservation_channel_name"),
                 state.channel_id.mention().to_string(),
                 false,
             )
-            .field("Admin Roles", role_mentions, false)
-            .field("Notification Settings", notification_summary, false)
-            .footer(serenity::all::CreateEmbedFooter::new(
-                "Click Complete to finish setup or Cancel to abort.",
-            ))
+            .field(t(lang, "setup.admin_roles_name"), role_mentions, false)
+            .field(
+                t(lang, "setup.notification_settings_name"),
+                notification_summary,
+                false,
+            )
+            .field(
+                t(lang, "setup.time_zone_name"),
+                tf(
+                    lang,
+                    "setup.time_zone_value",
+                    &[
+                        ("name", &time_zone.name()),
+                        (
+                            "offset",
+                            &crate::time::offset_string(time_zone, chrono::Utc::now()),
+                        ),
+                    ],
+                ),
+                false,
+            )
+            .field(
+                t(lang, "setup.language_name"),
+                tf(
+                    lang,
+                    "setup.language_value",
+                    &[("language", &state.language.native_name())],
+                ),
+                false,
+            )
+            .footer(serenity::all::CreateEmbedFooter::new(t(
+                lang,
+                "setup.final_footer",
+            )))
             .color(Colour::BLURPLE);
 
+        let time_zone_select = CreateSelectMenu::new(
+            "setup_time_zone_select",
+            CreateSelectMenuKind::String {
+                options: crate::time::COMMON_TIME_ZONES
+                    .iter()
+                    .map(|(name, label)| {
+                        CreateSelectMenuOption::new(*label, *name)
+                            .description(*name)
+                            .default_selection(*name == time_zone.name())
+                    })
+                    .collect(),
+            },
+        )
+        .placeholder(t(lang, "setup.time_zone_placeholder"))
+        .min_values(1)
+        .max_values(1);
+
+        let language_select = CreateSelectMenu::new(
+            "setup_language_select",
+            CreateSelectMenuKind::String {
+                options: Language::ALL
+                    .iter()
+                    .map(|language| {
+                        CreateSelectMenuOption::new(language.native_name(), language.code())
+                            .default_selection(*language == state.language)
+                    })
+                    .collect(),
+            },
+        )
+        .placeholder(t(lang, "setup.language_placeholder"))
+        .min_values(1)
+        .max_values(1);
+
         let buttons = CreateActionRow::Buttons(vec![
             CreateButton::new("setup_final_back")
-                .label("⬅️ Back")
+                .label(t(lang, "setup.back_button"))
                 .style(ButtonStyle::Secondary),
             CreateButton::new("setup_complete")
-                .label("✅ Complete")
+                .label(t(lang, "setup.complete_button"))
                 .style(ButtonStyle::Success),
             CreateButton::new("setup_cancel")
-                .label("❌ Cancel")
+                .label(t(lang, "setup.cancel_button"))
                 .style(ButtonStyle::Danger),
         ]);
 
         let response = CreateInteractionResponse::UpdateMessage(
             CreateInteractionResponseMessage::new()
                 .embed(embed)
-                .components(vec![buttons]),
+                .components(vec![
+                    CreateActionRow::SelectMenu(time_zone_select),
+                    CreateActionRow::SelectMenu(language_select),
+                    buttons,
+                ]),
         );
 
         interaction.create_response(&ctx.http, response).await?;
         Ok(())
     }
 
+    /// Handle the time zone select on the final confirmation step
+    pub async fn handle_time_zone_select(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        db: &SqlitePool,
+    ) -> Result<()> {
+        let user_id = interaction.user.id;
+        let selected = match &interaction.data.kind {
+            ComponentInteractionDataKind::StringSelect { values } => values
+                .first()
+                .filter(|name| crate::time::parse_time_zone(name).is_some())
+                .cloned(),
+            _ => None,
+        };
+
+        let state = {
+            let mut states = SETUP_STATES.lock().await;
+            states.get_mut(&user_id).map(|state| {
+                if let Some(name) = selected {
+                    state.time_zone = name;
+                }
+                state.clone()
+            })
+        };
+
+        match state {
+            Some(state) => {
+                let selected_roles = state.selected_roles.clone();
+                Self::show_final_confirmation(ctx, interaction, db, &state, &selected_roles).await
+            }
+            None => {
+                let lang = member_language(db, interaction.guild_id, interaction.user.id).await?;
+                respond_session_expired(ctx, interaction, lang).await
+            }
+        }
+    }
+
+    /// Handle the language select on the final confirmation step
+    pub async fn handle_language_select(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        db: &SqlitePool,
+    ) -> Result<()> {
+        let user_id = interaction.user.id;
+        let selected = match &interaction.data.kind {
+            ComponentInteractionDataKind::StringSelect { values } => {
+                values.first().and_then(|code| Language::from_code(code))
+            }
+            _ => None,
+        };
+
+        let state = {
+            let mut states = SETUP_STATES.lock().await;
+            states.get_mut(&user_id).map(|state| {
+                if let Some(language) = selected {
+                    state.language = language;
+                }
+                state.clone()
+            })
+        };
+
+        match state {
+            Some(state) => {
+                let selected_roles = state.selected_roles.clone();
+                Self::show_final_confirmation(ctx, interaction, db, &state, &selected_roles).await
+            }
+            None => {
+                let lang = member_language(db, interaction.guild_id, user_id).await?;
+                respond_session_expired(ctx, interaction, lang).await
+            }
+        }
+    }
+
     pub async fn handle_setup_complete(
         ctx: &Context,
         interaction: &ComponentInteraction,
@@ -716,14 +896,8 @@ impl SetupCommand {
                 (state.clone(), state.selected_roles.clone())
             } else {
                 // State not found, respond with error
-                let response = CreateInteractionResponse::UpdateMessage(
-                    CreateInteractionResponseMessage::new()
-                        .content("❌ Session expired. Please start setup again.")
-                        .embeds(vec![])
-                        .components(vec![]),
-                );
-                interaction.create_response(&ctx.http, response).await?;
-                return Ok(());
+                let lang = member_language(db, interaction.guild_id, interaction.user.id).await?;
+                return respond_session_expired(ctx, interaction, lang).await;
             }
         };
 
@@ -741,12 +915,14 @@ impl SetupCommand {
             serde_json::to_string(&role_ids)?
         };
 
+        let language_code = state.language.code();
+
         // Save configuration to database - update existing or insert new
         let result = sqlx::query!(
             "UPDATE guilds SET 
              reservation_channel_id = ?, admin_roles = ?, dm_fallback_channel_enabled = ?,
              pre_start_minutes = ?, pre_end_minutes = ?, overdue_repeat_hours = ?, 
-             overdue_max_count = ?, updated_at = CURRENT_TIMESTAMP
+             overdue_max_count = ?, time_zone = ?, language = ?, updated_at = CURRENT_TIMESTAMP
              WHERE id = ?",
             channel_id_i64,
             admin_roles_json,
@@ -755,6 +931,8 @@ impl SetupCommand {
             state.pre_end_minutes,
             state.overdue_repeat_hours,
             state.overdue_max_count,
+            state.time_zone,
+            language_code,
             guild_id_i64
         )
         .execute(db)
@@ -765,8 +943,9 @@ impl SetupCommand {
             sqlx::query!(
                 "INSERT INTO guilds 
                  (id, reservation_channel_id, admin_roles, dm_fallback_channel_enabled,
-                  pre_start_minutes, pre_end_minutes, overdue_repeat_hours, overdue_max_count)
-                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                  pre_start_minutes, pre_end_minutes, overdue_repeat_hours, overdue_max_count,
+                  time_zone, language)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
                 guild_id_i64,
                 channel_id_i64,
                 admin_roles_json,
@@ -774,52 +953,58 @@ impl SetupCommand {
                 state.pre_start_minutes,
                 state.pre_end_minutes,
                 state.overdue_repeat_hours,
-                state.overdue_max_count
+                state.overdue_max_count,
+                state.time_zone,
+                language_code
             )
             .execute(db)
             .await?;
         }
 
         // Show completion message
+        let lang = member_language(db, Some(state.guild_id), user_id).await?;
         let role_summary = if selected_roles.is_empty() {
-            "Only Discord administrators will have management access.".to_string()
+            t(lang, "setup.complete_roles_none").to_string()
         } else {
-            format!(
-                "Admin roles: {}",
-                selected_roles
-                    .iter()
-                    .map(|role_id| role_id.mention().to_string())
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            )
+            let roles = selected_roles
+                .iter()
+                .map(|role_id| role_id.mention().to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            tf(lang, "setup.complete_roles", &[("roles", &roles)])
         };
 
-        let notification_summary = format!(
-            "DM Fallback: {}, Pre-Start: {}min, Pre-End: {}min, Overdue: Every {}hrs (max {})",
-            if state.dm_fallback_enabled {
-                "✅"
-            } else {
-                "❌"
-            },
-            state.pre_start_minutes,
-            state.pre_end_minutes,
-            state.overdue_repeat_hours,
-            state.overdue_max_count
+        let notification_summary = tf(
+            lang,
+            "setup.complete_notifications",
+            &[
+                (
+                    "dm_fallback",
+                    &if state.dm_fallback_enabled {
+                        "✅"
+                    } else {
+                        "❌"
+                    },
+                ),
+                ("pre_start", &state.pre_start_minutes),
+                ("pre_end", &state.pre_end_minutes),
+                ("overdue_hours", &state.overdue_repeat_hours),
+                ("overdue_max", &state.overdue_max_count),
+            ],
         );
 
         let embed = CreateEmbed::new()
-            .title("✅ Setup Complete!")
-            .description(format!(
-                "Successfully configured {} as the reservation channel.\n\n\
-                {}\n\
-                📱 **Notifications:** {}\n\n\
-                🚀 **Next Steps:**\n\
-                • Use the **Overall Management** button to add equipment\n\
-                • Configure lending/return locations\n\
-                • Set up equipment tags for organization",
-                state.channel_id.mention(),
-                role_summary,
-                notification_summary
+            .title(t(lang, "setup.complete_title"))
+            .description(tf(
+                lang,
+                "setup.complete_description",
+                &[
+                    ("channel", &state.channel_id.mention()),
+                    ("roles", &role_summary),
+                    ("notifications", &notification_summary),
+                    ("time_zone", &state.time_zone),
+                    ("language", &state.language.native_name()),
+                ],
             ))
             .color(Colour::DARK_GREEN);
 
@@ -846,7 +1031,7 @@ impl SetupCommand {
     pub async fn handle_setup_cancel(
         ctx: &Context,
         interaction: &ComponentInteraction,
-        _db: &SqlitePool,
+        db: &SqlitePool,
     ) -> Result<()> {
         let user_id = interaction.user.id;
 
@@ -856,9 +1041,10 @@ impl SetupCommand {
             states.remove(&user_id);
         }
 
+        let lang = member_language(db, interaction.guild_id, user_id).await?;
         let response = CreateInteractionResponse::UpdateMessage(
             CreateInteractionResponseMessage::new()
-                .content("❌ Setup cancelled.")
+                .content(t(lang, "setup.cancelled"))
                 .embeds(vec![])
                 .components(vec![]),
         );
@@ -870,10 +1056,10 @@ impl SetupCommand {
     pub async fn handle_notification_back(
         ctx: &Context,
         interaction: &ComponentInteraction,
-        _db: &SqlitePool,
+        db: &SqlitePool,
     ) -> Result<()> {
         // Go back to role selection step
-        Self::show_role_selection_step(ctx, interaction, _db).await
+        Self::show_role_selection_step(ctx, interaction, db).await
     }
 
     /// Handle back button from final confirmation step to notification preferences step
@@ -891,14 +1077,8 @@ impl SetupCommand {
                 (state.clone(), state.selected_roles.clone())
             } else {
                 // State not found, respond with error
-                let response = CreateInteractionResponse::UpdateMessage(
-                    CreateInteractionResponseMessage::new()
-                        .content("❌ Session expired. Please start setup again.")
-                        .embeds(vec![])
-                        .components(vec![]),
-                );
-                interaction.create_response(&ctx.http, response).await?;
-                return Ok(());
+                let lang = member_language(db, interaction.guild_id, interaction.user.id).await?;
+                return respond_session_expired(ctx, interaction, lang).await;
             }
         };
 
@@ -906,3 +1086,277 @@ impl SetupCommand {
         Self::show_notification_preferences_step(ctx, interaction, db, &state, &selected_roles).await
     }
 }
+
+/// Per-user language preference
+pub struct LanguageCommand;
+
+impl LanguageCommand {
+    pub fn register() -> CreateCommand {
+        let mut option = CreateCommandOption::new(
+            CommandOptionType::String,
+            "language",
+            t(Language::En, "language.option_description"),
+        )
+        .description_localized("ja", t(Language::Ja, "language.option_description"))
+        .required(false);
+        for language in Language::ALL {
+            option = option.add_string_choice(language.native_name(), language.code());
+        }
+        option = option.add_string_choice_localized(
+            t(Language::En, "language.option_default"),
+            "default",
+            [("ja", t(Language::Ja, "language.option_default"))],
+        );
+
+        CreateCommand::new("language")
+            .description(t(Language::En, "language.command_description"))
+            .description_localized("ja", t(Language::Ja, "language.command_description"))
+            .dm_permission(false)
+            .add_option(option)
+    }
+
+    pub async fn handle(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        db: &SqlitePool,
+    ) -> Result<()> {
+        let user_id = interaction.user.id.get() as i64;
+        let guild_id = interaction.guild_id.unwrap().get() as i64;
+        let choice = interaction
+            .data
+            .options
+            .iter()
+            .find(|option| option.name == "language")
+            .and_then(|option| option.value.as_str())
+            .map(|code| (code, Language::from_code(code)));
+
+        let content = match choice {
+            Some(("default", _)) => {
+                i18n::set_user_language(db, guild_id, user_id, None).await?;
+                let lang = i18n::user_language(db, Some(guild_id), user_id).await?;
+                tf(lang, "language.reset", &[("language", &lang.native_name())])
+            }
+            Some((_, Some(lang))) => {
+                i18n::set_user_language(db, guild_id, user_id, Some(lang)).await?;
+                t(lang, "language.set").to_string()
+            }
+            _ => {
+                let preference = i18n::user_preference(db, guild_id, user_id).await?;
+                let lang = i18n::user_language(db, Some(guild_id), user_id).await?;
+                let source = if preference.is_none() {
+                    t(lang, "language.current_default")
+                } else {
+                    ""
+                };
+                tf(
+                    lang,
+                    "language.current",
+                    &[("language", &lang.native_name()), ("source", &source)],
+                )
+            }
+        };
+
+        let response = CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new()
+                .content(content)
+                .ephemeral(true),
+        );
+        interaction.create_response(&ctx.http, response).await?;
+        Ok(())
+    }
+}
+
+/// /reserve: start a reservation without scrolling the reservation channel.
+/// Handled by the reservation wizard in handlers.rs.
+pub struct ReserveCommand;
+
+impl ReserveCommand {
+    pub fn register() -> CreateCommand {
+        CreateCommand::new("reserve")
+            .description(t(Language::En, "reserve_cmd.description"))
+            .description_localized("ja", t(Language::Ja, "reserve_cmd.description"))
+            .dm_permission(false)
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "equipment",
+                    t(Language::En, "reserve_cmd.equipment_option"),
+                )
+                .description_localized("ja", t(Language::Ja, "reserve_cmd.equipment_option"))
+                .required(true)
+                .set_autocomplete(true),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "start",
+                    t(Language::En, "reserve_cmd.start_option"),
+                )
+                .description_localized("ja", t(Language::Ja, "reserve_cmd.start_option"))
+                .required(false),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "end",
+                    t(Language::En, "reserve_cmd.end_option"),
+                )
+                .description_localized("ja", t(Language::Ja, "reserve_cmd.end_option"))
+                .required(false),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "location",
+                    t(Language::En, "reserve_cmd.location_option"),
+                )
+                .description_localized("ja", t(Language::Ja, "reserve_cmd.location_option"))
+                .required(false)
+                .set_autocomplete(true),
+            )
+    }
+}
+
+/// /return: pick one of your active loans and go through the return flow
+pub struct ReturnCommand;
+
+impl ReturnCommand {
+    pub fn register() -> CreateCommand {
+        CreateCommand::new("return")
+            .description(t(Language::En, "return_cmd.description"))
+            .description_localized("ja", t(Language::Ja, "return_cmd.description"))
+            .dm_permission(false)
+    }
+}
+
+/// /my-reservations: your upcoming and active reservations with Edit/Cancel/Transfer buttons
+pub struct MyReservationsCommand;
+
+impl MyReservationsCommand {
+    pub fn register() -> CreateCommand {
+        CreateCommand::new("my-reservations")
+            .description(t(Language::En, "my_reservations.description"))
+            .description_localized("ja", t(Language::Ja, "my_reservations.description"))
+            .dm_permission(false)
+    }
+}
+
+/// /availability: list the earliest free windows of at least a given length
+pub struct AvailabilityCommand;
+
+impl AvailabilityCommand {
+    pub fn register() -> CreateCommand {
+        CreateCommand::new("availability")
+            .description(t(Language::En, "availability_cmd.description"))
+            .description_localized("ja", t(Language::Ja, "availability_cmd.description"))
+            .dm_permission(false)
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "duration",
+                    t(Language::En, "availability_cmd.duration_option"),
+                )
+                .description_localized("ja", t(Language::Ja, "availability_cmd.duration_option"))
+                .required(true),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "tag",
+                    t(Language::En, "availability_cmd.tag_option"),
+                )
+                .description_localized("ja", t(Language::Ja, "availability_cmd.tag_option"))
+                .required(false)
+                .set_autocomplete(true),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "equipment",
+                    t(Language::En, "availability_cmd.equipment_option"),
+                )
+                .description_localized("ja", t(Language::Ja, "availability_cmd.equipment_option"))
+                .required(false)
+                .set_autocomplete(true),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "from",
+                    t(Language::En, "availability_cmd.from_option"),
+                )
+                .description_localized("ja", t(Language::Ja, "availability_cmd.from_option"))
+                .required(false),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "to",
+                    t(Language::En, "availability_cmd.to_option"),
+                )
+                .description_localized("ja", t(Language::Ja, "availability_cmd.to_option"))
+                .required(false),
+            )
+    }
+}
+
+/// /equipment: find equipment
+pub struct EquipmentCommand;
+
+impl EquipmentCommand {
+    pub fn register() -> CreateCommand {
+        CreateCommand::new("equipment")
+            .description(t(Language::En, "equipment_cmd.description"))
+            .description_localized("ja", t(Language::Ja, "equipment_cmd.description"))
+            .dm_permission(false)
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "search",
+                    t(Language::En, "equipment_cmd.search_description"),
+                )
+                .description_localized("ja", t(Language::Ja, "equipment_cmd.search_description"))
+                .add_sub_option(
+                    CreateCommandOption::new(
+                        CommandOptionType::String,
+                        "query",
+                        t(Language::En, "equipment_cmd.query_option"),
+                    )
+                    .description_localized("ja", t(Language::Ja, "equipment_cmd.query_option"))
+                    .required(true)
+                    .max_length(50)
+                    .set_autocomplete(true),
+                ),
+            )
+    }
+}
+
+/// User context menu: an admin's view of a member's reservations and loans
+pub struct ViewLoansCommand;
+
+impl ViewLoansCommand {
+    pub const NAME: &'static str = "Equipment: View loans";
+
+    pub fn register() -> CreateCommand {
+        CreateCommand::new(Self::NAME)
+            .kind(CommandType::User)
+            .name_localized("ja", t(Language::Ja, "view_loans_cmd.name"))
+            .default_member_permissions(Permissions::ADMINISTRATOR)
+            .dm_permission(false)
+    }
+}
+
+/// User context menu: start transferring one of your reservations to the clicked member.
+/// Command names are limited to 32 characters.
+pub struct TransferToUserCommand;
+
+impl TransferToUserCommand {
+    pub const NAME: &'static str = "Transfer my reservation to…";
+
+    pub fn register() -> CreateCommand {
+        CreateCommand::new(Self::NAME)
+            .kind(CommandType::User)
+            .name_localized("ja", t(Language::Ja, "transfer_to_user_cmd.name"))
+            .dm_permission(false)
+    }
+}
@@ -0,0 +1,346 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use tracing::info;
+
+use crate::constants::Constants;
+use crate::models::ConditionReport;
+
+/// Parse a condition rating entered in the return modal ("1" to "5", "4/5" is accepted)
+pub fn parse_condition_rating(input: &str) -> Option<i64> {
+    let rating = input.trim().split('/').next()?.trim().parse::<i64>().ok()?;
+    (1..=Constants::MAX_CONDITION_RATING)
+        .contains(&rating)
+        .then_some(rating)
+}
+
+/// A return is a damage report when it has a damage note or a low condition rating
+pub fn is_damage_report(rating: i64, damage_note: Option<&str>) -> bool {
+    rating <= Constants::DAMAGE_CONDITION_RATING
+        || damage_note.is_some_and(|note| !note.trim().is_empty())
+}
+
+/// Unavailable reason recorded when damaged equipment is taken out of service
+pub fn damage_unavailable_reason(report: &ConditionReport) -> String {
+    let reason = match report.damage_note.as_deref() {
+        Some(note) if !note.trim().is_empty() => format!(
+            "Damage reported (condition {}/{}): {}",
+            report.rating,
+            Constants::MAX_CONDITION_RATING,
+            note.trim()
+        ),
+        _ => format!(
+            "Damage reported (condition {}/{})",
+            report.rating,
+            Constants::MAX_CONDITION_RATING
+        ),
+    };
+    reason
+        .chars()
+        .take(Constants::MAX_UNAVAILABLE_REASON_LENGTH)
+        .collect()
+}
+
+/// Condition reports filed on return
+pub struct ConditionReportService {
+    db: SqlitePool,
+}
+
+impl ConditionReportService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// File a condition report for a returned reservation.
+    /// Damage reports accept DM photos for DAMAGE_PHOTO_WINDOW_MINUTES and are logged.
+    pub async fn create_report(
+        &self,
+        reservation_id: i64,
+        user_id: i64,
+        rating: i64,
+        damage_note: Option<String>,
+    ) -> Result<ConditionReport> {
+        let damage_note = damage_note
+            .map(|note| note.trim().to_string())
+            .filter(|note| !note.is_empty());
+        let is_damage = is_damage_report(rating, damage_note.as_deref());
+        let now = Utc::now();
+        let photos_accepted_until =
+            is_damage.then(|| now + Duration::minutes(Constants::DAMAGE_PHOTO_WINDOW_MINUTES));
+
+        let mut tx = self.db.begin().await?;
+
+        let reservation = sqlx::query!(
+            "SELECT r.equipment_id, e.guild_id
+             FROM reservations r
+             JOIN equipment e ON r.equipment_id = e.id
+             WHERE r.id = ?",
+            reservation_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let report_id = sqlx::query!(
+            "INSERT INTO condition_reports (reservation_id, equipment_id, guild_id, user_id, rating, damage_note,
+                                            photos_accepted_until, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            reservation_id,
+            reservation.equipment_id,
+            reservation.guild_id,
+            user_id,
+            rating,
+            damage_note,
+            photos_accepted_until,
+            now
+        )
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+
+        if is_damage {
+            let log_notes = format!(
+                "Condition {}/{} reported on return of reservation {}{}",
+                rating,
+                Constants::MAX_CONDITION_RATING,
+                reservation_id,
+                damage_note
+                    .as_deref()
+                    .map(|note| format!(": {}", note))
+                    .unwrap_or_default()
+            );
+            sqlx::query!(
+                "INSERT INTO equipment_logs (equipment_id, user_id, action, location, previous_status, new_status, notes, timestamp)
+                 VALUES (?, ?, 'DamageReported', NULL, NULL, NULL, ?, ?)",
+                reservation.equipment_id,
+                user_id,
+                log_notes,
+                now
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        info!(
+            "Condition report {} filed for reservation {} by user {} (rating {}, damage: {})",
+            report_id, reservation_id, user_id, rating, is_damage
+        );
+
+        Ok(ConditionReport {
+            id: report_id,
+            reservation_id,
+            equipment_id: reservation.equipment_id,
+            guild_id: reservation.guild_id,
+            user_id,
+            rating,
+            damage_note,
+            image_urls: Vec::new(),
+            photos_accepted_until,
+            alert_channel_id: None,
+            alert_message_id: None,
+            marked_unavailable: false,
+            created_at: now,
+        })
+    }
+
+    /// Get a condition report by ID
+    pub async fn get_report(&self, report_id: i64) -> Result<Option<ConditionReport>> {
+        let row = sqlx::query!(
+            "SELECT id as \"id!\", reservation_id, equipment_id, guild_id, user_id, rating, damage_note,
+                    image_urls, photos_accepted_until, alert_channel_id, alert_message_id,
+                    marked_unavailable, created_at
+             FROM condition_reports WHERE id = ?",
+            report_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(row.map(|row| ConditionReport {
+            id: row.id,
+            reservation_id: row.reservation_id,
+            equipment_id: row.equipment_id,
+            guild_id: row.guild_id,
+            user_id: row.user_id,
+            rating: row.rating,
+            damage_note: row.damage_note,
+            image_urls: row
+                .image_urls
+                .as_deref()
+                .and_then(|json| serde_json::from_str(json).ok())
+                .unwrap_or_default(),
+            photos_accepted_until: row.photos_accepted_until.map(crate::time::naive_to_utc),
+            alert_channel_id: row.alert_channel_id,
+            alert_message_id: row.alert_message_id,
+            marked_unavailable: row.marked_unavailable,
+            created_at: crate::time::naive_to_utc(row.created_at),
+        }))
+    }
+
+    /// Latest damage report of a member that still accepts photos
+    pub async fn find_report_accepting_photos(
+        &self,
+        user_id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<Option<ConditionReport>> {
+        let report_id = sqlx::query_scalar!(
+            "SELECT id as \"id!\" FROM condition_reports
+             WHERE user_id = ? AND photos_accepted_until > ?
+             ORDER BY created_at DESC
+             LIMIT 1",
+            user_id,
+            now
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        match report_id {
+            Some(report_id) => self.get_report(report_id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// Append photo URLs to a report; returns the total number of photos attached
+    pub async fn attach_images(&self, report_id: i64, urls: &[String]) -> Result<usize> {
+        let mut tx = self.db.begin().await?;
+
+        let existing = sqlx::query_scalar!(
+            "SELECT image_urls FROM condition_reports WHERE id = ?",
+            report_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let mut image_urls: Vec<String> = existing
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        image_urls.extend(urls.iter().cloned());
+        let image_urls_json = serde_json::to_string(&image_urls)?;
+
+        sqlx::query!(
+            "UPDATE condition_reports SET image_urls = ? WHERE id = ?",
+            image_urls_json,
+            report_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Attached {} photo(s) to condition report {}",
+            urls.len(),
+            report_id
+        );
+        Ok(image_urls.len())
+    }
+
+    /// Remember where the admin alert for a report was posted
+    pub async fn set_alert_message(
+        &self,
+        report_id: i64,
+        channel_id: i64,
+        message_id: i64,
+    ) -> Result<()> {
+        sqlx::query!(
+            "UPDATE condition_reports SET alert_channel_id = ?, alert_message_id = ? WHERE id = ?",
+            channel_id,
+            message_id,
+            report_id
+        )
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Take the reported equipment out of service with the report as its unavailable reason.
+    /// Returns false if the report does not exist or was already applied.
+    pub async fn mark_equipment_unavailable(
+        &self,
+        report_id: i64,
+        actor_user_id: i64,
+    ) -> Result<bool> {
+        let Some(report) = self.get_report(report_id).await? else {
+            return Ok(false);
+        };
+        if report.marked_unavailable {
+            return Ok(false);
+        }
+
+        let reason = damage_unavailable_reason(&report);
+        let now = Utc::now();
+        let mut tx = self.db.begin().await?;
+
+        let previous_status = sqlx::query_scalar!(
+            "SELECT status FROM equipment WHERE id = ?",
+            report.equipment_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE equipment SET status = 'Unavailable', unavailable_reason = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?",
+            reason,
+            report.equipment_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE condition_reports SET marked_unavailable = TRUE WHERE id = ?",
+            report_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let log_notes = format!("Condition report {}: {}", report_id, reason);
+        sqlx::query!(
+            "INSERT INTO equipment_logs (equipment_id, user_id, action, location, previous_status, new_status, notes, timestamp)
+             VALUES (?, ?, 'MarkedUnavailable', NULL, ?, 'Unavailable', ?, ?)",
+            report.equipment_id,
+            actor_user_id,
+            previous_status,
+            log_notes,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Equipment {} marked unavailable from condition report {} by {}",
+            report.equipment_id, report_id, actor_user_id
+        );
+        Ok(true)
+    }
+
+    /// Recent condition reports for an equipment, newest first
+    pub async fn list_reports_for_equipment(
+        &self,
+        equipment_id: i64,
+        limit: i64,
+    ) -> Result<Vec<ConditionReport>> {
+        let report_ids = sqlx::query_scalar!(
+            "SELECT id as \"id!\" FROM condition_reports
+             WHERE equipment_id = ?
+             ORDER BY created_at DESC
+             LIMIT ?",
+            equipment_id,
+            limit
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut reports = Vec::with_capacity(report_ids.len());
+        for report_id in report_ids {
+            if let Some(report) = self.get_report(report_id).await? {
+                reports.push(report);
+            }
+        }
+        Ok(reports)
+    }
+}
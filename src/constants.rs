@@ -26,18 +26,59 @@ impl Constants {
     // Page sizes and limits
     pub const DEFAULT_MANAGEMENT_PAGE_SIZE: usize = 10;
     pub const DEFAULT_LOG_PAGE_SIZE: usize = 15;
+    pub const MY_RESERVATIONS_LIMIT: i64 = 5; // One row of Edit/Cancel/Transfer buttons each
+    pub const AUTOCOMPLETE_CHOICE_LIMIT: i64 = 25; // Most choices Discord shows
+    pub const AVAILABILITY_DEFAULT_DAYS: i64 = 7;
+    pub const AVAILABILITY_MAX_DAYS: i64 = 31;
+    pub const AVAILABILITY_RESULT_LIMIT: usize = 10; // Two rows of Reserve buttons
+    pub const EMBED_RESERVATION_LIMIT: i64 = 5; // Current plus upcoming reservations on an equipment embed
+    pub const EMBED_FIELD_VALUE_LIMIT: usize = 1024;
+    pub const RESERVATION_LIST_PAGE_SIZE: i64 = 10;
+    pub const HEADER_TIMELINE_LIMIT: usize = 9; // A message holds 10 embeds, one is the header
+    pub const EQUIPMENT_DETAIL_ENTRIES: i64 = 5; // Reservations and log entries on a detail card
     pub const MAX_EQUIPMENT_NAME_LENGTH: usize = 100;
     pub const MAX_LOCATION_NAME_LENGTH: usize = 50;
     pub const MAX_TAG_NAME_LENGTH: usize = 30;
     pub const MAX_UNAVAILABLE_REASON_LENGTH: usize = 200;
     pub const MAX_TRANSFER_NOTE_LENGTH: usize = 500;
 
+    // Equipment channel refreshes
+    pub const RECONCILE_DEBOUNCE_MS: u64 = 2000; // Requests within this window share one run
+    pub const CHANNEL_EDIT_BURST: u32 = 5;
+    pub const CHANNEL_EDITS_PER_SECOND: f64 = 1.0;
+    pub const CHANNEL_HISTORY_LIMIT: usize = 1000; // Messages read when checking a channel
+
     // Time constants (in hours)
     pub const TRANSFER_TIMEOUT_HOURS: i64 = 3;
     pub const RETURN_CORRECTION_WINDOW_HOURS: i64 = 1;
     pub const NEXT_RESERVATION_BUFFER_MINUTES: i64 = 15;
     pub const PRE_END_NOTIFICATION_MINUTES: i64 = 15;
+    pub const DEFAULT_NO_SHOW_GRACE_MINUTES: i64 = 0; // Off until an admin sets a grace period
+    pub const PICKUP_EARLY_MINUTES: i64 = 15; // How early before START pickup is allowed
+
+    // Late-return penalty defaults
+    pub const LATE_RETURN_POINTS_PER_HOUR: i64 = 1; // Points per started hour past END
+    pub const DEFAULT_PENALTY_THRESHOLD_POINTS: i64 = 0; // Off until an admin sets a threshold
+    pub const DEFAULT_PENALTY_SUSPENSION_DAYS: i64 = 0;
     
+    // Condition reports on return
+    pub const MAX_CONDITION_RATING: i64 = 5;
+    pub const DAMAGE_CONDITION_RATING: i64 = 2; // Ratings at or below this count as damage
+    pub const MAX_DAMAGE_NOTE_LENGTH: usize = 500;
+    pub const DAMAGE_PHOTO_WINDOW_MINUTES: i64 = 30; // How long DM photos are attached to a report
+
+    // Turnaround buffers between reservations
+    pub const MAX_BUFFER_MINUTES: i64 = 240;
+
+    // Opening hours and closed dates
+    pub const MAX_CLOSED_DATE_RANGE_DAYS: i64 = 366; // Longest range accepted in one entry
+    pub const ICS_IMPORT_WINDOW_MINUTES: i64 = 10; // How long the bot waits for a DM'd .ics file
+
+    // Reservation wizard slot pickers
+    pub const RESERVATION_SLOT_MINUTES: i64 = 30; // Granularity of selectable start times
+    pub const MAX_RESERVATION_DAYS_AHEAD: i64 = 60; // Reservations must end within this many days
+    pub const MAX_SELECT_OPTIONS: usize = 25; // Discord limit per select menu
+
     // Session cleanup constants
     pub const SESSION_CLEANUP_INTERVAL_MINUTES: i64 = 30; // How often to run cleanup
     pub const SESSION_EXPIRY_HOURS: i64 = 2; // How long sessions stay valid
@@ -46,6 +87,7 @@ impl Constants {
     pub const STATUS_CONFIRMED: &'static str = "Confirmed";
     pub const STATUS_PENDING: &'static str = "Pending";
     pub const STATUS_CANCELED: &'static str = "Canceled";
+    pub const STATUS_NO_SHOW: &'static str = "NoShow";
 
     // Equipment status
     pub const EQUIPMENT_AVAILABLE: &'static str = "Available";
@@ -66,6 +108,7 @@ impl Constants {
     pub const LOG_ACTION_TRANSFER: &'static str = "transfer";
     pub const LOG_ACTION_EDIT: &'static str = "edit";
     pub const LOG_ACTION_FORCE_STATE: &'static str = "force_state";
+    pub const LOG_ACTION_PICKUP: &'static str = "PickedUp";
     pub const LOG_ACTION_CREATE_EQUIPMENT: &'static str = "eq_create";
     pub const LOG_ACTION_DELETE_EQUIPMENT: &'static str = "eq_delete";
     pub const LOG_ACTION_RENAME_EQUIPMENT: &'static str = "eq_rename";
@@ -82,7 +125,7 @@ impl Constants {
     // Error messages
     pub const MSG_ADMIN_REQUIRED: &'static str = "❌ You need administrator permissions to use this feature.";
     pub const MSG_ADMIN_LOG_REQUIRED: &'static str = "❌ You need administrator permissions to view operation logs.";
-    pub const MSG_INVALID_TIME_FORMAT: &'static str = "❌ Invalid time format. Please use YYYY/MM/DD HH:MM format.";
+    pub const MSG_INVALID_TIME_FORMAT: &'static str = "❌ Invalid time format. Please use YYYY/MM/DD HH:MM, MM/DD HH:MM or e.g. tomorrow 14:00.";
     pub const MSG_EQUIPMENT_NOT_FOUND: &'static str = "❌ Equipment not found.";
     pub const MSG_RESERVATION_NOT_FOUND: &'static str = "❌ Reservation not found.";
     pub const MSG_OPERATION_NOT_POSSIBLE: &'static str = "❌ Operation is not possible because the next reservation is imminent or because 1 hour has passed since return.";
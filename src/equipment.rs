@@ -1,15 +1,104 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 use chrono::{NaiveDateTime, Utc};
 use serenity::all::{
-    ButtonStyle, ChannelId, Colour, Context, CreateActionRow, CreateButton, CreateEmbed,
-    CreateMessage, EditMessage, MessageId,
+    ActionRowComponent, Button, ButtonKind, ButtonStyle, ChannelId, Colour, Context,
+    CreateActionRow, CreateAttachment, CreateButton, CreateEmbed, CreateEmbedFooter, CreateMessage,
+    EditAttachments, EditMessage, GetMessages, Message, MessageId,
 };
 use sqlx::{Row, SqlitePool};
 use tracing::{error, info, warn};
 
-use crate::models::{Equipment, ManagedMessage, Reservation, Tag};
+use crate::constants::Constants;
+use crate::i18n::{self, t, tf, Language};
+use crate::models::{
+    ChannelRebuild, Equipment, EquipmentLog, Location, ManagedMessage, Reservation, Tag,
+};
+use crate::reconcile::{self, ChannelDrift, ReconcileStats};
 use crate::time;
 
+/// Equipment name with its tag as a prefix, e.g. "[PC] PC-A"
+pub fn tagged_name(name: &str, tag_name: Option<&str>) -> String {
+    match tag_name {
+        Some(tag_name) => format!("[{}] {}", tag_name, name),
+        None => name.to_string(),
+    }
+}
+
+/// Hash of a rendered equipment message, stored so unchanged messages can be skipped
+pub fn content_hash(embed: &CreateEmbed, components: &[CreateActionRow]) -> String {
+    // 64-bit FNV-1a over the JSON Discord would receive
+    let json = serde_json::to_vec(&(embed, components)).unwrap_or_default();
+    let hash = json.iter().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Join reservation lines into an embed field value that stays within Discord's field
+/// limit. Lines that do not fit, and the `total` beyond those given, are summarised
+/// as "+K more".
+pub fn reservation_list_field(lines: &[String], total: usize, lang: Language) -> String {
+    let mut value = String::new();
+    let mut shown = 0;
+    for line in lines {
+        let hidden = total.saturating_sub(shown + 1);
+        let more = if hidden > 0 {
+            tf(lang, "embed.more_reservations", &[("count", &hidden)])
+        } else {
+            String::new()
+        };
+        if value.chars().count() + line.chars().count() + more.chars().count() + 2
+            > Constants::EMBED_FIELD_VALUE_LIMIT
+        {
+            break;
+        }
+        if !value.is_empty() {
+            value.push('\n');
+        }
+        value.push_str(line);
+        shown += 1;
+    }
+
+    let hidden = total.saturating_sub(shown);
+    if hidden > 0 {
+        if !value.is_empty() {
+            value.push('\n');
+        }
+        value.push_str(&tf(lang, "embed.more_reservations", &[("count", &hidden)]));
+    }
+    value
+}
+
+/// How closely `text` matches a search query, lower is closer: 0 for the same text,
+/// then substrings by position, then the query's characters in order with gaps
+/// (e.g. "sna7" in "Sony A7"). `None` if it does not match at all.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<usize> {
+    let query = query.trim().to_lowercase();
+    let text = text.to_lowercase();
+    if query.is_empty() || query == text {
+        return Some(0);
+    }
+    if let Some(pos) = text.find(&query) {
+        return Some(1 + text[..pos].chars().count());
+    }
+
+    let mut wanted = query.chars().filter(|c| !c.is_whitespace()).peekable();
+    let (mut first, mut last) = (None, 0);
+    for (idx, c) in text.chars().enumerate() {
+        if wanted.peek() == Some(&c) {
+            wanted.next();
+            first.get_or_insert(idx);
+            last = idx;
+        }
+    }
+    match (wanted.peek(), first) {
+        (None, Some(first)) => Some(100 + first + (last - first)),
+        _ => None,
+    }
+}
+
 /// Equipment visualization and management
 pub struct EquipmentRenderer {
     db: SqlitePool,
@@ -31,6 +120,19 @@ pub struct EditPlan {
     pub creates: usize,
     pub edits: usize,
     pub deletes: usize,
+    pub skipped: usize,
+}
+
+impl EditPlan {
+    /// Counts the plan calls for; failed requests can make the outcome smaller
+    pub fn stats(&self) -> ReconcileStats {
+        ReconcileStats {
+            created: self.creates as u64,
+            edited: self.edits as u64,
+            skipped: self.skipped as u64,
+            deleted: self.deletes as u64,
+        }
+    }
 }
 
 impl EquipmentRenderer {
@@ -38,15 +140,18 @@ impl EquipmentRenderer {
         Self { db }
     }
 
-    /// Compute minimal edit plan for reconciling managed messages
+    /// Compute minimal edit plan for reconciling managed messages. `content_hashes`
+    /// maps equipment IDs to the hash of their freshly rendered message.
     pub fn compute_edit_plan(
         existing_messages: &[ManagedMessage],
         equipment_list: &[(Equipment, Option<Tag>)],
+        content_hashes: &HashMap<i64, String>,
     ) -> EditPlan {
         let mut actions = Vec::new();
         let mut creates = 0;
         let mut edits = 0;
         let mut deletes = 0;
+        let mut skipped = 0;
 
         // Check if header exists (sort_order = 0, message_type = 'Header')
         let has_header = existing_messages
@@ -77,13 +182,16 @@ impl EquipmentRenderer {
                 // We have an existing message at this position
                 let existing = existing_equipment_messages[index];
 
-                // Check if the equipment ID matches
-                if existing.equipment_id != Some(equipment.id) {
-                    // Equipment changed, need to edit the message
+                // Edit when the message shows other equipment or its content changed
+                let rendered = content_hashes.get(&equipment.id).map(String::as_str);
+                if existing.equipment_id != Some(equipment.id)
+                    || existing.content_hash.as_deref() != rendered
+                {
                     actions.push(EditAction::EditEquipment(existing.message_id, equipment.id));
                     edits += 1;
+                } else {
+                    skipped += 1;
                 }
-                // If equipment matches, no action needed (content will be updated anyway)
             } else {
                 // No existing message, need to create one
                 actions.push(EditAction::CreateEquipment(equipment.id));
@@ -102,6 +210,7 @@ impl EquipmentRenderer {
             creates,
             edits,
             deletes,
+            skipped,
         }
     }
 
@@ -143,7 +252,8 @@ impl EquipmentRenderer {
 
             let tag = if let Some(tag_id) = equipment.tag_id {
                 let tag_row = sqlx::query(
-                    "SELECT id, guild_id, name, sort_order, created_at FROM tags WHERE id = ?",
+                    "SELECT id, guild_id, name, sort_order, allowed_roles, manager_roles, created_at
+                     FROM tags WHERE id = ?",
                 )
                 .bind(tag_id)
                 .fetch_optional(&self.db)
@@ -155,6 +265,8 @@ impl EquipmentRenderer {
                         guild_id: tag_row.get("guild_id"),
                         name: tag_row.get("name"),
                         sort_order: tag_row.get("sort_order"),
+                        allowed_roles: tag_row.get("allowed_roles"),
+                        manager_roles: tag_row.get("manager_roles"),
                         created_at: tag_row.get("created_at"),
                     })
                 } else {
@@ -177,6 +289,184 @@ impl EquipmentRenderer {
         Ok(result)
     }
 
+    /// Equipment whose name contains the query (case-insensitive), closest matches first.
+    /// An empty query lists equipment by name.
+    pub async fn search_equipment(
+        &self,
+        guild_id: i64,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<Equipment>> {
+        let equipment = sqlx::query_as::<_, Equipment>(
+            "SELECT id, guild_id, tag_id, name, status, current_location,
+                    unavailable_reason, default_return_location, message_id,
+                    created_at, updated_at
+             FROM equipment
+             WHERE guild_id = ? AND instr(lower(name), lower(?)) > 0
+             ORDER BY instr(lower(name), lower(?)) ASC, name ASC
+             LIMIT ?",
+        )
+        .bind(guild_id)
+        .bind(query.trim())
+        .bind(query.trim())
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(equipment)
+    }
+
+    /// Tags whose name contains the query (case-insensitive), in display order
+    pub async fn search_tags(&self, guild_id: i64, query: &str, limit: i64) -> Result<Vec<Tag>> {
+        let tags = sqlx::query_as::<_, Tag>(
+            "SELECT id, guild_id, name, sort_order, allowed_roles, manager_roles, created_at
+             FROM tags
+             WHERE guild_id = ? AND instr(lower(name), lower(?)) > 0
+             ORDER BY sort_order ASC, name ASC
+             LIMIT ?",
+        )
+        .bind(guild_id)
+        .bind(query.trim())
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(tags)
+    }
+
+    /// Locations whose name contains the query (case-insensitive), closest matches first
+    pub async fn search_locations(
+        &self,
+        guild_id: i64,
+        query: &str,
+        limit: i64,
+    ) -> Result<Vec<Location>> {
+        let locations = sqlx::query_as::<_, Location>(
+            "SELECT id, guild_id, name, created_at
+             FROM locations
+             WHERE guild_id = ? AND instr(lower(name), lower(?)) > 0
+             ORDER BY instr(lower(name), lower(?)) ASC, name ASC
+             LIMIT ?",
+        )
+        .bind(guild_id)
+        .bind(query.trim())
+        .bind(query.trim())
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(locations)
+    }
+
+    /// Equipment matching a loose query on its name, tag or locations, best matches first
+    /// and otherwise in display order
+    pub async fn fuzzy_search_equipment(
+        &self,
+        guild_id: i64,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<(Equipment, Option<Tag>)>> {
+        let mut matches: Vec<_> = self
+            .get_ordered_equipment(guild_id)
+            .await?
+            .into_iter()
+            .filter_map(|(equipment, tag)| {
+                // A name match beats a tag match, which beats a location match
+                let score = [
+                    fuzzy_score(query, &equipment.name),
+                    tag.as_ref()
+                        .and_then(|tag| fuzzy_score(query, &tag.name))
+                        .map(|score| score + 1000),
+                    equipment
+                        .current_location
+                        .as_deref()
+                        .and_then(|location| fuzzy_score(query, location))
+                        .map(|score| score + 2000),
+                    equipment
+                        .default_return_location
+                        .as_deref()
+                        .and_then(|location| fuzzy_score(query, location))
+                        .map(|score| score + 2000),
+                ]
+                .into_iter()
+                .flatten()
+                .min()?;
+                Some((score, equipment, tag))
+            })
+            .collect();
+
+        matches.sort_by_key(|(score, _, _)| *score);
+        Ok(matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, equipment, tag)| (equipment, tag))
+            .collect())
+    }
+
+    /// Confirmed, unreturned reservations of an equipment that have not ended, earliest first
+    pub async fn upcoming_reservations(
+        &self,
+        equipment_id: i64,
+        now: chrono::DateTime<Utc>,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Reservation>> {
+        let reservations = sqlx::query_as::<_, Reservation>(
+            "SELECT id, equipment_id, user_id, start_time, end_time, location, status,
+                    created_at, updated_at, returned_at, return_location
+             FROM reservations
+             WHERE equipment_id = ? AND status = 'Confirmed' AND returned_at IS NULL
+               AND end_time > ?
+             ORDER BY start_time ASC, id ASC
+             LIMIT ? OFFSET ?",
+        )
+        .bind(equipment_id)
+        .bind(now)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(reservations)
+    }
+
+    /// Number of reservations `upcoming_reservations` can page through
+    pub async fn count_upcoming_reservations(
+        &self,
+        equipment_id: i64,
+        now: chrono::DateTime<Utc>,
+    ) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM reservations
+             WHERE equipment_id = ? AND status = 'Confirmed' AND returned_at IS NULL
+               AND end_time > ?",
+        )
+        .bind(equipment_id)
+        .bind(now)
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// Latest log entries of an equipment, newest first
+    pub async fn recent_logs(&self, equipment_id: i64, limit: i64) -> Result<Vec<EquipmentLog>> {
+        let logs = sqlx::query_as::<_, EquipmentLog>(
+            "SELECT id, equipment_id, user_id, action, location, previous_status, new_status,
+                    notes, timestamp
+             FROM equipment_logs
+             WHERE equipment_id = ?
+             ORDER BY timestamp DESC, id DESC
+             LIMIT ?",
+        )
+        .bind(equipment_id)
+        .bind(limit)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(logs)
+    }
+
     /// Create an embed for a single piece of equipment
     pub async fn create_equipment_embed(
         &self,
@@ -197,128 +487,271 @@ impl EquipmentRenderer {
             _ => Colour::LIGHT_GREY,
         };
 
+        let lang = i18n::guild_language(&self.db, equipment.guild_id).await?;
+        let status_label = i18n::status_label(lang, &equipment.status);
+
         let mut embed = CreateEmbed::new()
-            .title(format!("{} {}", status_emoji, equipment.name))
+            .title(format!(
+                "{} {}",
+                status_emoji,
+                tagged_name(&equipment.name, tag.as_ref().map(|tag| tag.name.as_str()))
+            ))
             .color(status_color)
-            .field("Status", &equipment.status, true);
+            .field(t(lang, "embed.status"), status_label, true);
 
         if let Some(tag) = tag {
-            embed = embed.field("Category", &tag.name, true);
+            embed = embed.field(t(lang, "embed.category"), &tag.name, true);
+
+            let allowed_roles = crate::tag_access::parse_role_ids(tag.allowed_roles.as_deref());
+            if !allowed_roles.is_empty() {
+                let roles = allowed_roles
+                    .iter()
+                    .map(|role| format!("<@&{}>", role))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                embed = embed.field(t(lang, "embed.reservable_by"), roles, true);
+            }
         }
 
         if let Some(location) = &equipment.current_location {
-            embed = embed.field("Current Location", location, true);
+            embed = embed.field(t(lang, "embed.current_location"), location, true);
         } else if let Some(default_location) = &equipment.default_return_location {
-            embed = embed.field("Default Return Location", default_location, true);
+            embed = embed.field(
+                t(lang, "embed.default_return_location"),
+                default_location,
+                true,
+            );
         }
 
         if equipment.status == "Unavailable" {
             if let Some(reason) = &equipment.unavailable_reason {
-                embed = embed.field("Unavailable Reason", reason, false);
+                embed = embed.field(t(lang, "embed.unavailable_reason"), reason, false);
             }
         }
 
-        // Add reservation information
-        let current_reservation = self.get_current_or_next_reservation(equipment.id).await?;
-        if let Some(reservation) = current_reservation {
-            let start_jst = time::utc_to_jst_string(reservation.start_time);
-            let end_jst = time::utc_to_jst_string(reservation.end_time);
-            let user_mention = format!("<@{}>", reservation.user_id);
-
-            let now = Utc::now();
-            if reservation.start_time <= now && now < reservation.end_time {
-                // Currently reserved
-                embed = embed.field(
-                    "Currently Reserved",
-                    format!("By: {}\nUntil: {}", user_mention, end_jst),
-                    false,
-                );
-            } else {
-                // Future reservation
-                embed = embed.field(
-                    "Next Reservation",
-                    format!("By: {}\nFrom: {} to {}", user_mention, start_jst, end_jst),
-                    false,
-                );
-            }
-        } else {
-            embed = embed.field("Availability", "Available for reservation", false);
+        let pending_ack = crate::returns::ReturnService::new(self.db.clone())
+            .pending_acknowledgment(equipment.id)
+            .await?;
+        if let Some(ack) = pending_ack {
+            let location = ack
+                .return_location
+                .as_deref()
+                .unwrap_or(t(lang, "common.unknown"));
+            embed = embed.field(
+                t(lang, "embed.awaiting_ack_name"),
+                tf(lang, "embed.awaiting_ack_value", &[("location", &location)]),
+                false,
+            );
         }
 
-        Ok(embed)
-    }
-
-    /// Get the current or next upcoming reservation for equipment
-    async fn get_current_or_next_reservation(
-        &self,
-        equipment_id: i64,
-    ) -> Result<Option<Reservation>> {
-        // Use regular query instead of query_as! to handle type conversions manually
-        let reservation_row = sqlx::query!(
-            "SELECT id, equipment_id, user_id, start_time, end_time, location, status, created_at, updated_at, returned_at, return_location
-             FROM reservations 
-             WHERE equipment_id = ? AND status = 'Confirmed' AND end_time > CURRENT_TIMESTAMP
-             ORDER BY start_time ASC
-             LIMIT 1",
-            equipment_id
-        )
-        .fetch_optional(&self.db)
-        .await?;
+        let buffer = crate::buffers::BufferService::new(self.db.clone())
+            .buffer_for_equipment(equipment.id)
+            .await?;
+        if !buffer.is_zero() {
+            embed = embed.field(t(lang, "embed.prep_time"), buffer.describe(lang), true);
+        }
 
-        if let Some(row) = reservation_row {
-            // Helper function to convert NaiveDateTime to DateTime<Utc>
-            let to_utc_datetime = |naive: NaiveDateTime| -> chrono::DateTime<Utc> {
-                chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(naive, chrono::Utc)
-            };
+        // Current reservation, then as many upcoming ones as fit
+        let now = Utc::now();
+        let reservations = self
+            .upcoming_reservations(equipment.id, now, 0, Constants::EMBED_RESERVATION_LIMIT)
+            .await?;
+        let mut total = self.count_upcoming_reservations(equipment.id, now).await? as usize;
+        let tz = time::guild_time_zone(&self.db, equipment.guild_id).await?;
+        let mut upcoming = reservations.as_slice();
+        if let Some(reservation) = reservations.first().filter(|res| res.start_time <= now) {
+            let end_local = time::utc_to_local_string(reservation.end_time, tz);
+            let user_mention = format!("<@{}>", reservation.user_id);
+            let mut value = tf(
+                lang,
+                "embed.current_reservation_value",
+                &[("user", &user_mention), ("end", &end_local)],
+            );
+            if buffer.after_minutes > 0 {
+                let (_, blocked_until) =
+                    buffer.blocked_range(reservation.start_time, reservation.end_time);
+                let until = time::utc_to_local_string(blocked_until, tz);
+                value.push_str(&tf(lang, "embed.prep_until", &[("time", &until)]));
+            }
+            embed = embed.field(t(lang, "embed.current_reservation_name"), value, false);
+            upcoming = &reservations[1..];
+            total -= 1;
+        }
 
-            Ok(Some(Reservation {
-                id: row.id.unwrap_or(0),
-                equipment_id: row.equipment_id,
-                user_id: row.user_id,
-                start_time: to_utc_datetime(row.start_time),
-                end_time: to_utc_datetime(row.end_time),
-                location: row.location,
-                status: row.status,
-                created_at: to_utc_datetime(row.created_at.unwrap_or_else(|| {
-                    chrono::DateTime::from_timestamp(0, 0).unwrap().naive_utc()
-                })),
-                updated_at: to_utc_datetime(row.updated_at.unwrap_or_else(|| {
-                    chrono::DateTime::from_timestamp(0, 0).unwrap().naive_utc()
-                })),
-                returned_at: row.returned_at.map(to_utc_datetime),
-                return_location: row.return_location,
-            }))
-        } else {
-            Ok(None)
+        if !upcoming.is_empty() {
+            let lines: Vec<String> = upcoming
+                .iter()
+                .map(|res| {
+                    tf(
+                        lang,
+                        "embed.upcoming_line",
+                        &[
+                            ("start", &time::utc_to_local_string(res.start_time, tz)),
+                            ("end", &time::utc_to_local_string(res.end_time, tz)),
+                            ("user", &format!("<@{}>", res.user_id)),
+                        ],
+                    )
+                })
+                .collect();
+            embed = embed.field(
+                tf(
+                    lang,
+                    "embed.upcoming_reservations_name",
+                    &[("count", &total)],
+                ),
+                reservation_list_field(&lines, total, lang),
+                false,
+            );
+        } else if reservations.is_empty() {
+            embed = embed.field(
+                t(lang, "embed.availability_name"),
+                t(lang, "embed.availability_value"),
+                false,
+            );
         }
+
+        Ok(embed)
     }
 
     /// Create action buttons for equipment
     /// Create the header message with overall management button
-    fn create_header_message(&self) -> CreateMessage {
+    fn create_header_message(&self, lang: Language) -> CreateMessage {
+        let (embed, button) = Self::header_parts(lang);
+        CreateMessage::new().embed(embed).components(vec![button])
+    }
+
+    fn header_parts(lang: Language) -> (CreateEmbed, CreateActionRow) {
         let embed = CreateEmbed::new()
-            .title("🔧 Equipment Management")
-            .description("Manage your equipment reservations and organization settings.")
+            .title(t(lang, "header.title"))
+            .description(t(lang, "header.description"))
             .color(Colour::BLUE);
 
         let button = CreateActionRow::Buttons(vec![CreateButton::new("overall_mgmt_open")
-            .label("📊 Overall Management")
+            .label(t(lang, "header.overall_management_button"))
             .style(ButtonStyle::Primary)]);
 
-        CreateMessage::new().embed(embed).components(vec![button])
+        (embed, button)
+    }
+
+    /// Whether the guild shows weekly timelines under the header
+    pub async fn header_timeline_enabled(&self, guild_id: i64) -> Result<bool> {
+        let enabled =
+            sqlx::query_scalar!("SELECT header_timeline FROM guilds WHERE id = ?", guild_id)
+                .fetch_optional(&self.db)
+                .await?
+                .unwrap_or(false);
+        Ok(enabled)
+    }
+
+    /// A weekly timeline embed per tag, in display order, with the images they show.
+    /// Rows are numbered and the description names the equipment behind each number.
+    async fn header_timelines(
+        &self,
+        guild_id: i64,
+        equipment_list: &[(Equipment, Option<Tag>)],
+        lang: Language,
+    ) -> Result<Vec<(CreateEmbed, CreateAttachment)>> {
+        let mut groups: Vec<(Option<&Tag>, Vec<&Equipment>)> = Vec::new();
+        for (equipment, tag) in equipment_list {
+            let tag_id = tag.as_ref().map(|tag| tag.id);
+            match groups.last_mut() {
+                Some((last, members)) if last.map(|tag| tag.id) == tag_id => {
+                    members.push(equipment)
+                }
+                _ => groups.push((tag.as_ref(), vec![equipment])),
+            }
+        }
+
+        let service = crate::timeline::TimelineService::new(self.db.clone());
+        let now = Utc::now();
+        let mut timelines = Vec::new();
+        for (index, (tag, members)) in groups
+            .iter()
+            .take(Constants::HEADER_TIMELINE_LIMIT)
+            .enumerate()
+        {
+            let ids: Vec<i64> = members.iter().map(|equipment| equipment.id).collect();
+            let png = service
+                .tag_week(guild_id, &ids, now)
+                .await?
+                .render()
+                .to_png()?;
+            let filename = format!("timeline-{}.png", index + 1);
+            let names = members
+                .iter()
+                .enumerate()
+                .map(|(row, equipment)| format!("`{}` {}", row + 1, equipment.name))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let tag_name = tag.map_or(t(lang, "timeline.untagged"), |tag| tag.name.as_str());
+            let embed = CreateEmbed::new()
+                .title(tf(lang, "timeline.tag_title", &[("tag", &tag_name)]))
+                .description(names)
+                .image(format!("attachment://{}", filename))
+                .footer(CreateEmbedFooter::new(t(lang, "timeline.legend")))
+                .color(Colour::BLUE);
+            timelines.push((embed, CreateAttachment::bytes(png, filename)));
+        }
+        Ok(timelines)
+    }
+
+    /// Redraw the header message, with fresh timelines when the guild shows them
+    pub async fn refresh_header(
+        &self,
+        ctx: &Context,
+        guild_id: i64,
+        channel_id: i64,
+    ) -> Result<()> {
+        let header_id = sqlx::query_scalar!(
+            "SELECT message_id FROM managed_messages
+             WHERE guild_id = ? AND channel_id = ? AND message_type = 'Header'",
+            guild_id,
+            channel_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+        let Some(header_id) = header_id else {
+            return Ok(());
+        };
+
+        let lang = i18n::guild_language(&self.db, guild_id).await?;
+        let (embed, button) = Self::header_parts(lang);
+        let mut embeds = vec![embed];
+        let mut attachments = EditAttachments::new();
+        if self.header_timeline_enabled(guild_id).await? {
+            let equipment_list = self.get_ordered_equipment(guild_id).await?;
+            for (embed, file) in self
+                .header_timelines(guild_id, &equipment_list, lang)
+                .await?
+            {
+                embeds.push(embed);
+                attachments = attachments.add(file);
+            }
+        }
+
+        let edit = EditMessage::new()
+            .embeds(embeds)
+            .components(vec![button])
+            .attachments(attachments);
+        reconcile::acquire_channel_slot(channel_id).await;
+        ChannelId::new(channel_id as u64)
+            .edit_message(&ctx.http, MessageId::new(header_id as u64), edit)
+            .await?;
+        Ok(())
     }
 
     pub async fn create_equipment_buttons(
         &self,
         equipment: &Equipment,
     ) -> Result<Vec<CreateActionRow>> {
+        let lang = i18n::guild_language(&self.db, equipment.guild_id).await?;
         let mut buttons = Vec::new();
 
         // Always show Reserve button for available equipment
         if equipment.status == "Available" {
             buttons.push(
                 CreateButton::new(format!("reserve_{}", equipment.id))
-                    .label("📅 Reserve")
+                    .label(t(lang, "button.reserve"))
                     .style(ButtonStyle::Primary),
             );
         }
@@ -335,9 +768,19 @@ impl EquipmentRenderer {
 
         // Check/Change button for equipment with active reservations
         if !user_reservations.is_empty() {
+            // Pickup button while the equipment is still on the shelf
+            // Pickup window and ownership are checked in the handler
+            if equipment.status == "Available" {
+                buttons.push(
+                    CreateButton::new(format!("pickup_{}", equipment.id))
+                        .label(t(lang, "button.pickup"))
+                        .style(ButtonStyle::Success),
+                );
+            }
+
             buttons.push(
                 CreateButton::new(format!("change_{}", equipment.id))
-                    .label("✏️ Check/Change")
+                    .label(t(lang, "button.change"))
                     .style(ButtonStyle::Secondary),
             );
 
@@ -345,7 +788,7 @@ impl EquipmentRenderer {
             // Permission checks will be done in the handler
             buttons.push(
                 CreateButton::new(format!("transfer_{}", equipment.id))
-                    .label("🔄 Transfer")
+                    .label(t(lang, "button.transfer"))
                     .style(ButtonStyle::Secondary),
             );
 
@@ -353,7 +796,7 @@ impl EquipmentRenderer {
             if equipment.status == "Loaned" {
                 buttons.push(
                     CreateButton::new(format!("return_{}", equipment.id))
-                        .label("↩️ Return")
+                        .label(t(lang, "button.return"))
                         .style(ButtonStyle::Danger),
                 );
             }
@@ -367,11 +810,21 @@ impl EquipmentRenderer {
         }
 
         // Second row: Admin settings button (always visible, permission checked in handler)
-        let admin_buttons = vec![
-            CreateButton::new(format!("eq_settings_{}", equipment.id))
-                .label("⚙️ This Equipment's Settings")
-                .style(ButtonStyle::Secondary),
-        ];
+        let mut admin_buttons = vec![CreateButton::new(format!("eq_settings_{}", equipment.id))
+            .label(t(lang, "button.settings"))
+            .style(ButtonStyle::Secondary)];
+
+        // Full reservation list once the embed can no longer show them all
+        let total = self
+            .count_upcoming_reservations(equipment.id, Utc::now())
+            .await?;
+        if total > Constants::EMBED_RESERVATION_LIMIT {
+            admin_buttons.push(
+                CreateButton::new(format!("eq_reservations:{}:0", equipment.id))
+                    .label(tf(lang, "button.all_reservations", &[("count", &total)]))
+                    .style(ButtonStyle::Secondary),
+            );
+        }
         action_rows.push(CreateActionRow::Buttons(admin_buttons));
 
         Ok(action_rows)
@@ -383,20 +836,21 @@ impl EquipmentRenderer {
         ctx: &Context,
         guild_id: i64,
         channel_id: i64,
-    ) -> Result<()> {
+    ) -> Result<ReconcileStats> {
         info!(
             "Reconciling equipment display for guild {} in channel {}",
             guild_id, channel_id
         );
 
         let channel = ChannelId::new(channel_id as u64);
+        let lang = i18n::guild_language(&self.db, guild_id).await?;
 
         // Get current equipment ordered by tag and name
         let equipment_list = self.get_ordered_equipment(guild_id).await?;
 
         // Get all existing managed messages for this channel (including header)
         let existing_messages: Vec<ManagedMessage> = sqlx::query(
-            "SELECT id, guild_id, channel_id, message_id, message_type, equipment_id, sort_order, created_at
+            "SELECT id, guild_id, channel_id, message_id, message_type, equipment_id, sort_order, created_at, content_hash
              FROM managed_messages 
              WHERE guild_id = ? AND channel_id = ?
              ORDER BY sort_order ASC"
@@ -422,23 +876,51 @@ impl EquipmentRenderer {
                 equipment_id: row.get("equipment_id"),
                 sort_order: row.get("sort_order"),
                 created_at,
+                content_hash: row.get("content_hash"),
             }
         })
         .collect();
 
+        // Render every message up front; its hash decides whether an edit is needed
+        let mut rendered = HashMap::new();
+        for (equipment, tag) in &equipment_list {
+            let embed = self.create_equipment_embed(equipment, tag).await?;
+            let buttons = self.create_equipment_buttons(equipment).await?;
+            let hash = content_hash(&embed, &buttons);
+            rendered.insert(equipment.id, (embed, buttons, hash));
+        }
+        let content_hashes: HashMap<i64, String> = rendered
+            .iter()
+            .map(|(id, (_, _, hash))| (*id, hash.clone()))
+            .collect();
+
         // Compute minimal edit plan
-        let edit_plan = Self::compute_edit_plan(&existing_messages, &equipment_list);
+        let edit_plan =
+            Self::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
         info!(
-            "Edit plan: {} creates, {} edits, {} deletes",
-            edit_plan.creates, edit_plan.edits, edit_plan.deletes
+            "Edit plan: {} creates, {} edits, {} deletes, {} unchanged",
+            edit_plan.creates, edit_plan.edits, edit_plan.deletes, edit_plan.skipped
         );
 
-        // Execute the edit plan
+        // Execute the edit plan, counting only the changes Discord accepted
+        let mut stats = ReconcileStats {
+            skipped: edit_plan.skipped as u64,
+            ..ReconcileStats::default()
+        };
         for action in &edit_plan.actions {
             match action {
                 EditAction::CreateHeader => {
-                    let message_builder = self.create_header_message();
+                    let mut message_builder = self.create_header_message(lang);
+                    if self.header_timeline_enabled(guild_id).await? {
+                        for (embed, file) in self
+                            .header_timelines(guild_id, &equipment_list, lang)
+                            .await?
+                        {
+                            message_builder = message_builder.add_embed(embed).add_file(file);
+                        }
+                    }
+                    reconcile::acquire_channel_slot(channel_id).await;
                     match channel.send_message(&ctx.http, message_builder).await {
                         Ok(message) => {
                             let message_id = message.id.get() as i64;
@@ -452,6 +934,7 @@ impl EquipmentRenderer {
                             .bind(message_id)
                             .execute(&self.db)
                             .await?;
+                            stats.created += 1;
                             info!("Created header message");
                         }
                         Err(e) => {
@@ -461,18 +944,17 @@ impl EquipmentRenderer {
                 }
 
                 EditAction::CreateEquipment(equipment_id) => {
-                    // Find the equipment and its tag
-                    if let Some((equipment, tag)) =
-                        equipment_list.iter().find(|(eq, _)| eq.id == *equipment_id)
-                    {
-                        let embed = self.create_equipment_embed(equipment, tag).await?;
-                        let buttons = self.create_equipment_buttons(equipment).await?;
-
-                        let mut message_builder = CreateMessage::new().embed(embed);
+                    // Find the equipment and its rendered message
+                    if let (Some((equipment, _)), Some((embed, buttons, hash))) = (
+                        equipment_list.iter().find(|(eq, _)| eq.id == *equipment_id),
+                        rendered.get(equipment_id),
+                    ) {
+                        let mut message_builder = CreateMessage::new().embed(embed.clone());
                         if !buttons.is_empty() {
-                            message_builder = message_builder.components(buttons);
+                            message_builder = message_builder.components(buttons.clone());
                         }
 
+                        reconcile::acquire_channel_slot(channel_id).await;
                         match channel.send_message(&ctx.http, message_builder).await {
                             Ok(message) => {
                                 let message_id = message.id.get() as i64;
@@ -486,16 +968,18 @@ impl EquipmentRenderer {
 
                                 sqlx::query(
                                     "INSERT INTO managed_messages 
-                                     (guild_id, channel_id, message_id, message_type, equipment_id, sort_order)
-                                     VALUES (?, ?, ?, 'EquipmentEmbed', ?, ?)"
+                                     (guild_id, channel_id, message_id, message_type, equipment_id, sort_order, content_hash)
+                                     VALUES (?, ?, ?, 'EquipmentEmbed', ?, ?, ?)"
                                 )
                                 .bind(guild_id)
                                 .bind(channel_id)
                                 .bind(message_id)
                                 .bind(equipment_id)
                                 .bind(sort_order)
+                                .bind(hash)
                                 .execute(&self.db)
                                 .await?;
+                                stats.created += 1;
 
                                 info!(
                                     "Created equipment embed for {} (ID: {})",
@@ -513,20 +997,16 @@ impl EquipmentRenderer {
                 }
 
                 EditAction::EditEquipment(message_id, equipment_id) => {
-                    // Find the equipment and its tag
-                    if let Some((equipment, tag)) =
-                        equipment_list.iter().find(|(eq, _)| eq.id == *equipment_id)
-                    {
-                        let embed = self.create_equipment_embed(equipment, tag).await?;
-                        let buttons = self.create_equipment_buttons(equipment).await?;
-
-                        let mut edit_builder = EditMessage::new().embed(embed);
-                        if !buttons.is_empty() {
-                            edit_builder = edit_builder.components(buttons);
-                        } else {
-                            edit_builder = edit_builder.components(Vec::new());
-                        }
-
+                    // Find the equipment and its rendered message
+                    if let (Some((equipment, _)), Some((embed, buttons, hash))) = (
+                        equipment_list.iter().find(|(eq, _)| eq.id == *equipment_id),
+                        rendered.get(equipment_id),
+                    ) {
+                        let edit_builder = EditMessage::new()
+                            .embed(embed.clone())
+                            .components(buttons.clone());
+
+                        reconcile::acquire_channel_slot(channel_id).await;
                         match channel
                             .edit_message(
                                 &ctx.http,
@@ -536,14 +1016,16 @@ impl EquipmentRenderer {
                             .await
                         {
                             Ok(_) => {
-                                // Update the equipment_id in the database
+                                // Record what the message now shows
                                 sqlx::query(
-                                    "UPDATE managed_messages SET equipment_id = ? WHERE message_id = ?"
+                                    "UPDATE managed_messages SET equipment_id = ?, content_hash = ? WHERE message_id = ?"
                                 )
                                 .bind(equipment_id)
+                                .bind(hash)
                                 .bind(message_id)
                                 .execute(&self.db)
                                 .await?;
+                                stats.edited += 1;
 
                                 info!(
                                     "Updated equipment embed for {} (ID: {})",
@@ -558,31 +1040,247 @@ impl EquipmentRenderer {
                 }
 
                 EditAction::DeleteMessage(message_id) => {
-                    if let Err(e) = channel
-                        .delete_message(&ctx.http, MessageId::new(*message_id as u64))
-                        .await
-                    {
-                        warn!("Failed to delete message {}: {}", message_id, e);
-                    }
-
-                    // Remove from database
+                    // Untrack first, so the delete event is not taken for a manual deletion
                     sqlx::query("DELETE FROM managed_messages WHERE message_id = ?")
                         .bind(message_id)
                         .execute(&self.db)
                         .await?;
 
-                    info!("Deleted message {}", message_id);
+                    reconcile::acquire_channel_slot(channel_id).await;
+                    match channel
+                        .delete_message(&ctx.http, MessageId::new(*message_id as u64))
+                        .await
+                    {
+                        Ok(()) => {
+                            stats.deleted += 1;
+                            info!("Deleted message {}", message_id);
+                        }
+                        Err(e) => {
+                            warn!("Failed to delete message {}: {}", message_id, e);
+                        }
+                    }
                 }
             }
         }
 
+        // Timelines go stale as time passes, so an existing header is redrawn every time
+        if !edit_plan.actions.contains(&EditAction::CreateHeader)
+            && self.header_timeline_enabled(guild_id).await?
+        {
+            if let Err(e) = self.refresh_header(ctx, guild_id, channel_id).await {
+                warn!("Failed to refresh header timelines: {}", e);
+            }
+        }
+
+        reconcile::METRICS.record(&stats);
+        info!(
+            "Equipment display reconciliation completed. {} creates, {} edits, {} deletes, {} unchanged.",
+            stats.created, stats.edited, stats.deleted, stats.skipped
+        );
+        reconcile::log_totals();
+        Ok(stats)
+    }
+
+    /// Fetch the channel's messages, newest first. The flag is false when the
+    /// history was longer than `Constants::CHANNEL_HISTORY_LIMIT` and was cut short.
+    async fn fetch_channel_history(ctx: &Context, channel_id: i64) -> Result<(Vec<Message>, bool)> {
+        let channel = ChannelId::new(channel_id as u64);
+        let mut messages = Vec::new();
+        let mut before: Option<MessageId> = None;
+        loop {
+            let mut request = GetMessages::new().limit(100);
+            if let Some(before) = before {
+                request = request.before(before);
+            }
+            let page = channel.messages(&ctx.http, request).await?;
+            let complete = page.len() < 100;
+            before = page.last().map(|message| message.id);
+            messages.extend(page);
+
+            if complete {
+                return Ok((messages, true));
+            }
+            if messages.len() >= Constants::CHANNEL_HISTORY_LIMIT {
+                return Ok((messages, false));
+            }
+        }
+    }
+
+    /// Compare the channel's history with the messages tracked for it
+    pub async fn check_channel(
+        &self,
+        ctx: &Context,
+        guild_id: i64,
+        channel_id: i64,
+    ) -> Result<ChannelDrift> {
+        let tracked = sqlx::query_scalar!(
+            "SELECT message_id FROM managed_messages WHERE guild_id = ? AND channel_id = ?",
+            guild_id,
+            channel_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let (history, complete) = Self::fetch_channel_history(ctx, channel_id).await?;
+        let bot_id = ctx.cache.current_user().id;
+        let history: Vec<(i64, bool)> = history
+            .iter()
+            .map(|message| {
+                let custom_ids = message
+                    .components
+                    .iter()
+                    .flat_map(|row| row.components.iter())
+                    .filter_map(|component| match component {
+                        ActionRowComponent::Button(Button {
+                            data: ButtonKind::NonLink { custom_id, .. },
+                            ..
+                        }) => Some(custom_id.as_str()),
+                        _ => None,
+                    });
+                let is_display =
+                    message.author.id == bot_id && reconcile::is_display_message(custom_ids);
+                (message.id.get() as i64, is_display)
+            })
+            .collect();
+        Ok(reconcile::find_channel_drift(&tracked, &history, complete))
+    }
+
+    /// Delete every message the bot manages in the channel, along with display
+    /// messages it lost track of, and post them again from the database in tag order.
+    /// `triggered_by` is the admin who asked for it, or None for a self-repair.
+    pub async fn rebuild_channel(
+        &self,
+        ctx: &Context,
+        guild_id: i64,
+        channel_id: i64,
+        triggered_by: Option<i64>,
+    ) -> Result<ChannelRebuild> {
+        let channel = ChannelId::new(channel_id as u64);
+        let drift = self.check_channel(ctx, guild_id, channel_id).await?;
+
+        let tracked = sqlx::query_scalar!(
+            "SELECT message_id FROM managed_messages
+             WHERE guild_id = ? AND channel_id = ?
+             ORDER BY sort_order ASC",
+            guild_id,
+            channel_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let to_delete: Vec<i64> = tracked
+            .into_iter()
+            .filter(|id| !drift.missing.contains(id))
+            .chain(drift.foreign.iter().copied())
+            .collect();
+
+        warn!(
+            "Rebuilding equipment channel {} for guild {}: {} missing, {} foreign, {} to delete",
+            channel_id,
+            guild_id,
+            drift.missing.len(),
+            drift.foreign.len(),
+            to_delete.len()
+        );
+
+        // Untrack everything before deleting, so the delete events do not ask for
+        // another repair
+        sqlx::query!(
+            "DELETE FROM managed_messages WHERE guild_id = ? AND channel_id = ?",
+            guild_id,
+            channel_id
+        )
+        .execute(&self.db)
+        .await?;
+
+        let mut deleted = 0i64;
+        for message_id in &to_delete {
+            reconcile::acquire_channel_slot(channel_id).await;
+            match channel
+                .delete_message(&ctx.http, MessageId::new(*message_id as u64))
+                .await
+            {
+                Ok(()) => deleted += 1,
+                Err(e) => warn!(
+                    "Failed to delete message {} during rebuild: {}",
+                    message_id, e
+                ),
+            }
+        }
+
+        // With nothing tracked, reconciliation posts the header and then every
+        // equipment message in tag order
+        let stats = self
+            .reconcile_equipment_display(ctx, guild_id, channel_id)
+            .await?;
+
+        let missing_count = drift.missing.len() as i64;
+        let foreign_count = drift.foreign.len() as i64;
+        let created_count = stats.created as i64;
+        let rebuild_id = sqlx::query!(
+            "INSERT INTO channel_rebuilds
+             (guild_id, channel_id, triggered_by, missing_count, foreign_count, deleted_count, created_count)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+            guild_id,
+            channel_id,
+            triggered_by,
+            missing_count,
+            foreign_count,
+            deleted,
+            created_count
+        )
+        .execute(&self.db)
+        .await?
+        .last_insert_rowid();
+
         info!(
-            "Equipment display reconciliation completed. {} creates, {} edits, {} deletes.",
-            edit_plan.creates, edit_plan.edits, edit_plan.deletes
+            "Rebuilt equipment channel {} for guild {} (requested by {:?}): {} deleted, {} created",
+            channel_id, guild_id, triggered_by, deleted, created_count
         );
+
+        let rebuild =
+            sqlx::query_as::<_, ChannelRebuild>("SELECT * FROM channel_rebuilds WHERE id = ?")
+                .bind(rebuild_id)
+                .fetch_one(&self.db)
+                .await?;
+        Ok(rebuild)
+    }
+
+    /// Rebuild the channel when its messages no longer match the database,
+    /// otherwise reconcile it as usual
+    pub async fn repair_channel(
+        &self,
+        ctx: &Context,
+        guild_id: i64,
+        channel_id: i64,
+    ) -> Result<()> {
+        match self.check_channel(ctx, guild_id, channel_id).await {
+            Ok(drift) if !drift.is_clean() => {
+                self.rebuild_channel(ctx, guild_id, channel_id, None)
+                    .await?;
+                return Ok(());
+            }
+            Ok(_) => {}
+            Err(e) => warn!(
+                "Could not read history of channel {}, reconciling without it: {}",
+                channel_id, e
+            ),
+        }
+        self.reconcile_equipment_display(ctx, guild_id, channel_id)
+            .await?;
         Ok(())
     }
 
+    /// The most recent rebuild of the guild's reservation channel
+    pub async fn last_rebuild(&self, guild_id: i64) -> Result<Option<ChannelRebuild>> {
+        let rebuild = sqlx::query_as::<_, ChannelRebuild>(
+            "SELECT * FROM channel_rebuilds WHERE guild_id = ? ORDER BY rebuilt_at DESC, id DESC LIMIT 1",
+        )
+        .bind(guild_id)
+        .fetch_optional(&self.db)
+        .await?;
+        Ok(rebuild)
+    }
+
     /// Remove duplicate guide messages if present, keeping only one
     pub async fn cleanup_duplicate_guides(
         &self,
@@ -611,6 +1309,12 @@ impl EquipmentRenderer {
             );
 
             for guide in guide_messages.iter().skip(1) {
+                // Delete from database first, so the delete event is ignored
+                sqlx::query("DELETE FROM managed_messages WHERE id = ?")
+                    .bind(guide.0)
+                    .execute(&self.db)
+                    .await?;
+
                 // Delete from Discord
                 if let Err(e) = channel
                     .delete_message(&ctx.http, MessageId::new(guide.1 as u64))
@@ -621,12 +1325,6 @@ impl EquipmentRenderer {
                         guide.1, e
                     );
                 }
-
-                // Delete from database
-                sqlx::query("DELETE FROM managed_messages WHERE id = ?")
-                    .bind(guide.0)
-                    .execute(&self.db)
-                    .await?;
             }
         }
 
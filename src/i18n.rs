@@ -0,0 +1,2373 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use sqlx::SqlitePool;
+
+use crate::constants::Constants;
+
+/// Languages members can read the bot in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    Ja,
+    En,
+}
+
+/// Language of guilds that have not picked one in /setup
+pub const DEFAULT_LANGUAGE: Language = Language::Ja;
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Ja, Language::En];
+
+    /// Code stored in the database, e.g. "ja"
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Ja => "ja",
+            Language::En => "en",
+        }
+    }
+
+    /// Parse a stored code or a Discord locale such as "en-US"
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim().to_lowercase();
+        let primary = code.split(['-', '_']).next().unwrap_or("");
+        match primary {
+            "ja" => Some(Language::Ja),
+            "en" => Some(Language::En),
+            _ => None,
+        }
+    }
+
+    /// Name of the language in itself, for pickers
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::Ja => "日本語",
+            Language::En => "English",
+        }
+    }
+
+    fn bundle(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::Ja => JA,
+            Language::En => EN,
+        }
+    }
+}
+
+/// Language stored for a guild or user; missing or unknown codes fall back to the default
+pub fn language_or_default(code: Option<&str>) -> Language {
+    code.and_then(Language::from_code)
+        .unwrap_or(DEFAULT_LANGUAGE)
+}
+
+/// Keys of a bundle in catalog order, for consistency checks
+pub fn bundle_keys(lang: Language) -> Vec<&'static str> {
+    lang.bundle().iter().map(|(key, _)| *key).collect()
+}
+
+lazy_static::lazy_static! {
+    static ref CATALOG: HashMap<Language, HashMap<&'static str, &'static str>> = {
+        debug_assert_eq!(bundle_keys(Language::Ja), bundle_keys(Language::En));
+        Language::ALL
+            .iter()
+            .map(|lang| (*lang, lang.bundle().iter().copied().collect()))
+            .collect()
+    };
+}
+
+/// Look up a message. Keys missing from a bundle fall back to English, then to the key itself.
+pub fn t(lang: Language, key: &'static str) -> &'static str {
+    CATALOG
+        .get(&lang)
+        .and_then(|bundle| bundle.get(key))
+        .or_else(|| CATALOG.get(&Language::En).and_then(|bundle| bundle.get(key)))
+        .copied()
+        .unwrap_or(key)
+}
+
+/// Look up a message and fill in its `{name}` placeholders
+pub fn tf(lang: Language, key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    let mut message = t(lang, key).to_string();
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    message
+}
+
+/// Display label for an equipment status; unknown statuses are shown as stored
+pub fn status_label(lang: Language, status: &str) -> &str {
+    match status {
+        "Available" => t(lang, "status.available"),
+        "Loaned" => t(lang, "status.loaned"),
+        "Unavailable" => t(lang, "status.unavailable"),
+        other => other,
+    }
+}
+
+/// Default language configured for a guild in /setup
+pub async fn guild_language(db: &SqlitePool, guild_id: i64) -> anyhow::Result<Language> {
+    let code = sqlx::query_scalar!("SELECT language FROM guilds WHERE id = ?", guild_id)
+        .fetch_optional(db)
+        .await?;
+    Ok(language_or_default(code.as_deref()))
+}
+
+/// Language chosen by the user with /language in a guild, if any
+pub async fn user_preference(
+    db: &SqlitePool,
+    guild_id: i64,
+    user_id: i64,
+) -> anyhow::Result<Option<Language>> {
+    let code = sqlx::query_scalar!(
+        "SELECT language FROM user_preferences WHERE guild_id = ? AND user_id = ?",
+        guild_id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await?
+    .flatten();
+    Ok(code.as_deref().and_then(Language::from_code))
+}
+
+/// Language to address a user in: their preference for the guild, else the guild default
+pub async fn user_language(
+    db: &SqlitePool,
+    guild_id: Option<i64>,
+    user_id: i64,
+) -> anyhow::Result<Language> {
+    let Some(guild_id) = guild_id else {
+        return Ok(DEFAULT_LANGUAGE);
+    };
+    if let Some(lang) = user_preference(db, guild_id, user_id).await? {
+        return Ok(lang);
+    }
+    guild_language(db, guild_id).await
+}
+
+/// Store a user's preference for a guild; None goes back to following the guild default
+pub async fn set_user_language(
+    db: &SqlitePool,
+    guild_id: i64,
+    user_id: i64,
+    lang: Option<Language>,
+) -> anyhow::Result<()> {
+    let code = lang.map(Language::code);
+    sqlx::query!(
+        "INSERT INTO user_preferences (guild_id, user_id, language, updated_at)
+         VALUES (?, ?, ?, CURRENT_TIMESTAMP)
+         ON CONFLICT(guild_id, user_id) DO UPDATE SET language = excluded.language, updated_at = CURRENT_TIMESTAMP",
+        guild_id,
+        user_id,
+        code
+    )
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// English bundle
+const EN: &[(&str, &str)] = &[
+    // Shared
+    ("common.error_prefix", "❌ {message}"),
+    ("error.admin_required", Constants::MSG_ADMIN_REQUIRED),
+    ("error.admin_log_required", Constants::MSG_ADMIN_LOG_REQUIRED),
+    ("error.invalid_time_format", Constants::MSG_INVALID_TIME_FORMAT),
+    ("error.equipment_not_found", Constants::MSG_EQUIPMENT_NOT_FOUND),
+    ("error.reservation_not_found", Constants::MSG_RESERVATION_NOT_FOUND),
+    ("error.operation_not_possible", Constants::MSG_OPERATION_NOT_POSSIBLE),
+    ("error.session_expired", "❌ Session expired. Please try again."),
+    ("success.operation", Constants::MSG_OPERATION_SUCCESS),
+    ("success.reservation_created", Constants::MSG_RESERVATION_CREATED),
+    ("success.reservation_canceled", Constants::MSG_RESERVATION_CANCELED),
+    ("success.equipment_returned", Constants::MSG_EQUIPMENT_RETURNED),
+    ("status.available", "Available"),
+    ("status.loaned", "Loaned"),
+    ("status.unavailable", "Unavailable"),
+    ("common.none", "None"),
+    ("common.unknown", "Unknown"),
+    ("common.enabled", "Enabled"),
+    ("common.disabled", "Disabled"),
+
+    // /language
+    ("language.command_description", "Choose the language the bot uses with you on this server"),
+    ("language.option_description", "Language to use"),
+    ("language.option_default", "Server default"),
+    ("language.set", "✅ I will talk to you in English on this server from now on."),
+    ("language.reset", "✅ Your language now follows the server default ({language})."),
+    (
+        "language.current",
+        "Your language: **{language}**{source}\nUse `/language` with an option to change it.",
+    ),
+    ("language.current_default", " (server default)"),
+
+    // /setup
+    ("setup.command_description", "Set up the equipment lending bot in this channel"),
+    ("setup.admin_required", "❌ You need administrator permissions to use this command."),
+    (
+        "setup.missing_permissions",
+        "❌ **Permission Error**\n\nThe bot is missing the following required permissions in this channel:\n• {permissions}\n\nPlease grant these permissions and try again.",
+    ),
+    ("setup.confirm_title", "🔧 Equipment Lending Bot Setup"),
+    (
+        "setup.confirm_description",
+        "Set {channel} as the reservation channel. Is that okay?\n\n⚠️ **Warning**: This will delete all existing messages in this channel except bot-managed messages.",
+    ),
+    ("setup.confirm_button", "✅ Confirm"),
+    ("setup.cancel_button", "❌ Cancel"),
+    ("setup.back_button", "⬅️ Back"),
+    ("setup.next_button", "➡️ Next"),
+    ("setup.skip_button", "⏭️ Skip"),
+    ("setup.complete_button", "✅ Complete"),
+    ("setup.cancelled", "❌ Setup cancelled."),
+    ("setup.session_expired", "❌ Session expired. Please start setup again."),
+    ("setup.roles_title", "🔧 Setup - Step 1: Admin Roles"),
+    (
+        "setup.roles_description",
+        "Select which roles should have administrative permissions for equipment management.\n\n**Optional**: You can skip this step if you only want Discord administrators to manage equipment.",
+    ),
+    ("setup.roles_placeholder", "Select admin roles (optional)"),
+    ("setup.roles_selected", "✅ Roles selected. Click **Next** to continue."),
+    ("setup.guide_title", "📋 Equipment Lending Management"),
+    ("setup.guide_description", "Please register equipment to get started."),
+    ("setup.overall_management_button", "⚙️ Overall Management"),
+    ("setup.notifications_title", "🔧 Setup - Step 3: Notification Preferences"),
+    ("setup.notifications_description", "Configure reminder notifications for equipment lending:"),
+    ("setup.dm_fallback_name", "📱 DM Fallback"),
+    ("setup.dm_fallback_value", "When DMs fail, send mentions in the reservation channel"),
+    ("setup.pre_start_name", "⏰ Pre-Start Reminder"),
+    ("setup.pre_start_value", "Notify users before their reservation starts (default: 15 minutes)"),
+    ("setup.pre_end_name", "🔔 Pre-End Reminder"),
+    ("setup.pre_end_value", "Notify users before their reservation ends (default: 15 minutes)"),
+    ("setup.overdue_name", "⚠️ Overdue Reminders"),
+    (
+        "setup.overdue_value",
+        "Repeat notifications for unreturned items (default: every 12 hours, max 3 times)",
+    ),
+    (
+        "setup.notifications_footer",
+        "Current settings shown are defaults. Adjust settings below and click Next to continue.",
+    ),
+    ("setup.dm_fallback_enabled_description", "Send channel mentions when DMs fail"),
+    ("setup.dm_fallback_disabled_description", "Only send DMs, no channel fallback"),
+    ("setup.dm_fallback_placeholder", "DM Fallback Setting"),
+    ("setup.minutes_option", "{minutes} minutes"),
+    ("setup.hours_option", "Every {hours} hours"),
+    ("setup.pre_start_placeholder", "Pre-Start Reminder Time"),
+    ("setup.pre_end_placeholder", "Pre-End Reminder Time"),
+    ("setup.overdue_placeholder", "Overdue Reminder Frequency"),
+    (
+        "setup.notifications_updated",
+        "✅ Notification preferences updated. Click **Next** to continue.",
+    ),
+    ("setup.no_admin_roles", "None (only Discord administrators)"),
+    (
+        "setup.notification_summary",
+        "DM Fallback: {dm_fallback}\nPre-Start: {pre_start} min\nPre-End: {pre_end} min\nOverdue: Every {overdue_hours} hrs (max {overdue_max})",
+    ),
+    ("setup.final_title", "🔧 Setup - Step 4: Final Confirmation"),
+    ("setup.final_description", "Please review your configuration:"),
+    ("setup.reservation_channel_name", "Reservation Channel"),
+    ("setup.admin_roles_name", "Admin Roles"),
+    ("setup.notification_settings_name", "Notification Settings"),
+    ("setup.time_zone_name", "Time Zone"),
+    (
+        "setup.time_zone_value",
+        "{name} - {offset}\nAll times are shown and entered in this time zone.",
+    ),
+    ("setup.language_name", "Language"),
+    ("setup.language_value", "{language}\nMembers can choose their own language with /language."),
+    ("setup.final_footer", "Click Complete to finish setup or Cancel to abort."),
+    ("setup.time_zone_placeholder", "Time Zone"),
+    ("setup.language_placeholder", "Language"),
+    ("setup.complete_title", "✅ Setup Complete!"),
+    ("setup.complete_roles_none", "Only Discord administrators will have management access."),
+    ("setup.complete_roles", "Admin roles: {roles}"),
+    (
+        "setup.complete_notifications",
+        "DM Fallback: {dm_fallback}, Pre-Start: {pre_start}min, Pre-End: {pre_end}min, Overdue: Every {overdue_hours}hrs (max {overdue_max})",
+    ),
+    (
+        "setup.complete_description",
+        "Successfully configured {channel} as the reservation channel.\n\n{roles}\n📱 **Notifications:** {notifications}\n🕘 **Time Zone:** {time_zone}\n🌐 **Language:** {language}\n\n🚀 **Next Steps:**\n• Use the **Overall Management** button to add equipment\n• Configure lending/return locations\n• Set up equipment tags for organization",
+    ),
+
+    // Reservation channel
+    ("header.title", "🔧 Equipment Management"),
+    ("header.description", "Manage your equipment reservations and organization settings."),
+    ("header.overall_management_button", "📊 Overall Management"),
+    ("embed.status", "Status"),
+    ("embed.category", "Category"),
+    ("embed.reservable_by", "🔒 Reservable By"),
+    ("embed.current_location", "Current Location"),
+    ("embed.default_return_location", "Default Return Location"),
+    ("embed.unavailable_reason", "Unavailable Reason"),
+    ("embed.awaiting_ack_name", "⚠️ Return Awaiting Acknowledgment"),
+    (
+        "embed.awaiting_ack_value",
+        "Returned to restricted location {location}; an admin needs to acknowledge it",
+    ),
+    ("embed.prep_time", "🧰 Prep Time"),
+    ("embed.current_reservation_name", "Currently Reserved"),
+    ("embed.current_reservation_value", "By: {user}\nUntil: {end}"),
+    ("embed.prep_until", "\nPrep time until: {time}"),
+    ("embed.availability_name", "Availability"),
+    ("embed.availability_value", "Available for reservation"),
+    ("button.reserve", "📅 Reserve"),
+    ("button.pickup", "📦 Pick Up"),
+    ("button.change", "✏️ Check/Change"),
+    ("button.transfer", "🔄 Transfer"),
+    ("button.return", "↩️ Return"),
+    ("button.settings", "⚙️ This Equipment's Settings"),
+
+    // Reminders
+    ("reminder.pre_start", "📅 Reminder: \"{equipment}\" starts in 15 minutes.\nStart time: {time}"),
+    (
+        "reminder.start",
+        "📅 Loan started: your loan of \"{equipment}\" has started.\nStart time: {time}",
+    ),
+    (
+        "reminder.pre_end",
+        "📅 Reminder: \"{equipment}\" is due back in 15 minutes.\nReturn time: {time}",
+    ),
+    (
+        "reminder.overdue",
+        "⚠️ Overdue #{count}: \"{equipment}\" is past its return time.\nDue: {time}",
+    ),
+    (
+        "reminder.no_show_owner",
+        "🚫 Not picked up: your reservation of \"{equipment}\" was released because it was not picked up within {minutes} minutes of the start.\nPeriod: {start} - {end}",
+    ),
+    (
+        "reminder.no_show_announcement",
+        "📢 Slot free: \"{equipment}\" is now free from {start} to {end}.",
+    ),
+
+    // Transfer notifications
+    (
+        "transfer_notice.request_dm",
+        "📤 **Transfer Request**\n\n<@{requester}> would like to transfer their reservation of \"{equipment}\" to you.\n\nCheck the details in your DMs and approve or deny it.\n\n⚠️ This request expires automatically after 3 hours.",
+    ),
+    (
+        "transfer_notice.approved_dm",
+        "✅ **Transfer Approved**\n\nThe transfer request for \"{equipment}\" was approved.",
+    ),
+    (
+        "transfer_notice.denied_dm",
+        "❌ **Transfer Denied**\n\nThe transfer request for \"{equipment}\" was denied.\n\nReason: {reason}",
+    ),
+    (
+        "transfer_notice.cancelled_dm",
+        "🚫 **Transfer Cancelled**\n\nThe transfer request for \"{equipment}\" was cancelled.",
+    ),
+    (
+        "transfer_notice.expired_dm",
+        "⏰ **Transfer Expired**\n\nThe transfer request for \"{equipment}\" was not approved within 3 hours and has been cancelled automatically.",
+    ),
+    (
+        "transfer_notice.request_fallback",
+        "There is a notification about transferring \"{equipment}\". Reservation ID: #{reservation_id}\n\nPlease enable DMs to see the details.",
+    ),
+    (
+        "transfer_notice.update_fallback",
+        "There is an update about transferring \"{equipment}\". Reservation ID: #{reservation_id}",
+    ),
+
+    // Reservation rules
+    ("time_error.empty", "Please enter a date and time."),
+    (
+        "time_error.unrecognized",
+        "Could not read \"{input}\". Use YYYY-MM-DD HH:MM, MM/DD HH:MM, \"tomorrow 14:00\" or \"+2h\".",
+    ),
+    ("time_error.missing_time", "Please add a time of day, e.g. \"1/15 14:30\"."),
+    ("time_error.invalid_date", "{date} is not a valid date."),
+    ("time_error.invalid_time", "{time} is not a valid time."),
+    (
+        "time_error.invalid_duration",
+        "Could not read the length \"{input}\". Use e.g. 90m, 2h30m or 1d.",
+    ),
+    ("time_error.zero_duration", "The length must be longer than zero."),
+    (
+        "time_error.nonexistent",
+        "{time} is skipped by a daylight saving time change. Please pick another time.",
+    ),
+    (
+        "schedule.closed_date_reason",
+        "The clubroom is closed on {date} ({reason}). Please choose another date.",
+    ),
+    ("schedule.closed_date", "The clubroom is closed on {date}. Please choose another date."),
+    ("schedule.closed_weekday", "The clubroom is closed on {weekday}. Please choose another date."),
+    (
+        "schedule.outside_hours",
+        "{time} is outside opening hours ({hours}). Reservations must start and end while the clubroom is open.",
+    ),
+    ("weekday.mon", "Mondays"),
+    ("weekday.tue", "Tuesdays"),
+    ("weekday.wed", "Wednesdays"),
+    ("weekday.thu", "Thursdays"),
+    ("weekday.fri", "Fridays"),
+    ("weekday.sat", "Saturdays"),
+    ("weekday.sun", "Sundays"),
+    ("buffer.none", "None"),
+    ("buffer.before", "{before} min before"),
+    ("buffer.after", "{after} min after"),
+    ("buffer.both", "{before} min before, {after} min after"),
+    (
+        "buffer.conflict",
+        "This time slot is too close to another reservation. This equipment needs {minutes} minutes of prep time between reservations ({buffer}).",
+    ),
+    ("reservation.slot_taken", "This time slot is already reserved. Please select another time."),
+    ("block.scope_all", "all equipment"),
+    ("block.scope_tag", "tag \"{tag}\""),
+    ("block.scope_tag_id", "tag #{tag_id}"),
+    ("block.until", " until {time}"),
+    ("block.message", "You are blocked from reserving {scope}{until}."),
+    (
+        "tag_access.restricted",
+        "Equipment in \"{tag}\" can only be reserved by members with one of these roles: {roles}",
+    ),
+    ("return_correction.not_returned", "❌ This reservation has not been returned."),
+    (
+        "return_correction.not_returner",
+        "❌ Only the member who returned the equipment can correct the return.",
+    ),
+
+    // Durations and dates
+    ("duration.one_day", "1 day"),
+    ("duration.days", "{days} days"),
+    ("duration.minutes", "{minutes} min"),
+    ("duration.hours", "{hours} h"),
+    ("duration.hours_minutes", "{hours} h {minutes} min"),
+    ("weekday_short.mon", "Mon"),
+    ("weekday_short.tue", "Tue"),
+    ("weekday_short.wed", "Wed"),
+    ("weekday_short.thu", "Thu"),
+    ("weekday_short.fri", "Fri"),
+    ("weekday_short.sat", "Sat"),
+    ("weekday_short.sun", "Sun"),
+
+    // Overall management
+    ("mgmt.dashboard_title", "⚙️ Overall Management Dashboard"),
+    ("mgmt.all_equipment", "All Equipment"),
+    ("mgmt.selected_equipment", "{count} selected equipment"),
+    ("mgmt.time_today", "Today"),
+    ("mgmt.time_next_24h", "Next 24 hours"),
+    ("mgmt.time_next_7d", "Next 7 days"),
+    ("mgmt.time_custom", "{start} to {end}"),
+    ("mgmt.time_all", "All Time"),
+    ("mgmt.status_active", "Active Loans"),
+    ("mgmt.status_upcoming", "Upcoming Reservations"),
+    ("mgmt.status_returned_today", "Returned Today"),
+    ("mgmt.status_all", "All Statuses"),
+    ("mgmt.current_filters_name", "🔧 Current Filters"),
+    (
+        "mgmt.current_filters_value",
+        "**Equipment:** {equipment}\n**Time:** {time}\n**Status:** {status}",
+    ),
+    ("mgmt.reservations_name", "📋 Reservations"),
+    ("mgmt.no_matching_reservations", "No reservations match the current filters."),
+    ("mgmt.location_not_specified", "Not specified"),
+    ("mgmt.reservations_page", "📋 Reservations ({from}-{to} of {total})"),
+    ("mgmt.transfer_button", "🔄 Transfer #{index}"),
+    ("mgmt.equipment_filter_button", "🔧 Equipment Filter"),
+    ("mgmt.time_filter_button", "📅 Time Filter"),
+    ("mgmt.status_filter_button", "📊 Status Filter"),
+    ("mgmt.clear_filters_button", "🗑️ Clear All"),
+    ("mgmt.checkin_button", "↩️ Check In"),
+    ("common.previous_button", "⬅️ Previous"),
+    ("common.next_button", "➡️ Next"),
+    ("mgmt.refresh_button", "🔄 Refresh Display"),
+    ("mgmt.export_button", "📊 Export CSV"),
+    ("mgmt.jump_button", "🔗 Jump to Equipment"),
+    ("mgmt.logs_button", "📋 Operation Logs"),
+    ("mgmt.tools_button", "🧰 Admin Tools"),
+
+    // Admin: add tag / location / equipment
+    ("admin.add_tag_title", "Add New Tag"),
+    ("admin.tag_name_label", "Tag Name"),
+    ("admin.tag_name_placeholder", "e.g., Cameras, Audio, Lighting"),
+    ("admin.sort_order_label", "Sort Order"),
+    ("admin.sort_order_placeholder", "Number for ordering (e.g., 1, 2, 3...)"),
+    ("admin.add_location_title", "Add New Location"),
+    ("admin.location_name_label", "Location Name"),
+    ("admin.location_name_placeholder", "e.g., Office A, Lab B, Storage Room"),
+    ("admin.add_equipment_title", "Add New Equipment"),
+    ("admin.equipment_name_label", "Equipment Name"),
+    ("admin.equipment_name_placeholder", "e.g., Sony A7III, Shure SM58"),
+    ("admin.tag_name_optional_placeholder", "Enter existing tag name (optional)"),
+    ("admin.default_location_label", "Default Return Location"),
+    ("admin.location_optional_placeholder", "Enter location name (optional)"),
+    ("admin.refresh_done", "✅ Equipment display refreshed successfully!"),
+    ("admin.refresh_failed", "❌ Failed to refresh equipment display. Check logs for details."),
+    ("admin.tag_name_required", "❌ Tag name is required."),
+    ("admin.sort_order_invalid", "❌ Sort order must be a number."),
+    (
+        "admin.tag_added",
+        "✅ Tag '{name}' added successfully! Use 'Refresh Display' to update the equipment list.",
+    ),
+    ("admin.tag_add_failed", "❌ Failed to add tag. It might already exist."),
+    ("admin.location_name_required", "❌ Location name is required."),
+    ("admin.location_added", "✅ Location '{name}' added successfully!"),
+    ("admin.location_add_failed", "❌ Failed to add location. It might already exist."),
+    ("admin.equipment_name_required", "❌ Equipment name is required."),
+    ("admin.equipment_added", "✅ Equipment '{name}' added successfully!"),
+    ("admin.equipment_added_without_tag", " (Note: Tag not found, equipment added without tag)"),
+    ("admin.refresh_hint", " Use 'Refresh Display' to update the equipment list."),
+    ("admin.equipment_add_failed", "❌ Failed to add equipment. It might already exist."),
+    (
+        "eqset.rename_permission",
+        "❌ You need administrator or equipment manager permissions to rename equipment.",
+    ),
+    ("eqset.rename_empty", "❌ Equipment name cannot be empty."),
+    (
+        "eqset.renamed",
+        "✅ Equipment renamed from '{old}' to '{new}' successfully! Use 'Refresh Display' to see the changes.",
+    ),
+    ("eqset.rename_failed", "❌ Failed to rename equipment. The name might already exist."),
+
+    // Reserve / change buttons
+    ("reserve.not_available", "❌ This equipment is not available for reservation."),
+    ("reservation.suspended", "Your reservations are suspended until {until} due to late returns."),
+    (
+        "change.no_active_reservations",
+        "❌ You don't have any active reservations for this equipment.",
+    ),
+    ("change.manage_title", "🔄 Manage Reservations"),
+    (
+        "change.manage_description",
+        "**Equipment:** {equipment}\n\nSelect a reservation to change or cancel:",
+    ),
+    ("change.no_location", "No location"),
+    ("change.reservation_option", "{start} to {end} - {location}"),
+    ("change.select_placeholder", "Select a reservation to manage..."),
+    (
+        "eqset.permission_required",
+        "❌ You need administrator or equipment manager permissions to access equipment settings.",
+    ),
+
+    // Equipment settings
+    ("eqset.no_upcoming", "No upcoming reservations"),
+    ("eqset.title", "⚙️ Settings - {equipment}"),
+    ("eqset.description", "Configure settings for this equipment"),
+    ("eqset.current_status", "Current Status"),
+    ("eqset.not_set", "Not set"),
+    ("eqset.upcoming_reservations", "Upcoming Reservations"),
+    ("eqset.current_loan_name", "📦 Currently Loaned"),
+    ("eqset.current_loan_value", "{user} • due {due}"),
+    ("eqset.overdue_suffix", " (overdue)"),
+    ("eqset.condition_report_line", "{rating}/{max} by {user} • {time}"),
+    ("eqset.condition_reports", "🛠️ Recent Condition Reports"),
+    (
+        "eqset.awaiting_ack_value",
+        "{user} returned this equipment to restricted location **{location}** at {time}",
+    ),
+    ("eqset.force_state_button", "🔄 Force State Change"),
+    ("eqset.unavailable_reason_button", "⚠️ Set Unavailable Reason"),
+    ("eqset.rename_button", "✏️ Rename Equipment"),
+    ("eqset.default_location_button", "📍 Set Default Return Location"),
+    ("eqset.view_log_button", "📋 View Operation Log"),
+    ("eqset.buffer_button", "⏱️ Set Prep Time"),
+    ("eqset.admin_return_button", "↩️ Check In for Borrower"),
+    ("eqset.assign_tag_button", "🏷️ Assign Tag"),
+    ("eqset.delete_button", "🗑️ Delete Equipment"),
+    ("eqset.ack_return_button", "✅ Acknowledge Return"),
+    ("eqset.cancel_reservation_button", "❌ Cancel {start}"),
+    ("eqset.prep_range", "prep time {start} - {end}"),
+
+    // Equipment settings actions
+    (
+        "eqset.force_state_permission",
+        "❌ You need administrator or equipment manager permissions to force state changes.",
+    ),
+    ("eqset.force_state_coming_soon", "🚧 Force state change functionality coming soon!"),
+    (
+        "eqset.unavailable_reason_permission",
+        "❌ You need administrator or equipment manager permissions to set unavailable reasons.",
+    ),
+    ("eqset.unavailable_reason_coming_soon", "🚧 Set unavailable reason functionality coming soon!"),
+    ("eqset.rename_title", "Rename Equipment"),
+    ("eqset.rename_label", "New Equipment Name"),
+    ("eqset.rename_placeholder", "Enter the new name for this equipment"),
+    ("eqset.assign_tag_admin", "❌ You need administrator permissions to assign tags."),
+    ("eqset.assign_tag_coming_soon", "🚧 Tag assignment functionality coming soon!"),
+    (
+        "eqset.default_location_permission",
+        "❌ You need administrator or equipment manager permissions to set default locations.",
+    ),
+    ("eqset.default_location_coming_soon", "🚧 Default location setting functionality coming soon!"),
+    (
+        "eqset.buffer_permission",
+        "❌ You need administrator or equipment manager permissions to set prep time.",
+    ),
+    ("eqset.buffer_before_label", "Minutes Before Each Reservation"),
+    ("eqset.buffer_after_label", "Minutes After Each Reservation"),
+    ("eqset.buffer_inherit_placeholder", "Leave empty to use the tag's prep time"),
+    ("eqset.buffer_title", "Set Prep Time"),
+    ("eqset.buffer_invalid", "❌ Prep time must be a number of minutes between 0 and {max}."),
+    (
+        "eqset.buffer_updated",
+        "✅ Prep time updated. Effective prep time: **{buffer}**\nExisting reservations are not changed; the prep time applies to new reservations and edits.",
+    ),
+    ("eqset.tag_buffer_title", "Prep Time: {tag}"),
+    (
+        "eqset.tag_buffer_updated",
+        "✅ Prep time for this tag set to **{buffer}**. Equipment with its own prep time keeps it.",
+    ),
+    (
+        "eqset.view_log_permission",
+        "❌ You need administrator or equipment manager permissions to view operation logs.",
+    ),
+
+    // Equipment deletion
+    ("eqset.delete_admin", "❌ You need administrator permissions to delete equipment."),
+    (
+        "eqset.delete_has_reservations",
+        "❌ Cannot delete equipment '{equipment}' because it has {count} active reservation(s). Cancel all reservations first.",
+    ),
+    ("eqset.delete_confirm_title", "⚠️ Confirm Equipment Deletion"),
+    (
+        "eqset.delete_confirm_description",
+        "Are you sure you want to delete the equipment '{equipment}'?\n\n**This action cannot be undone!**\nAll historical data and logs for this equipment will be permanently removed.",
+    ),
+    ("eqset.delete_confirm_button", "🗑️ Yes, Delete Equipment"),
+    ("common.cancel_button", "❌ Cancel"),
+    ("eqset.delete_missing", "❌ Equipment not found or already deleted."),
+    (
+        "eqset.deleted",
+        "✅ Equipment '{equipment}' has been permanently deleted. Use 'Refresh Display' to update the equipment list.",
+    ),
+    ("eqset.delete_cancelled", "❌ Equipment deletion cancelled."),
+
+    // Pickup and return buttons
+    (
+        "pickup.done",
+        "📦 **Equipment Picked Up!**\n\n**Equipment:** {equipment}\n**Return By:** {due} ({offset})\n\nUse the **Return** button when you bring it back.",
+    ),
+    (
+        "pickup.not_ready",
+        "You have no reservation ready for pickup. Pickup opens {minutes} minutes before your reservation starts.",
+    ),
+    ("pickup.not_available", "This equipment is currently {status} and cannot be picked up."),
+    (
+        "return.nothing_to_return",
+        "❌ You don't have any active reservations that can be returned for this equipment.",
+    ),
+    ("return.select_title", "↩️ Select Reservation to Return"),
+    (
+        "return.select_description",
+        "**Equipment:** {equipment}\n\nYou have multiple active reservations for this equipment. Please select which one you want to return:",
+    ),
+    ("return.option_location", "Location: {location}"),
+    ("return.select_placeholder", "Choose a reservation to return..."),
+    ("return.modal_title", "Return {equipment}"),
+    ("return.location_label", "Return Location"),
+    ("return.location_placeholder", "Where are you returning this equipment?"),
+    ("return.condition_label", "Condition (1-{max})"),
+    ("return.condition_placeholder", "5 = perfect, 1 = broken"),
+    ("return.damage_label", "Damage Note"),
+    ("return.damage_placeholder", "Describe any damage or missing parts (optional)"),
+
+    // Reservation wizard
+    (
+        "wizard.opening_hours",
+        "\n\n🕘 **Opening Hours ({zone}):** Reservations must start and end while open.\n```\n{hours}\n```",
+    ),
+    ("wizard.closed_dates", "\n📅 **Closed:** {dates}"),
+    ("wizard.times_in", "Times are in {offset}"),
+    (
+        "wizard.step1_description",
+        "**Equipment:** {equipment}\n\n**Step 1:** Choose a date, then a start time. Times that are already reserved or outside opening hours are not listed.",
+    ),
+    ("wizard.no_free_dates", "\n\n⚠️ There are no free start times in the next {days} days."),
+    ("wizard.date_placeholder", "📅 Choose a date"),
+    ("wizard.start_slot_range", "🕐 Start time ({from} - {to})"),
+    ("wizard.start_slot_placeholder", "🕐 Choose a start time"),
+    (
+        "wizard.step1_manual_hint",
+        "\n\n⌨️ Need an exact time? Use **Enter Manually**, e.g. `2025-01-15 14:30`, `1/15 14:30` or `tomorrow 14:00`.",
+    ),
+    ("wizard.step1_title", "📅 Reserve Equipment - Step 1/3"),
+    ("wizard.manual_button", "⌨️ Enter Manually"),
+    (
+        "wizard.step2_description",
+        "**Equipment:** {equipment}\n**Start Time:** {start}\n\n**Step 2:** Choose how long you need it. Lengths that run into another reservation or past closing time are not listed.",
+    ),
+    ("wizard.no_duration_fits", "\n\n⚠️ No length fits after this start time. Please reselect."),
+    ("wizard.duration_option", "{length} (until {end})"),
+    ("wizard.duration_placeholder", "⏳ Choose a length"),
+    (
+        "wizard.step2_manual_hint",
+        "\n\n⌨️ Need an exact end time? Use **Enter Manually** with a date and time or a length such as `2h30m` (within {days} days).",
+    ),
+    ("wizard.step2_title", "📅 Reserve Equipment - Step 2/3"),
+    ("wizard.reselect_button", "← Reselect"),
+    (
+        "wizard.step3_description",
+        "**Equipment:** {equipment}\n**Start Time:** {start}\n**End Time:** {end}\n\n**Step 3:** Please specify the return location (optional).\n\n📍 You can use the default location or enter a custom one.",
+    ),
+    ("wizard.step3_title", "📍 Reserve Equipment - Step 3/3"),
+    ("wizard.enter_location_button", "📍 Enter Location"),
+    ("wizard.default_location_button", "📍 Use Default ({location})"),
+    ("wizard.skip_location_button", "⏭️ Skip Location"),
+    ("wizard.session_expired", "❌ Session expired. Please start the reservation process again."),
+    ("wizard.conflict_title", "⚠️ Reservation Conflict Detected"),
+    ("wizard.reselect_time_button", "← Reselect Time"),
+    ("wizard.confirm_title", "✅ Confirm Reservation"),
+    (
+        "wizard.confirm_description",
+        "**Equipment:** {equipment}\n**Start Time:** {start}\n**End Time:** {end}\n**Return Location:** {location}{prep}\n\n🔍 **Conflict Check:** ✅ No conflicts detected\n\nPlease confirm your reservation details.",
+    ),
+    ("wizard.prep_line", "\n**Prep Time:** {buffer}"),
+    ("wizard.confirm_button", "✅ Confirm Reservation"),
+    ("common.back_button", "⬅️ Back"),
+
+    // Reservation create / edit / cancel
+    ("reservation.created_with_id", "✅ Reservation created successfully! (ID: {id})"),
+    ("reservation.not_found_or_cancelled", "❌ Reservation not found or already cancelled."),
+    ("reservation.edit_own_only", "❌ You can only edit your own reservations."),
+    ("reservation.edit_title", "Edit Reservation - {equipment}"),
+    ("time_input.start_label", "Start Time"),
+    ("time_input.end_label", "End Time"),
+    ("time_input.start_placeholder", "YYYY-MM-DD HH:MM, 1/15 14:30 or tomorrow 14:00 ({zone})"),
+    ("time_input.end_placeholder", "YYYY-MM-DD HH:MM ({zone}) or a length such as 2h30m"),
+    ("time_input.location_optional_label", "Return Location (Optional)"),
+    ("reservation.updated", "✅ Reservation updated successfully!"),
+    ("reservation.cancel_own_only", "❌ You can only cancel your own reservations."),
+    (
+        "reservation.cancelled_details",
+        "✅ Reservation cancelled successfully!\n\n**Equipment:** {equipment}\n**Period:** {start} to {end}",
+    ),
+    (
+        "error.manager_required",
+        "❌ You need administrator or equipment manager permissions to use this feature.",
+    ),
+    (
+        "reservation.admin_cancelled_details",
+        "✅ Reservation cancelled by admin!\n\n**Equipment:** {equipment}\n**Original User:** {user}\n**Period:** {start} to {end}",
+    ),
+    ("time_input.invalid_start", "Invalid start time. {reason}"),
+    ("time_input.invalid_end", "Invalid end time. {reason}"),
+    ("time_input.end_before_start", "End time must be after start time."),
+    ("time_input.start_in_past", "Start time cannot be in the past."),
+    (
+        "time_input.too_far_ahead",
+        "Reservation cannot extend more than {days} days into the future.",
+    ),
+    ("reservation.not_found", "Reservation not found"),
+    ("reservation.not_found_or_cancelled_plain", "Reservation not found or already cancelled"),
+
+    // Reservation wizard inputs
+    ("wizard.start_modal_title", "Enter Start Time"),
+    ("wizard.start_input_label", "Start Date & Time"),
+    ("wizard.end_modal_title", "Enter End Time"),
+    ("wizard.end_input_label", "End Date & Time"),
+    ("wizard.location_modal_title", "Enter Return Location"),
+    ("wizard.location_placeholder", "Where will you return this equipment?"),
+    (
+        "wizard.created",
+        "✅ **Reservation Created Successfully!**\n\n🆔 **Reservation ID:** {id}\n📅 **Period:** {start} to {end} ({zone})\n\nYour equipment reservation is now confirmed!",
+    ),
+    ("wizard.create_failed", "❌ **Failed to Create Reservation**\n\n{reason}"),
+    ("wizard.invalid_state", "❌ Invalid reservation state. Please start again."),
+    (
+        "wizard.cancelled",
+        "❌ **Reservation Cancelled**\n\nThe reservation process has been cancelled. You can start a new reservation anytime by clicking the Reserve button on any available equipment.",
+    ),
+    ("wizard.start_in_past", "❌ Start time cannot be in the past. Please choose a future time."),
+
+    // Change / cancel reservation
+    ("change.not_found_or_cancelled", "❌ Reservation not found or has been cancelled."),
+    ("change.manage_own_only", "❌ You can only manage your own reservations."),
+    ("change.manage_one_title", "🔧 Manage Reservation"),
+    (
+        "change.manage_one_description",
+        "**Equipment:** {equipment}\n**Period:** {start} to {end}\n**Location:** {location}\n\nWhat would you like to do?",
+    ),
+    ("change.time_button", "📅 Change Time"),
+    ("change.location_button", "📍 Change Location"),
+    ("change.cancel_button", "❌ Cancel Reservation"),
+    ("change.time_modal_title", "Change Reservation Time - {equipment}"),
+    ("change.new_start_label", "New Start Time"),
+    ("change.new_end_label", "New End Time"),
+    ("change.location_modal_title", "Change Return Location - {equipment}"),
+    ("change.new_location_label", "New Return Location"),
+    ("change.location_placeholder", "Leave empty to remove location"),
+    ("change.cancel_confirm_title", "⚠️ Cancel Reservation"),
+    (
+        "change.cancel_confirm_description",
+        "**Equipment:** {equipment}\n**Period:** {start} to {end}\n\n❌ **Warning:** This action cannot be undone!\n\nAre you sure you want to cancel this reservation?",
+    ),
+    ("change.cancel_yes_button", "❌ Yes, Cancel"),
+    ("change.cancel_no_button", "↩️ No, Go Back"),
+    (
+        "change.cancelled",
+        "✅ **Reservation Cancelled Successfully!**\n\nYour reservation has been cancelled.",
+    ),
+    ("change.cancel_failed", "❌ **Failed to Cancel Reservation**\n\n{reason}"),
+    ("change.cancel_aborted", "↩️ **Cancellation Aborted**\n\nYour reservation remains active."),
+    (
+        "change.time_updated",
+        "✅ **Reservation Time Updated!**\n\n📅 **New Period:** {start} to {end} ({zone})",
+    ),
+    ("change.update_failed", "❌ **Failed to Update Reservation**\n\n{reason}"),
+    (
+        "change.location_updated",
+        "✅ **Reservation Location Updated!**\n\n📍 **New Location:** {location}",
+    ),
+    ("change.location_update_failed", "❌ **Failed to Update Location**\n\n{reason}"),
+
+    // Return modal
+    ("return.processing_error", "❌ Error processing return request."),
+    ("return.location_required", "❌ Return location is required."),
+    ("return.condition_invalid", "❌ Condition must be a number from 1 to {max}."),
+
+    // Transfer requests
+    ("transfer.user_not_found", "❌ User not found in this server."),
+    ("transfer.bot_target", "❌ Cannot transfer to a bot user."),
+    ("transfer.already_returned", "❌ Cannot transfer a returned reservation."),
+    ("transfer.already_ended", "❌ Cannot transfer a reservation that has ended."),
+    ("transfer.own_only", "❌ You can only transfer your own reservations, or be an administrator."),
+    ("transfer.same_user", "❌ Cannot transfer to the same user."),
+    ("transfer.target_restricted", "❌ {user} cannot receive this reservation. {reason}"),
+
+    // Return confirmation and corrections
+    ("return.unknown_equipment", "Unknown Equipment"),
+    (
+        "return.not_default_warning",
+        "\n\n⚠️ **Are you sure this location is correct?** This equipment is normally returned to **{location}**.",
+    ),
+    (
+        "return.restricted_warning",
+        "\n\n🛡️ **{location}** is a restricted location. An admin will need to acknowledge this return.",
+    ),
+    (
+        "return.damage_warning",
+        "\n\n🛠️ A damage report will be sent to the admins. You can reply to the bot's DM with photos afterwards.",
+    ),
+    ("return.confirm_title", "↩️ Confirm Equipment Return"),
+    ("return.location_marker", "**Return Location:** "),
+    (
+        "return.confirm_description",
+        "**Equipment:** {equipment}\n**Reservation Period:** {start} to {end}\n**Original Location:** {original}\n**Return Location:** {location}{warnings}\n\nPlease confirm that you want to return this equipment now.",
+    ),
+    ("return.confirm_footer", "This action cannot be undone without admin assistance"),
+    ("return.condition_field", "Condition"),
+    ("return.damage_field", "Damage Note"),
+    ("return.confirm_button", "✅ Confirm Return"),
+    ("return.confirm_here_button", "✅ Yes, Return Here"),
+    ("return.correct_button", "✏️ Correct Return"),
+    (
+        "return.returned",
+        "✅ **Equipment Returned Successfully!**\n\n📦 **Equipment:** {equipment}\n📍 **Return Location:** {location}\n🕐 **Return Time:** {time}\n\n{details}\n\nMade a mistake? You can correct this return for up to {hours} hour(s), unless the next reservation is about to start.",
+    ),
+    ("return.failed", "❌ **Failed to Return Equipment**\n\n{error}"),
+    ("return.cancelled", "❌ Return cancelled."),
+    ("return.undo_button", "↩️ Cancel Return"),
+    ("return.fix_location_button", "📍 Fix Return Location"),
+    (
+        "return.correct_prompt",
+        "✏️ **Correct Return**\n\nYou can cancel this return or fix its return location until {deadline}.",
+    ),
+    (
+        "return.undone",
+        "↩️ **Return Cancelled**\n\nThe equipment is on loan to you again. Please return it before the reservation ends.",
+    ),
+    ("return.fix_location_title", "Fix Return Location"),
+    ("return.fix_location_placeholder", "Where did you actually return this equipment?"),
+    ("return.location_corrected", "✅ Return location corrected to **{location}**."),
+
+    // Condition reports
+    (
+        "condition.damage_alert",
+        "🛠️ **Damage Reported** {admins}\n\n📦 **Equipment:** {equipment}\n👤 **Returned By:** {user}\n⭐ **Condition:** {rating}/{max}\n📝 **Note:** {note}",
+    ),
+    ("condition.no_note", "None"),
+    ("condition.mark_unavailable_button", "🚧 Mark Unavailable"),
+    (
+        "condition.photo_request",
+        "🛠️ Thanks for reporting damage to **{equipment}**. Reply here with photos within {minutes} minutes and they will be attached to your report.",
+    ),
+    ("condition.details_filed", "\n🛠️ **Damage Report:** filed"),
+    ("condition.details_alerted", " and sent to the admins"),
+    ("condition.details_dm_sent", ". Reply to the bot's DM with photos of the damage."),
+    ("condition.details_dm_failed", ". Enable DMs from server members to send photos."),
+    ("condition.report_not_found", "❌ Condition report not found."),
+    (
+        "condition.permission_required",
+        "❌ You need administrator or equipment manager permissions to take equipment out of service.",
+    ),
+    (
+        "condition.marked_unavailable",
+        "🚧 Equipment marked **Unavailable** by {user}.\n**Reason:** {reason}",
+    ),
+    ("condition.already_applied", "ℹ️ This report has already been applied."),
+    ("condition.photo_link", "[Photo {number}]({url})"),
+    ("condition.photos_posted", "📷 Photos for the damage report above: {photos}"),
+    (
+        "condition.photos_attached",
+        "📷 Attached {count} photo(s) to your damage report ({total} in total).",
+    ),
+
+    // Return processing
+    ("return.error_not_found", "Reservation not found"),
+    ("return.error_own_only", "You can only return your own reservations"),
+    ("return.error_already_returned", "This reservation has already been returned"),
+    ("return.error_not_started", "Cannot return equipment before the reservation period starts"),
+    (
+        "return.details",
+        "📅 **Reservation Period:** {start} to {end}\n📍 **Original Location:** {original}",
+    ),
+    (
+        "return.details_restricted",
+        "\n🛡️ **Restricted Location:** an admin will acknowledge this return.",
+    ),
+    ("return.details_late", "\n⚠️ **Late Return:** +{points} penalty point(s) (total {total})"),
+    ("return.details_suspended", "\n⛔ **New reservations suspended until:** {until}"),
+
+    // Management filters and export
+    (
+        "mgmt.no_equipment",
+        "❌ No equipment found. Add equipment first using the Overall Management panel.",
+    ),
+    ("mgmt.all_equipment_description", "Show reservations for all equipment"),
+    ("mgmt.filter_by", "Filter by {equipment}"),
+    ("mgmt.equipment_select_placeholder", "Select equipment to filter by..."),
+    (
+        "mgmt.equipment_filter_prompt",
+        "🔧 **Equipment Filter**\nSelect which equipment to show reservations for:",
+    ),
+    ("mgmt.time_today_button", "📅 Today"),
+    ("mgmt.time_24h_button", "🕐 Next 24h"),
+    ("mgmt.time_7d_button", "📊 Next 7 days"),
+    ("mgmt.time_custom_button", "⚙️ Custom"),
+    ("mgmt.time_all_button", "🌐 All Time"),
+    ("mgmt.time_filter_prompt", "📅 **Time Filter**\nSelect time range for reservations:"),
+    ("mgmt.status_active_button", "🟢 Active"),
+    ("mgmt.status_upcoming_button", "🟡 Upcoming"),
+    ("mgmt.status_returned_button", "🔄 Returned Today"),
+    ("mgmt.status_all_button", "📊 All Status"),
+    ("mgmt.status_filter_prompt", "📊 **Status Filter**\nSelect reservation status:"),
+    ("mgmt.refreshing", "🔄 Refreshing display... Please wait."),
+    ("mgmt.refreshed", "✅ Display refreshed successfully!"),
+    ("mgmt.refresh_failed", "❌ Failed to refresh display. Check logs for details."),
+    ("mgmt.export_generating", "📊 Generating CSV export... Please wait."),
+    ("mgmt.export_selected", "{count} selected"),
+    ("mgmt.export_all", "All"),
+    ("mgmt.time_custom_short", "Custom"),
+    (
+        "mgmt.export_summary",
+        "📊 **CSV Export Summary**\n**Total Reservations:** {count}\n**Applied Filters:**\n• Equipment: {equipment}\n• Time: {time}\n• Status: {status}\n\n*CSV download feature coming soon. Data preview:*\n```\n{preview}```",
+    ),
+
+    // Admin tools
+    ("tools.grace_after_start", "{minutes} minutes after start"),
+    ("tools.disabled", "Disabled"),
+    ("tools.title", "🧰 Admin Tools"),
+    ("tools.description", "Guild-wide lending policies and member records."),
+    ("tools.noshow_name", "🚫 No-Show Release"),
+    (
+        "tools.noshow_value",
+        "Reservations not picked up in time are released automatically.\n**Grace Period:** {grace}",
+    ),
+    ("tools.penalty_name", "⚖️ Late-Return Penalties"),
+    (
+        "tools.penalty_value",
+        "{points} point(s) per started hour past the end time.\n**Suspension:** {suspension}",
+    ),
+    ("tools.suspension_policy", "{days} days at {threshold} points"),
+    ("tools.restricted_name", "🛡️ Restricted Return Locations"),
+    ("tools.restricted_none", "None - returns to any location are accepted"),
+    ("tools.restricted_value", "{locations}\nReturns here need an admin's acknowledgment."),
+    ("tools.hours_name", "🕘 Opening Hours"),
+    ("tools.hours_enforced", "Reservations must start and end during opening hours"),
+    ("tools.always_open", "Always open"),
+    ("tools.grace_disable_option", "Disable no-show release"),
+    ("tools.grace_option", "{minutes} minutes"),
+    ("tools.grace_placeholder", "No-show grace period"),
+    ("tools.threshold_disable_option", "Disable penalty suspension"),
+    ("tools.threshold_option", "Suspend at {points} points"),
+    ("tools.threshold_placeholder", "Penalty threshold"),
+    ("tools.days_option", "Suspend for {days} day(s)"),
+    ("tools.days_placeholder", "Suspension length"),
+    ("tools.noshow_list_button", "🚫 No-Show Records"),
+    ("tools.penalty_list_button", "⚖️ Penalty Points"),
+    ("tools.blocklist_button", "⛔ Blocklist"),
+    ("tools.tag_access_button", "🏷️ Tag Access"),
+    ("tools.back_button", "⬅️ Back to Management"),
+    ("tools.restricted_button", "🛡️ Restricted Locations"),
+    ("tools.hours_button", "🕘 Opening Hours"),
+
+    // No-shows, penalties and tag access
+    ("tools.noshow_empty", "No no-shows have been recorded."),
+    ("tools.noshow_line", "**{rank}.** {user} • {count} no-show(s) • last: {last}"),
+    ("tools.noshow_title", "🚫 No-Show Records"),
+    ("tools.noshow_footer", "Top 20 members by no-show count"),
+    ("tools.back_to_tools_button", "⬅️ Back to Admin Tools"),
+    ("tools.penalty_empty", "No members have penalty points or suspensions."),
+    ("tools.penalty_suspended_until", " • ⛔ suspended until {until}"),
+    ("tools.penalty_line", "**{rank}.** {user} • {points} point(s){suspension}"),
+    ("tools.penalty_title", "⚖️ Penalty Points"),
+    ("tools.penalty_footer", "Suspended members first, then by points"),
+    ("tools.forgive_button", "🕊️ Forgive"),
+    ("tools.adjust_button", "✏️ Adjust Points"),
+    ("tools.forgive_title", "Forgive Penalties"),
+    ("tools.adjust_title", "Adjust Penalty Points"),
+    ("tools.member_label", "Member (user ID or mention)"),
+    ("tools.points_delta_label", "Point change"),
+    ("tools.points_delta_placeholder", "e.g. -3 or 2"),
+    ("tools.reason_label", "Reason"),
+    ("tools.invalid_member", "❌ Please enter a valid user ID or mention."),
+    ("tools.forgiven", "🕊️ Cleared penalty points and any suspension for {user}."),
+    ("tools.points_delta_invalid", "❌ Point change must be a whole number (e.g. -3 or 2)."),
+    ("tools.points_adjusted", "✏️ Penalty points for {user} are now **{total}**."),
+    (
+        "tools.points_adjusted_suspended",
+        "\n⛔ This reached the suspension threshold. New reservations are suspended until {until}.",
+    ),
+    ("tag_access.no_tags", "No tags have been created yet."),
+    ("tag_access.line", "**{tag}** • reservable by {allowed} • managed by {managers}"),
+    ("tag_access.everyone", "everyone"),
+    ("tag_access.admins_only", "admins only"),
+    ("tag_access.prep_time", " • prep time {buffer}"),
+    ("tag_access.title", "🏷️ Tag Access"),
+    ("tag_access.footer", "Select a tag to set who can reserve and who manages its equipment"),
+    ("tag_access.select_placeholder", "Select a tag"),
+    ("tag_access.roles_placeholder", "Roles allowed to reserve \"{tag}\""),
+    ("tag_access.managers_placeholder", "Roles that manage \"{tag}\" equipment"),
+    ("tag_access.allow_everyone_button", "🔓 Allow Everyone"),
+    ("tag_access.prep_time_button", "⏱️ Prep Time"),
+
+    // Calendar import
+    ("hours.ics_no_events", "❌ No events were found in this calendar file."),
+    ("hours.ics_imported", "✅ Imported {count} closed date(s) from `{file}`."),
+    ("hours.ics_download_failed", "❌ Failed to download the calendar file. Please try again."),
+
+    // Return acknowledgment and staff check-in
+    ("ack.done", "✅ Return acknowledged and recorded in the operation log."),
+    ("ack.already_done", "ℹ️ This return has already been acknowledged."),
+    (
+        "checkin.permission_required",
+        "❌ You need administrator or equipment manager permissions to check in equipment for others.",
+    ),
+    ("checkin.no_active_loan", "ℹ️ This equipment has no active loan to check in."),
+    ("checkin.no_loans", "ℹ️ No equipment is currently on loan."),
+    ("checkin.unknown_user", "User {id}"),
+    ("checkin.select_placeholder", "Select the equipment to check in..."),
+    (
+        "checkin.select_prompt",
+        "↩️ **Check In for Borrower**\nSelect the equipment that was handed back to you. Overdue loans are listed first.",
+    ),
+    ("checkin.already_returned", "ℹ️ This equipment has already been returned."),
+    ("checkin.location_placeholder", "Where was the equipment checked in?"),
+    ("checkin.modal_title", "Check In: {equipment}"),
+    ("checkin.failed", "❌ **Failed to Check In Equipment**\n\n{error}"),
+    (
+        "checkin.owner_notice",
+        "↩️ Your reservation for **{equipment}** was checked in by {staff}.\n📍 **Return Location:** {location}\n🕐 **Return Time:** {time}",
+    ),
+    (
+        "checkin.done",
+        "✅ **Equipment Checked In**\n\n📦 **Equipment:** {equipment}\n👤 **Reservation Owner:** {owner}\n🙋 **Checked In By:** {staff}\n📍 **Return Location:** {location}\n🕐 **Return Time:** {time}\n\n{details}{notice}",
+    ),
+    ("checkin.owner_not_notified", "\n\n⚠️ The owner could not be notified by DM."),
+    ("restricted.no_locations", "No locations have been registered yet."),
+    (
+        "restricted.description",
+        "Returns to a restricted location need an admin's acknowledgment. Select the restricted locations below.",
+    ),
+    ("restricted.placeholder", "Restricted return locations"),
+
+    // Opening hours
+    ("hours.always_open", "Always open - reservations are accepted at any time"),
+    ("hours.no_closed_dates", "No upcoming closed dates"),
+    ("hours.description", "Reservations must start and end while the clubroom is open."),
+    ("hours.weekly_field", "Weekly Hours ({zone})"),
+    ("hours.closed_field", "📅 Upcoming Closed Dates"),
+    ("hours.footer", "Import closed dates from a university or holiday calendar with Import ICS"),
+    ("hours.reopen_placeholder", "Reopen closed dates..."),
+    ("hours.edit_button", "✏️ Edit Weekly Hours"),
+    ("hours.closed_add_button", "➕ Add Closed Dates"),
+    ("hours.import_button", "📥 Import ICS"),
+    ("hours.weekly_label", "Weekly Hours (local time)"),
+    (
+        "hours.weekly_placeholder",
+        "Mon-Fri 09:00-21:00\nSat 10:00-17:00\nSun closed\n(empty = always open)",
+    ),
+    ("hours.weekly_modal_title", "Weekly Opening Hours"),
+    ("hours.cleared", "✅ Opening hours cleared. Reservations are accepted at any time."),
+    (
+        "hours.updated",
+        "✅ Opening hours updated:\n```\n{hours}\n```Existing reservations are not changed.",
+    ),
+    ("hours.closed_modal_title", "Add Closed Dates"),
+    ("hours.closed_label", "Closed Dates"),
+    (
+        "hours.closed_placeholder",
+        "2025-01-01 New Year's Day\n2025-08-10..2025-08-20 Summer closure",
+    ),
+    ("hours.no_dates", "❌ No dates were entered."),
+    ("hours.closed_added", "✅ Added {count} closed date(s)."),
+    (
+        "hours.import_prompt",
+        "📥 **Import Closed Dates**\n\nSend the `.ics` calendar file to me by direct message within {minutes} minutes. Every event in the calendar is added as a closed date, using its title as the reason.",
+    ),
+    ("hours.input_error", "Line {line}: {problem} (\"{text}\")"),
+    ("hours.error_missing_hours", "expected a weekday followed by hours"),
+    ("hours.error_unknown_weekday", "unknown weekday"),
+    ("hours.error_weekday_order", "weekday ranges must run from Monday towards Sunday"),
+    ("hours.error_hours_format", "hours must look like 09:00-21:00"),
+    ("hours.error_invalid_time", "invalid time"),
+    ("hours.error_close_before_open", "closing time must be after opening time"),
+    ("hours.error_invalid_date", "invalid date"),
+    ("hours.error_date_range", "ranges must end after they start and span at most {days} days"),
+
+    // Blocklist
+    ("blocklist.empty", "No members are blocked."),
+    ("blocklist.no_expiry", "no expiry"),
+    ("blocklist.line", "• {user} • {scope} • until {until} • by {admin}{reason}"),
+    ("blocklist.title", "⛔ Blocklist"),
+    (
+        "blocklist.footer",
+        "Blocked members cannot reserve or receive transfers in the blocked scope",
+    ),
+    ("blocklist.option", "User {id} • {scope}"),
+    ("blocklist.lift_placeholder", "Lift a block"),
+    ("blocklist.add_button", "➕ Block Member"),
+    ("blocklist.modal_title", "Block Member"),
+    ("blocklist.tag_label", "Tag (blank = all)"),
+    ("blocklist.tag_placeholder", "Leave blank to block all equipment"),
+    ("blocklist.days_label", "Days (blank = no expiry)"),
+    ("blocklist.days_placeholder", "e.g. 30"),
+    ("blocklist.invalid_member", "Please enter a valid user ID or mention."),
+    ("blocklist.tag_not_found", "Tag \"{tag}\" not found."),
+    ("blocklist.days_invalid", "Days must be a positive whole number."),
+    ("blocklist.scope_tag", "tag \"{tag}\""),
+    ("blocklist.scope_all", "all equipment"),
+    ("blocklist.until", " until {until}"),
+    ("blocklist.blocked", "⛔ {user} is now blocked from reserving {scope}{until}."),
+
+    // Transfers
+    (
+        "transfer.no_own_reservations",
+        "❌ You don't have any active or upcoming reservations for this equipment.",
+    ),
+    ("transfer.no_reservations", "❌ No active or upcoming reservations found for this equipment."),
+    ("transfer.returned", "❌ Cannot transfer a returned reservation."),
+    (
+        "transfer.owner_or_admin",
+        "❌ You can only transfer your own reservations, or be an administrator.",
+    ),
+
+    // Operation log viewer
+    ("logs.title", "📋 Operation Log Viewer"),
+    ("logs.time_last_7d", "Last 7 Days"),
+    ("logs.time_last_30d", "Last 30 Days"),
+    ("logs.all_actions", "All Actions"),
+    ("logs.filters_name", "🔍 Current Filters"),
+    ("logs.filters_value", "**Time:** {time}\n**Equipment:** {equipment}\n**Action:** {action}"),
+    ("logs.results_name", "📊 Results"),
+    ("logs.results_value", "Showing {from}-{to} of {total} total log entries (Page {page})"),
+    ("logs.unknown_equipment", "Unknown"),
+    ("logs.entries_name", "📋 Log Entries"),
+    ("logs.no_entries", "No log entries found for the current filters."),
+    ("logs.action_filter_button", "⚡ Action Filter"),
+    ("logs.refresh_button", "🔄 Refresh"),
+
+    // Log filters and equipment log
+    ("logs.time_placeholder", "Select time period"),
+    ("logs.time_prompt", "Please select a time period:"),
+    ("logs.equipment_filter_pending", "🚧 Equipment filter not yet implemented."),
+    ("logs.action_filter_pending", "🚧 Action filter not yet implemented."),
+    ("logs.export_pending", "🚧 Log export not yet implemented."),
+    ("logs.equipment_title", "📋 Operation Log - {equipment}"),
+    ("logs.equipment_empty", "No operation logs found for this equipment."),
+    ("logs.entry", "{emoji} **{action}** {notes}\n**Time:** {time}\n**User:** {user}\n"),
+    ("logs.entry_location", "**Location:** {location}\n"),
+    ("logs.entry_status", "**Status:** {from} → {to}\n"),
+    ("logs.equipment_footer", "Page {page} • {total} total logs"),
+    ("common.close_button", "❌ Close"),
+    ("logs.next_button", "Next ➡️"),
+
+    // Transfer requests
+    ("transfer.select_user", "🔄 Who should take over **{equipment}** ({start} → {end})?"),
+    ("transfer.user_placeholder", "Select the new owner"),
+    ("transfer.modal_title", "Transfer Reservation - {equipment}"),
+    ("transfer.note_label", "Note (Optional)"),
+    ("transfer.note_placeholder", "Optional note for the transfer"),
+    ("transfer.request_not_found", "❌ Transfer request not found or already processed."),
+    (
+        "transfer.cancel_not_allowed",
+        "❌ You can only cancel transfer requests you created, or be an administrator.",
+    ),
+    (
+        "transfer.cancelled",
+        "🚫 **Transfer Cancelled**\n\n👤 **From:** {from}\n👤 **To:** {to}\n🕐 **Was Scheduled For:** {time} ({zone})\n✅ **Cancelled by:** {user}",
+    ),
+    ("transfer.approve_not_allowed", "❌ You are not authorized to approve this transfer."),
+    ("transfer.deny_not_allowed", "❌ You are not authorized to deny this transfer."),
+    ("transfer.no_longer_valid", "❌ Transfer request no longer valid."),
+    (
+        "transfer.recipient_blocked",
+        "❌ **Cannot Accept Transfer**\n\nYou are currently blocked from reserving \"{equipment}\", so this transfer was cancelled.",
+    ),
+    (
+        "transfer.recipient_suspended",
+        "❌ **Cannot Accept Transfer**\n\nYour reservations are suspended until {until} due to late returns, so this transfer was cancelled.",
+    ),
+    ("transfer.reason_blocked", "The recipient is blocked from reserving"),
+    ("transfer.reason_suspended", "The recipient has reservations suspended"),
+    ("transfer.reason_denied", "Denied by the recipient"),
+    (
+        "transfer.approved",
+        "✅ **Transfer Approved**\n\nThe reservation for \"{equipment}\" has been transferred to you.\n\n📅 **Period:** {start} - {end} ({zone})\n📍 **Location:** {location}",
+    ),
+    (
+        "transfer.denied",
+        "❌ **Transfer Denied**\n\nYou denied the transfer request for \"{equipment}\". The requester will be notified.",
+    ),
+    ("transfer.confirm_coming_soon", "⚙️ Transfer confirmation feature coming soon."),
+    (
+        "transfer.target_blocked",
+        "❌ {user} is blocked from reserving {scope} and cannot receive this reservation.",
+    ),
+    (
+        "transfer.target_suspended",
+        "❌ {user} has reservations suspended until {until} and cannot receive this reservation.",
+    ),
+    ("transfer.already_pending", "❌ A transfer request for this reservation is already pending."),
+    (
+        "transfer.approval_dm",
+        "📤 **Equipment Transfer Request**\n\n{requester} would like to transfer their reservation for \"{equipment}\" to you.\n\n📅 **Period:** {start} - {end} ({zone})\n📍 **Location:** {location}\n\n{note}Do you accept?\n\n⚠️ This request expires automatically in 3 hours.",
+    ),
+    ("transfer.approval_note", "📝 **Note:** {note}\n\n"),
+    (
+        "transfer.request_sent",
+        "✅ **Transfer Request Sent**\n\nAn approval request was sent to {user} by DM. The reservation is transferred once they accept.\n\n⏰ The request expires automatically in 3 hours.",
+    ),
+    (
+        "transfer.request_dm_failed",
+        "⚠️ **Transfer Request Created (DM Failed)**\n\nThe DM to {user} could not be sent, but the request was created. Please contact them directly.\n\n⏰ The request expires automatically in 3 hours.",
+    ),
+    ("transfer.approve_button", "Approve"),
+    ("transfer.deny_button", "Deny"),
+    (
+        "transfer.transferred",
+        "✅ **Reservation Transferred Successfully!**\n\n📦 **Equipment:** {equipment}\n👤 **From:** {from}\n👤 **To:** {to}\n🕐 **Period:** {start} - {end} ({zone}){note}",
+    ),
+    ("transfer.note_line", "\n📝 **Note:** {note}"),
+    (
+        "transfer.scheduled",
+        "✅ **Transfer Scheduled!**\n\n📦 **Equipment:** {equipment}\n👤 **From:** {from}\n👤 **To:** {to}\n🕐 **Execute At:** {time} ({zone}){note}",
+    ),
+    ("transfer.cancel_button", "🚫 Cancel Transfer"),
+
+    // Member slash commands
+    ("reserve_cmd.description", "Reserve equipment"),
+    ("reserve_cmd.equipment_option", "Equipment to reserve"),
+    (
+        "reserve_cmd.start_option",
+        "Start, e.g. 1/15 14:30 or tomorrow 14:00. Leave empty to pick from menus",
+    ),
+    ("reserve_cmd.end_option", "End time or a length such as 2h30m"),
+    (
+        "reserve_cmd.times_incomplete",
+        "❌ Enter both a start and an end, or leave both empty to pick them from menus.",
+    ),
+    (
+        "reserve_cmd.location_option",
+        "Return location when start and end are given. Defaults to the equipment's",
+    ),
+    ("return_cmd.description", "Return equipment you have borrowed"),
+    ("return_cmd.nothing_to_return", "❌ You don't have any borrowed equipment to return."),
+    ("return_cmd.select_description", "You have several loans. Select the one to return:"),
+    ("my_reservations.description", "Show your upcoming and active reservations"),
+    ("my_reservations.title", "📋 My Reservations"),
+    ("my_reservations.empty", "📭 You have no upcoming or active reservations."),
+    ("my_reservations.more", "Showing your first {count} reservations."),
+    ("my_reservations.status_upcoming", "🕐 Upcoming"),
+    ("my_reservations.status_active", "🟢 In use"),
+    ("my_reservations.status_overdue", "🔴 Overdue"),
+    ("my_reservations.edit_button", "✏️ Edit #{index}"),
+    ("my_reservations.cancel_button", "🗑️ Cancel #{index}"),
+    ("my_reservations.transfer_button", "🔄 Transfer #{index}"),
+
+    // Availability finder
+    ("availability_cmd.description", "Find when equipment is free for a given length"),
+    ("availability_cmd.duration_option", "Minimum free length, e.g. 3h or 1h30m"),
+    ("availability_cmd.tag_option", "Only search equipment with this tag"),
+    ("availability_cmd.equipment_option", "Only search this equipment"),
+    ("availability_cmd.from_option", "First day, e.g. 1/15 or tomorrow. Defaults to today"),
+    ("availability_cmd.to_option", "Last day, e.g. 1/21. Defaults to a week from the first day"),
+    ("availability.invalid_duration", "❌ Invalid length. {reason}"),
+    ("availability.invalid_from", "❌ Invalid first day. {reason}"),
+    ("availability.invalid_to", "❌ Invalid last day. {reason}"),
+    ("availability.range_invalid", "❌ The last day must not be before the first day."),
+    ("availability.range_past", "❌ The date range is already over."),
+    ("availability.range_too_long", "❌ Search at most {days} days at a time."),
+    ("availability.no_equipment", "❌ No reservable equipment matches."),
+    ("availability.title", "🔎 Free Slots"),
+    ("availability.summary", "{scope} • {from} – {to} • at least {length}"),
+    ("availability.scope_all", "All equipment"),
+    ("availability.none", "📭 Nothing is free for that long in this range."),
+    ("availability.more", "Showing the earliest {count} slots."),
+    ("availability.reserve_button", "📅 Reserve #{index}"),
+    ("availability.slot_expired", "❌ This slot has already started. Run /availability again."),
+
+    // Equipment admin command
+    ("equipment_cmd.description", "Find equipment"),
+    ("equipment_cmd.search_description", "Find equipment by name, tag or location"),
+    ("equipment_cmd.query_option", "Part of the name, tag or location, e.g. a7 or camera"),
+    ("equipment_search.no_results", "🔍 No equipment matches \"{query}\"."),
+    ("equipment_search.reservations_name", "📅 Next Reservations"),
+    ("equipment_search.no_reservations", "No upcoming reservations"),
+    ("equipment_search.logs_name", "📜 Recent Activity"),
+    ("equipment_search.matches", "{count} matches. Pick another below."),
+    ("equipment_search.select_placeholder", "Show another match"),
+    ("equipment_search.reserve_button", "📅 Reserve"),
+    ("equipment_search.settings_button", "⚙️ Settings"),
+    ("equipment_search.log_button", "📜 Full Log"),
+    ("equipment_search.jump_button", "🔗 Open in Channel"),
+
+    // User context menus
+    ("view_loans_cmd.name", "Equipment: View loans"),
+    ("transfer_to_user_cmd.name", "Transfer my reservation to…"),
+    ("view_loans.title", "📋 Reservations of {name}"),
+    ("view_loans.header", "{user}'s upcoming and active reservations:"),
+    ("view_loans.empty", "📭 {user} has no upcoming or active reservations."),
+    ("view_loans.more", "Showing the first {count} reservations."),
+    ("view_loans.check_in_button", "↩️ Check In #{index}"),
+    (
+        "transfer_to_user.no_reservations",
+        "❌ You don't have any active or upcoming reservations to transfer.",
+    ),
+    ("transfer_to_user.select_description", "Which reservation should go to {user}?"),
+    ("transfer_to_user.select_placeholder", "Select a reservation"),
+
+    // Equipment embed reservation list
+    ("embed.upcoming_reservations_name", "Upcoming Reservations ({count})"),
+    ("embed.upcoming_line", "• {start} → {end} {user}"),
+    ("embed.more_reservations", "+{count} more (📋 All Reservations below)"),
+    ("button.all_reservations", "📋 All Reservations ({count})"),
+    ("equipment_reservations.title", "📋 Reservations: {name}"),
+    ("equipment_reservations.footer", "Page {page}/{pages} • {total} reservations"),
+
+    // Weekly timelines
+    ("timeline.legend", "🟦 Reserved · 🟧 In use · 🟨 Prep time · ⬜ Closed · 🟥 Now"),
+    ("timeline.tag_title", "🗓️ {tag} — next 7 days"),
+    ("timeline.untagged", "Untagged"),
+    ("equipment_search.timeline_name", "🗓️ Next 7 Days"),
+    ("tools.header_timeline_name", "🗓️ Header Timelines"),
+    ("tools.header_timeline_on", "A weekly timeline per tag is shown under the header"),
+    ("tools.header_timeline_off", "Not shown"),
+    ("tools.header_timeline_show_button", "🗓️ Show Timelines"),
+    ("tools.header_timeline_hide_button", "🗓️ Hide Timelines"),
+
+    // Equipment display updates
+    (
+        "display.update_counts",
+        "{created} created · {edited} updated · {skipped} unchanged · {deleted} removed",
+    ),
+    ("tools.display_updates_name", "📊 Display Updates Since Start"),
+
+    // Channel rebuild
+    ("tools.rebuild_name", "🧱 Last Channel Rebuild"),
+    ("tools.rebuild_never", "Never rebuilt"),
+    (
+        "tools.rebuild_value",
+        "{at} by {by}: {missing} missing, {foreign} unknown · {deleted} deleted, {created} posted",
+    ),
+    ("tools.rebuild_by_bot", "the bot (automatic repair)"),
+    ("tools.rebuild_button", "🧱 Rebuild Channel"),
+    (
+        "tools.rebuilding",
+        "🧱 Deleting and reposting every equipment message. This can take a while...",
+    ),
+    ("tools.rebuilt", "✅ Reservation channel rebuilt."),
+    (
+        "tools.rebuild_failed",
+        "❌ Failed to rebuild the reservation channel. Check logs for details.",
+    ),
+    ("tools.rebuild_no_channel", "❌ No reservation channel is set up. Run /setup first."),
+];
+
+/// Japanese bundle
+const JA: &[(&str, &str)] = &[
+    // Shared
+    ("common.error_prefix", "❌ {message}"),
+    ("error.admin_required", "❌ この機能を使うには管理者権限が必要です。"),
+    ("error.admin_log_required", "❌ 操作ログを見るには管理者権限が必要です。"),
+    (
+        "error.invalid_time_format",
+        "❌ 日時の形式が正しくありません。YYYY/MM/DD HH:MM、MM/DD HH:MM、または「明日 14:00」のように入力してください。",
+    ),
+    ("error.equipment_not_found", "❌ 機材が見つかりません。"),
+    ("error.reservation_not_found", "❌ 予約が見つかりません。"),
+    ("error.operation_not_possible", "❌ 次の予約が迫っているか、返却から1時間が経過しているため操作できません。"),
+    ("error.session_expired", "❌ セッションの有効期限が切れました。もう一度お試しください。"),
+    ("success.operation", "✅ 操作が完了しました。"),
+    ("success.reservation_created", "✅ 予約を作成しました。"),
+    ("success.reservation_canceled", "✅ 予約をキャンセルしました。"),
+    ("success.equipment_returned", "✅ 機材を返却しました。"),
+    ("status.available", "利用可能"),
+    ("status.loaned", "貸出中"),
+    ("status.unavailable", "利用不可"),
+    ("common.none", "なし"),
+    ("common.unknown", "不明"),
+    ("common.enabled", "有効"),
+    ("common.disabled", "無効"),
+
+    // /language
+    ("language.command_description", "このサーバーでボットがあなたに使う言語を選びます"),
+    ("language.option_description", "使用する言語"),
+    ("language.option_default", "サーバーの既定"),
+    ("language.set", "✅ このサーバーではこれから日本語でお知らせします。"),
+    ("language.reset", "✅ 言語設定をサーバーの既定（{language}）に戻しました。"),
+    ("language.current", "現在の言語: **{language}**{source}\n変更するには `/language` で言語を選んでください。"),
+    ("language.current_default", "（サーバーの既定）"),
+
+    // /setup
+    ("setup.command_description", "このチャンネルに機材貸出ボットをセットアップします"),
+    ("setup.admin_required", "❌ このコマンドを使うには管理者権限が必要です。"),
+    (
+        "setup.missing_permissions",
+        "❌ **権限エラー**\n\nこのチャンネルでボットに次の権限がありません:\n• {permissions}\n\n権限を付与してからもう一度お試しください。",
+    ),
+    ("setup.confirm_title", "🔧 機材貸出ボットのセットアップ"),
+    (
+        "setup.confirm_description",
+        "{channel} を予約チャンネルに設定します。よろしいですか？\n\n⚠️ **注意**: このチャンネルにある、ボットが管理していないメッセージはすべて削除されます。",
+    ),
+    ("setup.confirm_button", "✅ 確定"),
+    ("setup.cancel_button", "❌ キャンセル"),
+    ("setup.back_button", "⬅️ 戻る"),
+    ("setup.next_button", "➡️ 次へ"),
+    ("setup.skip_button", "⏭️ スキップ"),
+    ("setup.complete_button", "✅ 完了"),
+    ("setup.cancelled", "❌ セットアップをキャンセルしました。"),
+    ("setup.session_expired", "❌ セッションの有効期限が切れました。もう一度セットアップを開始してください。"),
+    ("setup.roles_title", "🔧 セットアップ - ステップ1: 管理者ロール"),
+    (
+        "setup.roles_description",
+        "機材管理の管理者権限を持たせるロールを選んでください。\n\n**任意**: Discordの管理者だけが機材を管理する場合はスキップできます。",
+    ),
+    ("setup.roles_placeholder", "管理者ロールを選択（任意）"),
+    ("setup.roles_selected", "✅ ロールを選択しました。**次へ**を押して続けてください。"),
+    ("setup.guide_title", "📋 機材貸出管理"),
+    ("setup.guide_description", "まずは機材を登録してください。"),
+    ("setup.overall_management_button", "⚙️ 全体管理"),
+    ("setup.notifications_title", "🔧 セットアップ - ステップ3: 通知設定"),
+    ("setup.notifications_description", "機材貸出のリマインダー通知を設定します:"),
+    ("setup.dm_fallback_name", "📱 DMの代替通知"),
+    ("setup.dm_fallback_value", "DMを送れないときは予約チャンネルでメンションします"),
+    ("setup.pre_start_name", "⏰ 開始前リマインダー"),
+    ("setup.pre_start_value", "予約開始前に通知します（既定: 15分前）"),
+    ("setup.pre_end_name", "🔔 終了前リマインダー"),
+    ("setup.pre_end_value", "予約終了前に通知します（既定: 15分前）"),
+    ("setup.overdue_name", "⚠️ 返却遅延の通知"),
+    ("setup.overdue_value", "未返却の機材について繰り返し通知します（既定: 12時間ごと、最大3回）"),
+    ("setup.notifications_footer", "表示中の設定は既定値です。下で調整してから「次へ」を押してください。"),
+    ("setup.dm_fallback_enabled_description", "DMを送れないときはチャンネルでメンションする"),
+    ("setup.dm_fallback_disabled_description", "DMのみ送信し、チャンネルには送らない"),
+    ("setup.dm_fallback_placeholder", "DMの代替通知"),
+    ("setup.minutes_option", "{minutes}分"),
+    ("setup.hours_option", "{hours}時間ごと"),
+    ("setup.pre_start_placeholder", "開始前リマインダーの時間"),
+    ("setup.pre_end_placeholder", "終了前リマインダーの時間"),
+    ("setup.overdue_placeholder", "返却遅延の通知間隔"),
+    ("setup.notifications_updated", "✅ 通知設定を更新しました。**次へ**を押して続けてください。"),
+    ("setup.no_admin_roles", "なし（Discordの管理者のみ）"),
+    (
+        "setup.notification_summary",
+        "DMの代替通知: {dm_fallback}\n開始前: {pre_start}分\n終了前: {pre_end}分\n返却遅延: {overdue_hours}時間ごと（最大{overdue_max}回）",
+    ),
+    ("setup.final_title", "🔧 セットアップ - ステップ4: 最終確認"),
+    ("setup.final_description", "設定内容を確認してください:"),
+    ("setup.reservation_channel_name", "予約チャンネル"),
+    ("setup.admin_roles_name", "管理者ロール"),
+    ("setup.notification_settings_name", "通知設定"),
+    ("setup.time_zone_name", "タイムゾーン"),
+    ("setup.time_zone_value", "{name} - {offset}\nすべての日時はこのタイムゾーンで表示・入力されます。"),
+    ("setup.language_name", "言語"),
+    ("setup.language_value", "{language}\nメンバーは /language で自分の言語を選べます。"),
+    ("setup.final_footer", "「完了」でセットアップを終了し、「キャンセル」で中止します。"),
+    ("setup.time_zone_placeholder", "タイムゾーン"),
+    ("setup.language_placeholder", "言語"),
+    ("setup.complete_title", "✅ セットアップ完了！"),
+    ("setup.complete_roles_none", "Discordの管理者だけが管理機能を使えます。"),
+    ("setup.complete_roles", "管理者ロール: {roles}"),
+    (
+        "setup.complete_notifications",
+        "DMの代替通知: {dm_fallback}、開始前: {pre_start}分、終了前: {pre_end}分、返却遅延: {overdue_hours}時間ごと（最大{overdue_max}回）",
+    ),
+    (
+        "setup.complete_description",
+        "{channel} を予約チャンネルに設定しました。\n\n{roles}\n📱 **通知:** {notifications}\n🕘 **タイムゾーン:** {time_zone}\n🌐 **言語:** {language}\n\n🚀 **次のステップ:**\n• **全体管理** ボタンから機材を登録する\n• 貸出・返却場所を設定する\n• 機材を整理するタグを設定する",
+    ),
+
+    // Reservation channel
+    ("header.title", "🔧 機材管理"),
+    ("header.description", "機材の予約や各種設定はここから行えます。"),
+    ("header.overall_management_button", "📊 全体管理"),
+    ("embed.status", "状態"),
+    ("embed.category", "カテゴリ"),
+    ("embed.reservable_by", "🔒 予約できるロール"),
+    ("embed.current_location", "現在の場所"),
+    ("embed.default_return_location", "既定の返却場所"),
+    ("embed.unavailable_reason", "利用不可の理由"),
+    ("embed.awaiting_ack_name", "⚠️ 返却確認待ち"),
+    ("embed.awaiting_ack_value", "制限された場所「{location}」に返却されました。管理者の確認が必要です"),
+    ("embed.prep_time", "🧰 準備時間"),
+    ("embed.current_reservation_name", "現在の予約"),
+    ("embed.current_reservation_value", "予約者: {user}\n終了: {end}"),
+    ("embed.prep_until", "\n準備時間の終了: {time}"),
+    ("embed.availability_name", "空き状況"),
+    ("embed.availability_value", "予約できます"),
+    ("button.reserve", "📅 予約"),
+    ("button.pickup", "📦 受け取り"),
+    ("button.change", "✏️ 確認・変更"),
+    ("button.transfer", "🔄 移譲"),
+    ("button.return", "↩️ 返却"),
+    ("button.settings", "⚙️ この機材の設定"),
+
+    // Reminders
+    ("reminder.pre_start", "📅 リマインダー: 「{equipment}」の貸出開始まで15分です。\n開始時刻: {time}"),
+    ("reminder.start", "📅 貸出開始: 「{equipment}」の貸出が開始されました。\n貸出時刻: {time}"),
+    ("reminder.pre_end", "📅 リマインダー: 「{equipment}」の貸出期限まで15分です。\n返却時刻: {time}"),
+    ("reminder.overdue", "⚠️ 返却遅延 #{count}: 「{equipment}」の返却期限が過ぎています。\n期限: {time}"),
+    (
+        "reminder.no_show_owner",
+        "🚫 未受取: 「{equipment}」の予約は開始から{minutes}分以内に受け取りがなかったため、自動的に解放されました。\n期間: {start} - {end}",
+    ),
+    ("reminder.no_show_announcement", "📢 空き枠: 「{equipment}」の {start} - {end} の予約枠が空きました。"),
+
+    // Transfer notifications
+    (
+        "transfer_notice.request_dm",
+        "📤 **予約移譲依頼**\n\n<@{requester}>から「{equipment}」の予約移譲依頼があります。\n\nDMで詳細を確認して承認・拒否を選択してください。\n\n⚠️ この依頼は3時間後に自動的に期限切れになります。",
+    ),
+    ("transfer_notice.approved_dm", "✅ **移譲承認通知**\n\n「{equipment}」の予約移譲依頼が承認されました。"),
+    ("transfer_notice.denied_dm", "❌ **移譲拒否通知**\n\n「{equipment}」の予約移譲依頼が拒否されました。\n\n理由: {reason}"),
+    ("transfer_notice.cancelled_dm", "🚫 **移譲キャンセル通知**\n\n「{equipment}」の予約移譲依頼がキャンセルされました。"),
+    (
+        "transfer_notice.expired_dm",
+        "⏰ **移譲期限切れ通知**\n\n「{equipment}」の予約移譲依頼が3時間以内に承認されなかったため、自動的にキャンセルされました。",
+    ),
+    (
+        "transfer_notice.request_fallback",
+        "「{equipment}」の予約移譲に関する通知があります。予約ID: #{reservation_id}\n\nDMを有効にして詳細を確認してください。",
+    ),
+    ("transfer_notice.update_fallback", "「{equipment}」の予約移譲に関する更新があります。予約ID: #{reservation_id}"),
+
+    // Reservation rules
+    ("time_error.empty", "日時を入力してください。"),
+    (
+        "time_error.unrecognized",
+        "「{input}」を読み取れませんでした。YYYY-MM-DD HH:MM、MM/DD HH:MM、「明日 14:00」、「+2h」のように入力してください。",
+    ),
+    ("time_error.missing_time", "「1/15 14:30」のように時刻も入力してください。"),
+    ("time_error.invalid_date", "{date} は存在しない日付です。"),
+    ("time_error.invalid_time", "{time} は正しい時刻ではありません。"),
+    ("time_error.invalid_duration", "長さ「{input}」を読み取れませんでした。90m、2h30m、1d のように入力してください。"),
+    ("time_error.zero_duration", "長さは0より大きくしてください。"),
+    ("time_error.nonexistent", "{time} は夏時間の切り替えで存在しない時刻です。別の時刻を選んでください。"),
+    ("schedule.closed_date_reason", "{date} は部室が閉まっています（{reason}）。別の日を選んでください。"),
+    ("schedule.closed_date", "{date} は部室が閉まっています。別の日を選んでください。"),
+    ("schedule.closed_weekday", "{weekday}は部室が閉まっています。別の日を選んでください。"),
+    ("schedule.outside_hours", "{time} は開室時間（{hours}）外です。予約は開室時間内に開始・終了してください。"),
+    ("weekday.mon", "毎週月曜日"),
+    ("weekday.tue", "毎週火曜日"),
+    ("weekday.wed", "毎週水曜日"),
+    ("weekday.thu", "毎週木曜日"),
+    ("weekday.fri", "毎週金曜日"),
+    ("weekday.sat", "毎週土曜日"),
+    ("weekday.sun", "毎週日曜日"),
+    ("buffer.none", "なし"),
+    ("buffer.before", "前{before}分"),
+    ("buffer.after", "後{after}分"),
+    ("buffer.both", "前{before}分、後{after}分"),
+    ("buffer.conflict", "ほかの予約と近すぎます。この機材は予約の間に{minutes}分の準備時間が必要です（{buffer}）。"),
+    ("reservation.slot_taken", "この時間帯はすでに予約されています。別の時間を選んでください。"),
+    ("block.scope_all", "すべての機材"),
+    ("block.scope_tag", "タグ「{tag}」"),
+    ("block.scope_tag_id", "タグ #{tag_id}"),
+    ("block.until", "（{time}まで）"),
+    ("block.message", "{scope}の予約が制限されています{until}。"),
+    ("tag_access.restricted", "「{tag}」の機材は次のいずれかのロールを持つメンバーのみ予約できます: {roles}"),
+    ("return_correction.not_returned", "❌ この予約はまだ返却されていません。"),
+    ("return_correction.not_returner", "❌ 返却を修正できるのは返却したメンバーのみです。"),
+
+    // Durations and dates
+    ("duration.one_day", "1日"),
+    ("duration.days", "{days}日"),
+    ("duration.minutes", "{minutes}分"),
+    ("duration.hours", "{hours}時間"),
+    ("duration.hours_minutes", "{hours}時間{minutes}分"),
+    ("weekday_short.mon", "月"),
+    ("weekday_short.tue", "火"),
+    ("weekday_short.wed", "水"),
+    ("weekday_short.thu", "木"),
+    ("weekday_short.fri", "金"),
+    ("weekday_short.sat", "土"),
+    ("weekday_short.sun", "日"),
+
+    // Overall management
+    ("mgmt.dashboard_title", "⚙️ 全体管理ダッシュボード"),
+    ("mgmt.all_equipment", "すべての機材"),
+    ("mgmt.selected_equipment", "{count}件の機材を選択中"),
+    ("mgmt.time_today", "今日"),
+    ("mgmt.time_next_24h", "今後24時間"),
+    ("mgmt.time_next_7d", "今後7日間"),
+    ("mgmt.time_custom", "{start} 〜 {end}"),
+    ("mgmt.time_all", "全期間"),
+    ("mgmt.status_active", "貸出中"),
+    ("mgmt.status_upcoming", "今後の予約"),
+    ("mgmt.status_returned_today", "本日返却済み"),
+    ("mgmt.status_all", "すべての状態"),
+    ("mgmt.current_filters_name", "🔧 現在の絞り込み"),
+    ("mgmt.current_filters_value", "**機材:** {equipment}\n**期間:** {time}\n**状態:** {status}"),
+    ("mgmt.reservations_name", "📋 予約"),
+    ("mgmt.no_matching_reservations", "条件に一致する予約はありません。"),
+    ("mgmt.location_not_specified", "未指定"),
+    ("mgmt.reservations_page", "📋 予約（{total}件中 {from}-{to}件）"),
+    ("mgmt.transfer_button", "🔄 移譲 #{index}"),
+    ("mgmt.equipment_filter_button", "🔧 機材で絞り込み"),
+    ("mgmt.time_filter_button", "📅 期間で絞り込み"),
+    ("mgmt.status_filter_button", "📊 状態で絞り込み"),
+    ("mgmt.clear_filters_button", "🗑️ すべて解除"),
+    ("mgmt.checkin_button", "↩️ 返却受付"),
+    ("common.previous_button", "⬅️ 前へ"),
+    ("common.next_button", "➡️ 次へ"),
+    ("mgmt.refresh_button", "🔄 表示を更新"),
+    ("mgmt.export_button", "📊 CSVを出力"),
+    ("mgmt.jump_button", "🔗 機材へ移動"),
+    ("mgmt.logs_button", "📋 操作ログ"),
+    ("mgmt.tools_button", "🧰 管理ツール"),
+
+    // Admin: add tag / location / equipment
+    ("admin.add_tag_title", "タグを追加"),
+    ("admin.tag_name_label", "タグ名"),
+    ("admin.tag_name_placeholder", "例: カメラ、音響、照明"),
+    ("admin.sort_order_label", "表示順"),
+    ("admin.sort_order_placeholder", "並び順の数字（例: 1, 2, 3...）"),
+    ("admin.add_location_title", "場所を追加"),
+    ("admin.location_name_label", "場所の名前"),
+    ("admin.location_name_placeholder", "例: 部室A、実験室B、倉庫"),
+    ("admin.add_equipment_title", "機材を追加"),
+    ("admin.equipment_name_label", "機材名"),
+    ("admin.equipment_name_placeholder", "例: Sony A7III、Shure SM58"),
+    ("admin.tag_name_optional_placeholder", "既存のタグ名（任意）"),
+    ("admin.default_location_label", "既定の返却場所"),
+    ("admin.location_optional_placeholder", "場所の名前（任意）"),
+    ("admin.refresh_done", "✅ 機材一覧を更新しました。"),
+    ("admin.refresh_failed", "❌ 機材一覧の更新に失敗しました。詳細はログを確認してください。"),
+    ("admin.tag_name_required", "❌ タグ名を入力してください。"),
+    ("admin.sort_order_invalid", "❌ 表示順は数字で入力してください。"),
+    ("admin.tag_added", "✅ タグ「{name}」を追加しました。「表示を更新」で機材一覧に反映されます。"),
+    ("admin.tag_add_failed", "❌ タグを追加できませんでした。既に存在する可能性があります。"),
+    ("admin.location_name_required", "❌ 場所の名前を入力してください。"),
+    ("admin.location_added", "✅ 場所「{name}」を追加しました。"),
+    ("admin.location_add_failed", "❌ 場所を追加できませんでした。既に存在する可能性があります。"),
+    ("admin.equipment_name_required", "❌ 機材名を入力してください。"),
+    ("admin.equipment_added", "✅ 機材「{name}」を追加しました。"),
+    ("admin.equipment_added_without_tag", "（タグが見つからなかったため、タグなしで追加しました）"),
+    ("admin.refresh_hint", " 「表示を更新」で機材一覧に反映されます。"),
+    ("admin.equipment_add_failed", "❌ 機材を追加できませんでした。既に存在する可能性があります。"),
+    ("eqset.rename_permission", "❌ 機材名を変更するには管理者または機材管理者の権限が必要です。"),
+    ("eqset.rename_empty", "❌ 機材名を空にはできません。"),
+    ("eqset.renamed", "✅ 機材名を「{old}」から「{new}」に変更しました。「表示を更新」で反映されます。"),
+    ("eqset.rename_failed", "❌ 機材名を変更できませんでした。同じ名前が既に存在する可能性があります。"),
+
+    // Reserve / change buttons
+    ("reserve.not_available", "❌ この機材は現在予約できません。"),
+    ("reservation.suspended", "返却遅延のため、{until}まで予約が停止されています。"),
+    ("change.no_active_reservations", "❌ この機材に対する有効な予約はありません。"),
+    ("change.manage_title", "🔄 予約の管理"),
+    ("change.manage_description", "**機材:** {equipment}\n\n変更またはキャンセルする予約を選んでください:"),
+    ("change.no_location", "場所未指定"),
+    ("change.reservation_option", "{start} 〜 {end} - {location}"),
+    ("change.select_placeholder", "管理する予約を選択..."),
+    ("eqset.permission_required", "❌ 機材設定を開くには管理者または機材管理者の権限が必要です。"),
+
+    // Equipment settings
+    ("eqset.no_upcoming", "今後の予約はありません"),
+    ("eqset.title", "⚙️ 設定 - {equipment}"),
+    ("eqset.description", "この機材の設定を変更します"),
+    ("eqset.current_status", "現在の状態"),
+    ("eqset.not_set", "未設定"),
+    ("eqset.upcoming_reservations", "今後の予約"),
+    ("eqset.current_loan_name", "📦 貸出中"),
+    ("eqset.current_loan_value", "{user} • 返却期限 {due}"),
+    ("eqset.overdue_suffix", "（延滞中）"),
+    ("eqset.condition_report_line", "{rating}/{max}（{user}）• {time}"),
+    ("eqset.condition_reports", "🛠️ 最近の状態報告"),
+    ("eqset.awaiting_ack_value", "{user} が {time} に制限付きの場所 **{location}** へ返却しました"),
+    ("eqset.force_state_button", "🔄 状態を強制変更"),
+    ("eqset.unavailable_reason_button", "⚠️ 利用不可の理由を設定"),
+    ("eqset.rename_button", "✏️ 名前を変更"),
+    ("eqset.default_location_button", "📍 既定の返却場所を設定"),
+    ("eqset.view_log_button", "📋 操作ログを見る"),
+    ("eqset.buffer_button", "⏱️ 準備時間を設定"),
+    ("eqset.admin_return_button", "↩️ 代理で返却処理"),
+    ("eqset.assign_tag_button", "🏷️ タグを割り当て"),
+    ("eqset.delete_button", "🗑️ 機材を削除"),
+    ("eqset.ack_return_button", "✅ 返却を確認"),
+    ("eqset.cancel_reservation_button", "❌ {start} の予約を取消"),
+    ("eqset.prep_range", "準備時間 {start} - {end}"),
+
+    // Equipment settings actions
+    ("eqset.force_state_permission", "❌ 状態を強制変更するには管理者または機材管理者の権限が必要です。"),
+    ("eqset.force_state_coming_soon", "🚧 状態の強制変更は近日対応予定です。"),
+    ("eqset.unavailable_reason_permission", "❌ 利用不可の理由を設定するには管理者または機材管理者の権限が必要です。"),
+    ("eqset.unavailable_reason_coming_soon", "🚧 利用不可の理由の設定は近日対応予定です。"),
+    ("eqset.rename_title", "機材名の変更"),
+    ("eqset.rename_label", "新しい機材名"),
+    ("eqset.rename_placeholder", "この機材の新しい名前を入力"),
+    ("eqset.assign_tag_admin", "❌ タグを割り当てるには管理者権限が必要です。"),
+    ("eqset.assign_tag_coming_soon", "🚧 タグの割り当ては近日対応予定です。"),
+    ("eqset.default_location_permission", "❌ 既定の返却場所を設定するには管理者または機材管理者の権限が必要です。"),
+    ("eqset.default_location_coming_soon", "🚧 既定の返却場所の設定は近日対応予定です。"),
+    ("eqset.buffer_permission", "❌ 準備時間を設定するには管理者または機材管理者の権限が必要です。"),
+    ("eqset.buffer_before_label", "各予約の前の準備時間（分）"),
+    ("eqset.buffer_after_label", "各予約の後の準備時間（分）"),
+    ("eqset.buffer_inherit_placeholder", "空欄にするとタグの準備時間を使います"),
+    ("eqset.buffer_title", "準備時間の設定"),
+    ("eqset.buffer_invalid", "❌ 準備時間は0〜{max}分の数字で入力してください。"),
+    (
+        "eqset.buffer_updated",
+        "✅ 準備時間を更新しました。適用される準備時間: **{buffer}**\n既存の予約は変わりません。新しい予約と予約の変更から適用されます。",
+    ),
+    ("eqset.tag_buffer_title", "準備時間: {tag}"),
+    ("eqset.tag_buffer_updated", "✅ このタグの準備時間を **{buffer}** に設定しました。個別に準備時間を設定した機材はそのままです。"),
+    ("eqset.view_log_permission", "❌ 操作ログを見るには管理者または機材管理者の権限が必要です。"),
+
+    // Equipment deletion
+    ("eqset.delete_admin", "❌ 機材を削除するには管理者権限が必要です。"),
+    (
+        "eqset.delete_has_reservations",
+        "❌ 機材「{equipment}」には有効な予約が{count}件あるため削除できません。先にすべての予約をキャンセルしてください。",
+    ),
+    ("eqset.delete_confirm_title", "⚠️ 機材削除の確認"),
+    (
+        "eqset.delete_confirm_description",
+        "機材「{equipment}」を削除してもよろしいですか？\n\n**この操作は取り消せません！**\nこの機材の履歴とログはすべて完全に削除されます。",
+    ),
+    ("eqset.delete_confirm_button", "🗑️ 削除する"),
+    ("common.cancel_button", "❌ キャンセル"),
+    ("eqset.delete_missing", "❌ 機材が見つからないか、既に削除されています。"),
+    ("eqset.deleted", "✅ 機材「{equipment}」を削除しました。「表示を更新」で機材一覧に反映されます。"),
+    ("eqset.delete_cancelled", "❌ 機材の削除を取り消しました。"),
+
+    // Pickup and return buttons
+    (
+        "pickup.done",
+        "📦 **機材を受け取りました！**\n\n**機材:** {equipment}\n**返却期限:** {due}（{offset}）\n\n返すときは **返却** ボタンを押してください。",
+    ),
+    ("pickup.not_ready", "受け取れる予約がありません。受け取りは予約開始の{minutes}分前からです。"),
+    ("pickup.not_available", "この機材は現在「{status}」のため受け取れません。"),
+    ("return.nothing_to_return", "❌ この機材で返却できる予約はありません。"),
+    ("return.select_title", "↩️ 返却する予約を選択"),
+    ("return.select_description", "**機材:** {equipment}\n\nこの機材の有効な予約が複数あります。返却する予約を選んでください:"),
+    ("return.option_location", "場所: {location}"),
+    ("return.select_placeholder", "返却する予約を選択..."),
+    ("return.modal_title", "{equipment} を返却"),
+    ("return.location_label", "返却場所"),
+    ("return.location_placeholder", "どこに返却しますか？"),
+    ("return.condition_label", "状態（1-{max}）"),
+    ("return.condition_placeholder", "5 = 完璧、1 = 故障"),
+    ("return.damage_label", "破損メモ"),
+    ("return.damage_placeholder", "破損や欠品があれば記入してください（任意）"),
+
+    // Reservation wizard
+    ("wizard.opening_hours", "\n\n🕘 **営業時間（{zone}）:** 予約は営業時間内に開始・終了する必要があります。\n```\n{hours}\n```"),
+    ("wizard.closed_dates", "\n📅 **休業日:** {dates}"),
+    ("wizard.times_in", "時刻は{offset}です"),
+    (
+        "wizard.step1_description",
+        "**機材:** {equipment}\n\n**ステップ1:** 日付を選んでから開始時刻を選んでください。予約済みの時間や営業時間外は表示されません。",
+    ),
+    ("wizard.no_free_dates", "\n\n⚠️ 今後{days}日間に空いている開始時刻はありません。"),
+    ("wizard.date_placeholder", "📅 日付を選択"),
+    ("wizard.start_slot_range", "🕐 開始時刻（{from} - {to}）"),
+    ("wizard.start_slot_placeholder", "🕐 開始時刻を選択"),
+    (
+        "wizard.step1_manual_hint",
+        "\n\n⌨️ 細かい時刻を指定したいときは **手入力** を使ってください（例: `2025-01-15 14:30`、`1/15 14:30`、`明日 14:00`）。",
+    ),
+    ("wizard.step1_title", "📅 機材の予約 - ステップ1/3"),
+    ("wizard.manual_button", "⌨️ 手入力"),
+    (
+        "wizard.step2_description",
+        "**機材:** {equipment}\n**開始日時:** {start}\n\n**ステップ2:** 利用する長さを選んでください。他の予約や閉店時刻にかかる長さは表示されません。",
+    ),
+    ("wizard.no_duration_fits", "\n\n⚠️ この開始時刻から選べる長さがありません。選び直してください。"),
+    ("wizard.duration_option", "{length}（{end}まで）"),
+    ("wizard.duration_placeholder", "⏳ 長さを選択"),
+    (
+        "wizard.step2_manual_hint",
+        "\n\n⌨️ 終了時刻を細かく指定したいときは **手入力** で日時か `2h30m` のような長さを入力してください（{days}日以内）。",
+    ),
+    ("wizard.step2_title", "📅 機材の予約 - ステップ2/3"),
+    ("wizard.reselect_button", "← 選び直す"),
+    (
+        "wizard.step3_description",
+        "**機材:** {equipment}\n**開始日時:** {start}\n**終了日時:** {end}\n\n**ステップ3:** 返却場所を指定してください（任意）。\n\n📍 既定の場所を使うか、別の場所を入力できます。",
+    ),
+    ("wizard.step3_title", "📍 機材の予約 - ステップ3/3"),
+    ("wizard.enter_location_button", "📍 場所を入力"),
+    ("wizard.default_location_button", "📍 既定の場所（{location}）"),
+    ("wizard.skip_location_button", "⏭️ 場所を指定しない"),
+    ("wizard.session_expired", "❌ セッションの有効期限が切れました。もう一度予約をやり直してください。"),
+    ("wizard.conflict_title", "⚠️ 予約が重複しています"),
+    ("wizard.reselect_time_button", "← 時間を選び直す"),
+    ("wizard.confirm_title", "✅ 予約の確認"),
+    (
+        "wizard.confirm_description",
+        "**機材:** {equipment}\n**開始日時:** {start}\n**終了日時:** {end}\n**返却場所:** {location}{prep}\n\n🔍 **重複チェック:** ✅ 重複はありません\n\n予約内容を確認してください。",
+    ),
+    ("wizard.prep_line", "\n**準備時間:** {buffer}"),
+    ("wizard.confirm_button", "✅ 予約を確定"),
+    ("common.back_button", "⬅️ 戻る"),
+
+    // Reservation create / edit / cancel
+    ("reservation.created_with_id", "✅ 予約を作成しました。（ID: {id}）"),
+    ("reservation.not_found_or_cancelled", "❌ 予約が見つからないか、既にキャンセルされています。"),
+    ("reservation.edit_own_only", "❌ 自分の予約しか編集できません。"),
+    ("reservation.edit_title", "予約の編集 - {equipment}"),
+    ("time_input.start_label", "開始日時"),
+    ("time_input.end_label", "終了日時"),
+    ("time_input.start_placeholder", "YYYY-MM-DD HH:MM、1/15 14:30、明日 14:00 など（{zone}）"),
+    ("time_input.end_placeholder", "YYYY-MM-DD HH:MM（{zone}）または 2h30m のような長さ"),
+    ("time_input.location_optional_label", "返却場所（任意）"),
+    ("reservation.updated", "✅ 予約を更新しました。"),
+    ("reservation.cancel_own_only", "❌ 自分の予約しかキャンセルできません。"),
+    (
+        "reservation.cancelled_details",
+        "✅ 予約をキャンセルしました。\n\n**機材:** {equipment}\n**期間:** {start} 〜 {end}",
+    ),
+    ("error.manager_required", "❌ この機能を使うには管理者または機材管理者の権限が必要です。"),
+    (
+        "reservation.admin_cancelled_details",
+        "✅ 管理者として予約をキャンセルしました。\n\n**機材:** {equipment}\n**予約者:** {user}\n**期間:** {start} 〜 {end}",
+    ),
+    ("time_input.invalid_start", "開始日時が正しくありません。{reason}"),
+    ("time_input.invalid_end", "終了日時が正しくありません。{reason}"),
+    ("time_input.end_before_start", "終了日時は開始日時より後にしてください。"),
+    ("time_input.start_in_past", "開始日時に過去は指定できません。"),
+    ("time_input.too_far_ahead", "{days}日より先までの予約はできません。"),
+    ("reservation.not_found", "予約が見つかりません"),
+    ("reservation.not_found_or_cancelled_plain", "予約が見つからないか、既にキャンセルされています"),
+
+    // Reservation wizard inputs
+    ("wizard.start_modal_title", "開始日時を入力"),
+    ("wizard.start_input_label", "開始日時"),
+    ("wizard.end_modal_title", "終了日時を入力"),
+    ("wizard.end_input_label", "終了日時"),
+    ("wizard.location_modal_title", "返却場所を入力"),
+    ("wizard.location_placeholder", "どこに返却する予定ですか？"),
+    (
+        "wizard.created",
+        "✅ **予約が完了しました！**\n\n🆔 **予約ID:** {id}\n📅 **期間:** {start} 〜 {end}（{zone}）\n\n機材の予約が確定しました。",
+    ),
+    ("wizard.create_failed", "❌ **予約を作成できませんでした**\n\n{reason}"),
+    ("wizard.invalid_state", "❌ 予約の状態が正しくありません。最初からやり直してください。"),
+    ("wizard.cancelled", "❌ **予約を中止しました**\n\n予約手続きを中止しました。利用可能な機材の「予約」ボタンからいつでも新しく予約できます。"),
+    ("wizard.start_in_past", "❌ 開始日時に過去は指定できません。これからの日時を選んでください。"),
+
+    // Change / cancel reservation
+    ("change.not_found_or_cancelled", "❌ 予約が見つからないか、キャンセルされています。"),
+    ("change.manage_own_only", "❌ 自分の予約しか管理できません。"),
+    ("change.manage_one_title", "🔧 予約の管理"),
+    (
+        "change.manage_one_description",
+        "**機材:** {equipment}\n**期間:** {start} 〜 {end}\n**場所:** {location}\n\nどの操作をしますか？",
+    ),
+    ("change.time_button", "📅 時間を変更"),
+    ("change.location_button", "📍 場所を変更"),
+    ("change.cancel_button", "❌ 予約をキャンセル"),
+    ("change.time_modal_title", "予約時間の変更 - {equipment}"),
+    ("change.new_start_label", "新しい開始日時"),
+    ("change.new_end_label", "新しい終了日時"),
+    ("change.location_modal_title", "返却場所の変更 - {equipment}"),
+    ("change.new_location_label", "新しい返却場所"),
+    ("change.location_placeholder", "空欄にすると場所の指定を外します"),
+    ("change.cancel_confirm_title", "⚠️ 予約のキャンセル"),
+    (
+        "change.cancel_confirm_description",
+        "**機材:** {equipment}\n**期間:** {start} 〜 {end}\n\n❌ **注意:** この操作は取り消せません！\n\nこの予約をキャンセルしてもよろしいですか？",
+    ),
+    ("change.cancel_yes_button", "❌ キャンセルする"),
+    ("change.cancel_no_button", "↩️ やめる"),
+    ("change.cancelled", "✅ **予約をキャンセルしました！**\n\n予約はキャンセルされました。"),
+    ("change.cancel_failed", "❌ **予約をキャンセルできませんでした**\n\n{reason}"),
+    ("change.cancel_aborted", "↩️ **キャンセルを中止しました**\n\n予約はそのまま有効です。"),
+    ("change.time_updated", "✅ **予約時間を変更しました！**\n\n📅 **新しい期間:** {start} 〜 {end}（{zone}）"),
+    ("change.update_failed", "❌ **予約を変更できませんでした**\n\n{reason}"),
+    ("change.location_updated", "✅ **返却場所を変更しました！**\n\n📍 **新しい場所:** {location}"),
+    ("change.location_update_failed", "❌ **場所を変更できませんでした**\n\n{reason}"),
+
+    // Return modal
+    ("return.processing_error", "❌ 返却の処理中にエラーが発生しました。"),
+    ("return.location_required", "❌ 返却場所を入力してください。"),
+    ("return.condition_invalid", "❌ 状態は1〜{max}の数字で入力してください。"),
+
+    // Transfer requests
+    ("transfer.user_not_found", "❌ このサーバーにそのユーザーはいません。"),
+    ("transfer.bot_target", "❌ ボットには移譲できません。"),
+    ("transfer.already_returned", "❌ 返却済みの予約は移譲できません。"),
+    ("transfer.already_ended", "❌ 終了した予約は移譲できません。"),
+    ("transfer.own_only", "❌ 移譲できるのは自分の予約だけです（管理者を除く）。"),
+    ("transfer.same_user", "❌ 同じユーザーには移譲できません。"),
+    ("transfer.target_restricted", "❌ {user} はこの予約を受け取れません。{reason}"),
+
+    // Return confirmation and corrections
+    ("return.unknown_equipment", "不明な機材"),
+    ("return.not_default_warning", "\n\n⚠️ **この返却場所で間違いありませんか？** この機材は通常 **{location}** に返却します。"),
+    ("return.restricted_warning", "\n\n🛡️ **{location}** は制限付きの場所です。この返却には管理者の確認が必要です。"),
+    ("return.damage_warning", "\n\n🛠️ 破損報告が管理者に送信されます。あとでBotからのDMに写真を返信できます。"),
+    ("return.confirm_title", "↩️ 機材返却の確認"),
+    ("return.location_marker", "**返却場所:** "),
+    (
+        "return.confirm_description",
+        "**機材:** {equipment}\n**予約期間:** {start} 〜 {end}\n**予約時の場所:** {original}\n**返却場所:** {location}{warnings}\n\n今すぐこの機材を返却してよろしいですか？",
+    ),
+    ("return.confirm_footer", "この操作は管理者の手を借りないと取り消せません"),
+    ("return.condition_field", "状態"),
+    ("return.damage_field", "破損メモ"),
+    ("return.confirm_button", "✅ 返却する"),
+    ("return.confirm_here_button", "✅ はい、ここに返却します"),
+    ("return.correct_button", "✏️ 返却を修正"),
+    (
+        "return.returned",
+        "✅ **機材を返却しました！**\n\n📦 **機材:** {equipment}\n📍 **返却場所:** {location}\n🕐 **返却日時:** {time}\n\n{details}\n\n間違えた場合は、次の予約の直前でなければ {hours} 時間以内に返却を修正できます。",
+    ),
+    ("return.failed", "❌ **機材を返却できませんでした**\n\n{error}"),
+    ("return.cancelled", "❌ 返却を取り消しました。"),
+    ("return.undo_button", "↩️ 返却を取り消す"),
+    ("return.fix_location_button", "📍 返却場所を修正"),
+    ("return.correct_prompt", "✏️ **返却の修正**\n\n{deadline} まで、この返却の取り消しや返却場所の修正ができます。"),
+    ("return.undone", "↩️ **返却を取り消しました**\n\n機材は再びあなたに貸し出し中です。予約終了までに返却してください。"),
+    ("return.fix_location_title", "返却場所の修正"),
+    ("return.fix_location_placeholder", "実際に機材を返却した場所を入力してください"),
+    ("return.location_corrected", "✅ 返却場所を **{location}** に修正しました。"),
+
+    // Condition reports
+    (
+        "condition.damage_alert",
+        "🛠️ **破損報告** {admins}\n\n📦 **機材:** {equipment}\n👤 **返却者:** {user}\n⭐ **状態:** {rating}/{max}\n📝 **メモ:** {note}",
+    ),
+    ("condition.no_note", "なし"),
+    ("condition.mark_unavailable_button", "🚧 利用不可にする"),
+    (
+        "condition.photo_request",
+        "🛠️ **{equipment}** の破損報告ありがとうございます。{minutes} 分以内にここへ写真を返信すると、報告に添付されます。",
+    ),
+    ("condition.details_filed", "\n🛠️ **破損報告:** 提出済み"),
+    ("condition.details_alerted", "・管理者に通知済み"),
+    ("condition.details_dm_sent", "。BotからのDMに破損箇所の写真を返信してください。"),
+    ("condition.details_dm_failed", "。写真を送るにはサーバーメンバーからのDMを許可してください。"),
+    ("condition.report_not_found", "❌ 状態報告が見つかりません。"),
+    ("condition.permission_required", "❌ 機材を利用停止にするには管理者または機材管理者の権限が必要です。"),
+    ("condition.marked_unavailable", "🚧 {user} が機材を **利用不可** にしました。\n**理由:** {reason}"),
+    ("condition.already_applied", "ℹ️ この報告はすでに反映されています。"),
+    ("condition.photo_link", "[写真{number}]({url})"),
+    ("condition.photos_posted", "📷 上記の破損報告の写真: {photos}"),
+    ("condition.photos_attached", "📷 破損報告に写真を {count} 枚添付しました（合計 {total} 枚）。"),
+
+    // Return processing
+    ("return.error_not_found", "予約が見つかりません"),
+    ("return.error_own_only", "自分の予約しか返却できません"),
+    ("return.error_already_returned", "この予約はすでに返却済みです"),
+    ("return.error_not_started", "予約期間が始まる前に返却することはできません"),
+    ("return.details", "📅 **予約期間:** {start} 〜 {end}\n📍 **予約時の場所:** {original}"),
+    ("return.details_restricted", "\n🛡️ **制限付きの場所:** 管理者がこの返却を確認します。"),
+    ("return.details_late", "\n⚠️ **返却遅延:** ペナルティ +{points} 点（合計 {total} 点）"),
+    ("return.details_suspended", "\n⛔ **新規予約の停止期限:** {until}"),
+
+    // Management filters and export
+    ("mgmt.no_equipment", "❌ 機材がありません。先に全体管理パネルから機材を追加してください。"),
+    ("mgmt.all_equipment_description", "すべての機材の予約を表示"),
+    ("mgmt.filter_by", "{equipment} で絞り込み"),
+    ("mgmt.equipment_select_placeholder", "絞り込む機材を選択..."),
+    ("mgmt.equipment_filter_prompt", "🔧 **機材で絞り込み**\n予約を表示する機材を選択してください:"),
+    ("mgmt.time_today_button", "📅 今日"),
+    ("mgmt.time_24h_button", "🕐 今後24時間"),
+    ("mgmt.time_7d_button", "📊 今後7日間"),
+    ("mgmt.time_custom_button", "⚙️ カスタム"),
+    ("mgmt.time_all_button", "🌐 全期間"),
+    ("mgmt.time_filter_prompt", "📅 **期間で絞り込み**\n予約の期間を選択してください:"),
+    ("mgmt.status_active_button", "🟢 貸出中"),
+    ("mgmt.status_upcoming_button", "🟡 今後"),
+    ("mgmt.status_returned_button", "🔄 本日返却済み"),
+    ("mgmt.status_all_button", "📊 すべての状態"),
+    ("mgmt.status_filter_prompt", "📊 **状態で絞り込み**\n予約の状態を選択してください:"),
+    ("mgmt.refreshing", "🔄 表示を更新しています... しばらくお待ちください。"),
+    ("mgmt.refreshed", "✅ 表示を更新しました！"),
+    ("mgmt.refresh_failed", "❌ 表示を更新できませんでした。詳細はログを確認してください。"),
+    ("mgmt.export_generating", "📊 CSVを作成しています... しばらくお待ちください。"),
+    ("mgmt.export_selected", "{count}件選択中"),
+    ("mgmt.export_all", "すべて"),
+    ("mgmt.time_custom_short", "カスタム"),
+    (
+        "mgmt.export_summary",
+        "📊 **CSV出力の概要**\n**予約件数:** {count}\n**適用中の絞り込み:**\n• 機材: {equipment}\n• 期間: {time}\n• 状態: {status}\n\n*CSVのダウンロードは今後対応予定です。データのプレビュー:*\n```\n{preview}```",
+    ),
+
+    // Admin tools
+    ("tools.grace_after_start", "開始から {minutes} 分後"),
+    ("tools.disabled", "無効"),
+    ("tools.title", "🧰 管理ツール"),
+    ("tools.description", "サーバー全体の貸出ポリシーとメンバーの記録です。"),
+    ("tools.noshow_name", "🚫 未受取の自動解放"),
+    ("tools.noshow_value", "時間内に受け取られなかった予約は自動的に解放されます。\n**猶予時間:** {grace}"),
+    ("tools.penalty_name", "⚖️ 返却遅延ペナルティ"),
+    ("tools.penalty_value", "終了時刻を過ぎると1時間ごとに {points} 点。\n**利用停止:** {suspension}"),
+    ("tools.suspension_policy", "{threshold} 点で {days} 日間"),
+    ("tools.restricted_name", "🛡️ 制限付きの返却場所"),
+    ("tools.restricted_none", "なし - どの場所への返却も受け付けます"),
+    ("tools.restricted_value", "{locations}\nここへの返却には管理者の確認が必要です。"),
+    ("tools.hours_name", "🕘 営業時間"),
+    ("tools.hours_enforced", "予約は営業時間内に開始・終了する必要があります"),
+    ("tools.always_open", "常時営業"),
+    ("tools.grace_disable_option", "未受取の自動解放を無効にする"),
+    ("tools.grace_option", "{minutes} 分"),
+    ("tools.grace_placeholder", "未受取の猶予時間"),
+    ("tools.threshold_disable_option", "ペナルティによる利用停止を無効にする"),
+    ("tools.threshold_option", "{points} 点で利用停止"),
+    ("tools.threshold_placeholder", "ペナルティのしきい値"),
+    ("tools.days_option", "{days} 日間利用停止"),
+    ("tools.days_placeholder", "利用停止の期間"),
+    ("tools.noshow_list_button", "🚫 未受取の記録"),
+    ("tools.penalty_list_button", "⚖️ ペナルティ点数"),
+    ("tools.blocklist_button", "⛔ ブロックリスト"),
+    ("tools.tag_access_button", "🏷️ タグのアクセス権"),
+    ("tools.back_button", "⬅️ 管理画面に戻る"),
+    ("tools.restricted_button", "🛡️ 制限付きの場所"),
+    ("tools.hours_button", "🕘 営業時間"),
+
+    // No-shows, penalties and tag access
+    ("tools.noshow_empty", "未受取の記録はありません。"),
+    ("tools.noshow_line", "**{rank}.** {user} • 未受取 {count} 回 • 最終: {last}"),
+    ("tools.noshow_title", "🚫 未受取の記録"),
+    ("tools.noshow_footer", "未受取回数の多い上位20名"),
+    ("tools.back_to_tools_button", "⬅️ 管理ツールに戻る"),
+    ("tools.penalty_empty", "ペナルティ点数や利用停止のあるメンバーはいません。"),
+    ("tools.penalty_suspended_until", " • ⛔ {until} まで利用停止"),
+    ("tools.penalty_line", "**{rank}.** {user} • {points} 点{suspension}"),
+    ("tools.penalty_title", "⚖️ ペナルティ点数"),
+    ("tools.penalty_footer", "利用停止中のメンバーを先頭に、点数順"),
+    ("tools.forgive_button", "🕊️ 免除"),
+    ("tools.adjust_button", "✏️ 点数を調整"),
+    ("tools.forgive_title", "ペナルティの免除"),
+    ("tools.adjust_title", "ペナルティ点数の調整"),
+    ("tools.member_label", "メンバー（ユーザーIDまたはメンション）"),
+    ("tools.points_delta_label", "点数の増減"),
+    ("tools.points_delta_placeholder", "例: -3 や 2"),
+    ("tools.reason_label", "理由"),
+    ("tools.invalid_member", "❌ 正しいユーザーIDまたはメンションを入力してください。"),
+    ("tools.forgiven", "🕊️ {user} のペナルティ点数と利用停止を解除しました。"),
+    ("tools.points_delta_invalid", "❌ 点数の増減は整数で入力してください（例: -3 や 2）。"),
+    ("tools.points_adjusted", "✏️ {user} のペナルティ点数は **{total}** 点になりました。"),
+    ("tools.points_adjusted_suspended", "\n⛔ 利用停止の基準に達しました。{until} まで新規予約が停止されます。"),
+    ("tag_access.no_tags", "タグはまだ作成されていません。"),
+    ("tag_access.line", "**{tag}** • 予約可能: {allowed} • 管理者: {managers}"),
+    ("tag_access.everyone", "全員"),
+    ("tag_access.admins_only", "管理者のみ"),
+    ("tag_access.prep_time", " • 準備時間 {buffer}"),
+    ("tag_access.title", "🏷️ タグのアクセス権"),
+    ("tag_access.footer", "タグを選ぶと、予約できる人と機材を管理する人を設定できます"),
+    ("tag_access.select_placeholder", "タグを選択"),
+    ("tag_access.roles_placeholder", "「{tag}」を予約できるロール"),
+    ("tag_access.managers_placeholder", "「{tag}」の機材を管理するロール"),
+    ("tag_access.allow_everyone_button", "🔓 全員に許可"),
+    ("tag_access.prep_time_button", "⏱️ 準備時間"),
+
+    // Calendar import
+    ("hours.ics_no_events", "❌ このカレンダーファイルには予定が見つかりませんでした。"),
+    ("hours.ics_imported", "✅ `{file}` から休業日を {count} 件取り込みました。"),
+    ("hours.ics_download_failed", "❌ カレンダーファイルをダウンロードできませんでした。もう一度お試しください。"),
+
+    // Return acknowledgment and staff check-in
+    ("ack.done", "✅ 返却を確認し、操作ログに記録しました。"),
+    ("ack.already_done", "ℹ️ この返却はすでに確認済みです。"),
+    ("checkin.permission_required", "❌ 他の人の機材の返却を受け付けるには管理者または機材管理者の権限が必要です。"),
+    ("checkin.no_active_loan", "ℹ️ この機材には受け付ける貸出がありません。"),
+    ("checkin.no_loans", "ℹ️ 現在貸出中の機材はありません。"),
+    ("checkin.unknown_user", "ユーザー {id}"),
+    ("checkin.select_placeholder", "返却を受け付ける機材を選択..."),
+    ("checkin.select_prompt", "↩️ **代理で返却を受付**\n返却された機材を選択してください。延滞中の貸出が先頭に表示されます。"),
+    ("checkin.already_returned", "ℹ️ この機材はすでに返却されています。"),
+    ("checkin.location_placeholder", "機材はどこで返却されましたか？"),
+    ("checkin.modal_title", "返却受付: {equipment}"),
+    ("checkin.failed", "❌ **返却を受け付けられませんでした**\n\n{error}"),
+    (
+        "checkin.owner_notice",
+        "↩️ **{equipment}** の予約は {staff} によって返却受付されました。\n📍 **返却場所:** {location}\n🕐 **返却日時:** {time}",
+    ),
+    (
+        "checkin.done",
+        "✅ **返却を受け付けました**\n\n📦 **機材:** {equipment}\n👤 **予約者:** {owner}\n🙋 **受付者:** {staff}\n📍 **返却場所:** {location}\n🕐 **返却日時:** {time}\n\n{details}{notice}",
+    ),
+    ("checkin.owner_not_notified", "\n\n⚠️ 予約者にDMで通知できませんでした。"),
+    ("restricted.no_locations", "場所はまだ登録されていません。"),
+    ("restricted.description", "制限付きの場所への返却には管理者の確認が必要です。下で制限付きの場所を選択してください。"),
+    ("restricted.placeholder", "制限付きの返却場所"),
+
+    // Opening hours
+    ("hours.always_open", "常時営業 - いつでも予約を受け付けます"),
+    ("hours.no_closed_dates", "今後の休業日はありません"),
+    ("hours.description", "予約は部室が開いている時間内に開始・終了する必要があります。"),
+    ("hours.weekly_field", "毎週の営業時間（{zone}）"),
+    ("hours.closed_field", "📅 今後の休業日"),
+    ("hours.footer", "「ICSを取り込む」で大学や祝日のカレンダーから休業日を取り込めます"),
+    ("hours.reopen_placeholder", "休業日を取り消す..."),
+    ("hours.edit_button", "✏️ 毎週の営業時間を編集"),
+    ("hours.closed_add_button", "➕ 休業日を追加"),
+    ("hours.import_button", "📥 ICSを取り込む"),
+    ("hours.weekly_label", "毎週の営業時間（現地時刻）"),
+    ("hours.weekly_placeholder", "Mon-Fri 09:00-21:00\nSat 10:00-17:00\nSun closed\n（空欄 = 常時営業）"),
+    ("hours.weekly_modal_title", "毎週の営業時間"),
+    ("hours.cleared", "✅ 営業時間を解除しました。いつでも予約を受け付けます。"),
+    ("hours.updated", "✅ 営業時間を更新しました:\n```\n{hours}\n```既存の予約は変更されません。"),
+    ("hours.closed_modal_title", "休業日の追加"),
+    ("hours.closed_label", "休業日"),
+    ("hours.closed_placeholder", "2025-01-01 元日\n2025-08-10..2025-08-20 夏季休業"),
+    ("hours.no_dates", "❌ 日付が入力されていません。"),
+    ("hours.closed_added", "✅ 休業日を {count} 件追加しました。"),
+    (
+        "hours.import_prompt",
+        "📥 **休業日の取り込み**\n\n{minutes} 分以内に `.ics` カレンダーファイルをDMで送ってください。カレンダーのすべての予定が、タイトルを理由として休業日に追加されます。",
+    ),
+    ("hours.input_error", "{line} 行目: {problem}（「{text}」）"),
+    ("hours.error_missing_hours", "曜日のあとに時間を書いてください"),
+    ("hours.error_unknown_weekday", "曜日を認識できません"),
+    ("hours.error_weekday_order", "曜日の範囲は月曜から日曜の順に指定してください"),
+    ("hours.error_hours_format", "時間は 09:00-21:00 の形式で指定してください"),
+    ("hours.error_invalid_time", "時刻が正しくありません"),
+    ("hours.error_close_before_open", "閉室時刻は開室時刻より後にしてください"),
+    ("hours.error_invalid_date", "日付が正しくありません"),
+    ("hours.error_date_range", "期間は開始日以降に終わり、最長 {days} 日までにしてください"),
+
+    // Blocklist
+    ("blocklist.empty", "ブロック中のメンバーはいません。"),
+    ("blocklist.no_expiry", "無期限"),
+    ("blocklist.line", "• {user} • {scope} • 期限 {until} • 登録者 {admin}{reason}"),
+    ("blocklist.title", "⛔ ブロックリスト"),
+    ("blocklist.footer", "ブロック中のメンバーは、対象範囲の機材を予約したり移譲を受けたりできません"),
+    ("blocklist.option", "ユーザー {id} • {scope}"),
+    ("blocklist.lift_placeholder", "ブロックを解除"),
+    ("blocklist.add_button", "➕ メンバーをブロック"),
+    ("blocklist.modal_title", "メンバーのブロック"),
+    ("blocklist.tag_label", "タグ（空欄 = すべて）"),
+    ("blocklist.tag_placeholder", "空欄にするとすべての機材をブロックします"),
+    ("blocklist.days_label", "日数（空欄 = 無期限）"),
+    ("blocklist.days_placeholder", "例: 30"),
+    ("blocklist.invalid_member", "正しいユーザーIDまたはメンションを入力してください。"),
+    ("blocklist.tag_not_found", "タグ「{tag}」が見つかりません。"),
+    ("blocklist.days_invalid", "日数は正の整数で入力してください。"),
+    ("blocklist.scope_tag", "タグ「{tag}」の機材"),
+    ("blocklist.scope_all", "すべての機材"),
+    ("blocklist.until", "（{until} まで）"),
+    ("blocklist.blocked", "⛔ {user} は{until}{scope}を予約できなくなりました。"),
+
+    // Transfers
+    ("transfer.no_own_reservations", "❌ この機材にあなたの貸出中または今後の予約はありません。"),
+    ("transfer.no_reservations", "❌ この機材に貸出中または今後の予約はありません。"),
+    ("transfer.returned", "❌ 返却済みの予約は移譲できません。"),
+    ("transfer.owner_or_admin", "❌ 移譲できるのは自分の予約のみです（管理者を除く）。"),
+
+    // Operation log viewer
+    ("logs.title", "📋 操作ログ"),
+    ("logs.time_last_7d", "過去7日間"),
+    ("logs.time_last_30d", "過去30日間"),
+    ("logs.all_actions", "すべての操作"),
+    ("logs.filters_name", "🔍 現在の絞り込み"),
+    ("logs.filters_value", "**期間:** {time}\n**機材:** {equipment}\n**操作:** {action}"),
+    ("logs.results_name", "📊 結果"),
+    ("logs.results_value", "全 {total} 件中 {from}-{to} 件を表示（{page} ページ目）"),
+    ("logs.unknown_equipment", "不明"),
+    ("logs.entries_name", "📋 ログ"),
+    ("logs.no_entries", "条件に一致するログはありません。"),
+    ("logs.action_filter_button", "⚡ 操作で絞り込み"),
+    ("logs.refresh_button", "🔄 更新"),
+
+    // Log filters and equipment log
+    ("logs.time_placeholder", "期間を選択"),
+    ("logs.time_prompt", "期間を選択してください:"),
+    ("logs.equipment_filter_pending", "🚧 機材での絞り込みはまだ実装されていません。"),
+    ("logs.action_filter_pending", "🚧 操作での絞り込みはまだ実装されていません。"),
+    ("logs.export_pending", "🚧 ログの出力はまだ実装されていません。"),
+    ("logs.equipment_title", "📋 操作ログ - {equipment}"),
+    ("logs.equipment_empty", "この機材の操作ログはありません。"),
+    ("logs.entry", "{emoji} **{action}** {notes}\n**日時:** {time}\n**ユーザー:** {user}\n"),
+    ("logs.entry_location", "**場所:** {location}\n"),
+    ("logs.entry_status", "**状態:** {from} → {to}\n"),
+    ("logs.equipment_footer", "{page} ページ目 • 全 {total} 件"),
+    ("common.close_button", "❌ 閉じる"),
+    ("logs.next_button", "次へ ➡️"),
+
+    // Transfer requests
+    ("transfer.select_user", "🔄 **{equipment}**（{start} → {end}）の予約を誰に移譲しますか？"),
+    ("transfer.user_placeholder", "新しい予約者を選択"),
+    ("transfer.modal_title", "予約の移譲 - {equipment}"),
+    ("transfer.note_label", "メモ（任意）"),
+    ("transfer.note_placeholder", "移譲についてのメモ（任意）"),
+    ("transfer.request_not_found", "❌ 移譲依頼が見つからないか、すでに処理されています。"),
+    ("transfer.cancel_not_allowed", "❌ 取り消せるのは自分が作成した移譲依頼のみです（管理者を除く）。"),
+    (
+        "transfer.cancelled",
+        "🚫 **移譲を取り消しました**\n\n👤 **移譲元:** {from}\n👤 **移譲先:** {to}\n🕐 **予定日時:** {time}（{zone}）\n✅ **取消者:** {user}",
+    ),
+    ("transfer.approve_not_allowed", "❌ この移譲を承認する権限がありません。"),
+    ("transfer.deny_not_allowed", "❌ この移譲を拒否する権限がありません。"),
+    ("transfer.no_longer_valid", "❌ この移譲依頼はもう有効ではありません。"),
+    (
+        "transfer.recipient_blocked",
+        "❌ **移譲を受け取れません**\n\n現在「{equipment}」の予約がブロックされているため、この移譲は取り消されました。",
+    ),
+    (
+        "transfer.recipient_suspended",
+        "❌ **移譲を受け取れません**\n\n返却遅延のため {until} まで予約が停止されているので、この移譲は取り消されました。",
+    ),
+    ("transfer.reason_blocked", "受信者は予約をブロックされています"),
+    ("transfer.reason_suspended", "受信者は予約を停止されています"),
+    ("transfer.reason_denied", "受信者によって拒否されました"),
+    (
+        "transfer.approved",
+        "✅ **移譲を承認しました**\n\n「{equipment}」の予約があなたに移譲されました。\n\n📅 **期間:** {start} - {end}（{zone}）\n📍 **場所:** {location}",
+    ),
+    ("transfer.denied", "❌ **移譲を拒否しました**\n\n「{equipment}」の予約移譲依頼を拒否しました。依頼者に通知されます。"),
+    ("transfer.confirm_coming_soon", "⚙️ 移譲の確認機能は今後対応予定です。"),
+    ("transfer.target_blocked", "❌ {user} は{scope}の予約をブロックされているため、この予約を受け取れません。"),
+    ("transfer.target_suspended", "❌ {user} は {until} まで予約が停止されているため、この予約を受け取れません。"),
+    ("transfer.already_pending", "❌ この予約にはすでに保留中の移譲依頼があります。"),
+    (
+        "transfer.approval_dm",
+        "📤 **機材移譲の依頼**\n\n{requester}から「{equipment}」の予約移譲の依頼があります。\n\n📅 **期間:** {start} - {end}（{zone}）\n📍 **場所:** {location}\n\n{note}承認しますか？\n\n⚠️ この依頼は3時間後に自動的に期限切れになります。",
+    ),
+    ("transfer.approval_note", "📝 **メモ:** {note}\n\n"),
+    (
+        "transfer.request_sent",
+        "✅ **移譲依頼を送信しました**\n\n{user}にDMで承認依頼を送信しました。承認されると予約が移譲されます。\n\n⏰ 依頼は3時間後に自動的に期限切れになります。",
+    ),
+    (
+        "transfer.request_dm_failed",
+        "⚠️ **移譲依頼を作成しました（DM送信失敗）**\n\n{user}へのDM送信に失敗しましたが、依頼は作成されました。ユーザーに直接連絡を取ってください。\n\n⏰ 依頼は3時間後に自動的に期限切れになります。",
+    ),
+    ("transfer.approve_button", "承認する"),
+    ("transfer.deny_button", "拒否する"),
+    (
+        "transfer.transferred",
+        "✅ **予約を移譲しました！**\n\n📦 **機材:** {equipment}\n👤 **移譲元:** {from}\n👤 **移譲先:** {to}\n🕐 **期間:** {start} - {end}（{zone}）{note}",
+    ),
+    ("transfer.note_line", "\n📝 **メモ:** {note}"),
+    (
+        "transfer.scheduled",
+        "✅ **移譲を予約しました！**\n\n📦 **機材:** {equipment}\n👤 **移譲元:** {from}\n👤 **移譲先:** {to}\n🕐 **実行日時:** {time}（{zone}）{note}",
+    ),
+    ("transfer.cancel_button", "🚫 移譲を取り消す"),
+
+    // Member slash commands
+    ("reserve_cmd.description", "機材を予約します"),
+    ("reserve_cmd.equipment_option", "予約する機材"),
+    ("reserve_cmd.start_option", "開始日時（例: 1/15 14:30、明日 14:00）。空欄ならメニューから選びます"),
+    ("reserve_cmd.end_option", "終了日時、または 2h30m のような長さ"),
+    ("reserve_cmd.times_incomplete", "❌ 開始と終了の両方を入力するか、両方とも空欄にしてメニューから選んでください。"),
+    ("reserve_cmd.location_option", "開始と終了を入力した場合の返却場所。省略時は機材の既定の場所"),
+    ("return_cmd.description", "借りている機材を返却します"),
+    ("return_cmd.nothing_to_return", "❌ 返却できる貸出中の機材はありません。"),
+    ("return_cmd.select_description", "複数の機材を借りています。返却するものを選んでください："),
+    ("my_reservations.description", "自分の予約と貸出中の機材を表示します"),
+    ("my_reservations.title", "📋 自分の予約"),
+    ("my_reservations.empty", "📭 予定中・貸出中の予約はありません。"),
+    ("my_reservations.more", "最初の{count}件を表示しています。"),
+    ("my_reservations.status_upcoming", "🕐 予約中"),
+    ("my_reservations.status_active", "🟢 貸出中"),
+    ("my_reservations.status_overdue", "🔴 返却期限切れ"),
+    ("my_reservations.edit_button", "✏️ 変更 #{index}"),
+    ("my_reservations.cancel_button", "🗑️ 取消 #{index}"),
+    ("my_reservations.transfer_button", "🔄 移譲 #{index}"),
+
+    // Availability finder
+    ("availability_cmd.description", "指定した長さで空いている機材の時間帯を探します"),
+    ("availability_cmd.duration_option", "必要な空き時間の長さ（例: 3h、1h30m）"),
+    ("availability_cmd.tag_option", "このタグの機材だけを探す"),
+    ("availability_cmd.equipment_option", "この機材だけを探す"),
+    ("availability_cmd.from_option", "検索開始日（例: 1/15、明日）。省略時は今日"),
+    ("availability_cmd.to_option", "検索終了日（例: 1/21）。省略時は開始日から1週間"),
+    ("availability.invalid_duration", "❌ 長さが正しくありません。{reason}"),
+    ("availability.invalid_from", "❌ 検索開始日が正しくありません。{reason}"),
+    ("availability.invalid_to", "❌ 検索終了日が正しくありません。{reason}"),
+    ("availability.range_invalid", "❌ 検索終了日は検索開始日以降にしてください。"),
+    ("availability.range_past", "❌ 指定した期間はすでに過ぎています。"),
+    ("availability.range_too_long", "❌ 一度に検索できるのは{days}日分までです。"),
+    ("availability.no_equipment", "❌ 条件に合う予約可能な機材がありません。"),
+    ("availability.title", "🔎 空き時間"),
+    ("availability.summary", "{scope} • {from}〜{to} • {length}以上"),
+    ("availability.scope_all", "すべての機材"),
+    ("availability.none", "📭 この期間にその長さで空いている時間はありません。"),
+    ("availability.more", "早い順に{count}件を表示しています。"),
+    ("availability.reserve_button", "📅 予約 #{index}"),
+    ("availability.slot_expired", "❌ この時間帯はすでに始まっています。もう一度 /availability を実行してください。"),
+
+    // Equipment admin command
+    ("equipment_cmd.description", "機材を探します"),
+    ("equipment_cmd.search_description", "名前・タグ・場所で機材を探します"),
+    ("equipment_cmd.query_option", "名前・タグ・場所の一部（例: a7、カメラ）"),
+    ("equipment_search.no_results", "🔍 「{query}」に一致する機材はありません。"),
+    ("equipment_search.reservations_name", "📅 今後の予約"),
+    ("equipment_search.no_reservations", "今後の予約はありません"),
+    ("equipment_search.logs_name", "📜 最近の操作"),
+    ("equipment_search.matches", "{count}件が一致しました。下のメニューから切り替えられます。"),
+    ("equipment_search.select_placeholder", "他の一致した機材を表示"),
+    ("equipment_search.reserve_button", "📅 予約"),
+    ("equipment_search.settings_button", "⚙️ 設定"),
+    ("equipment_search.log_button", "📜 すべての履歴"),
+    ("equipment_search.jump_button", "🔗 チャンネルで開く"),
+
+    // User context menus
+    ("view_loans_cmd.name", "機材: 貸出状況を見る"),
+    ("transfer_to_user_cmd.name", "この人に予約を移譲…"),
+    ("view_loans.title", "📋 {name} さんの予約"),
+    ("view_loans.header", "{user} さんの予定中・貸出中の予約："),
+    ("view_loans.empty", "📭 {user} さんには予定中・貸出中の予約はありません。"),
+    ("view_loans.more", "最初の{count}件を表示しています。"),
+    ("view_loans.check_in_button", "↩️ 返却処理 #{index}"),
+    ("transfer_to_user.no_reservations", "❌ 移譲できる貸出中または今後の予約はありません。"),
+    ("transfer_to_user.select_description", "{user} さんにどの予約を移譲しますか？"),
+    ("transfer_to_user.select_placeholder", "予約を選択"),
+
+    // Equipment embed reservation list
+    ("embed.upcoming_reservations_name", "今後の予約 ({count}件)"),
+    ("embed.upcoming_line", "• {start} 〜 {end} {user}"),
+    ("embed.more_reservations", "ほか{count}件（下の 📋 すべての予約 から）"),
+    ("button.all_reservations", "📋 すべての予約 ({count})"),
+    ("equipment_reservations.title", "📋 予約一覧: {name}"),
+    ("equipment_reservations.footer", "{page}/{pages}ページ • 予約{total}件"),
+
+    // Weekly timelines
+    ("timeline.legend", "🟦 予約 · 🟧 貸出中 · 🟨 準備時間 · ⬜ 閉室 · 🟥 現在"),
+    ("timeline.tag_title", "🗓️ {tag} — 今後7日間"),
+    ("timeline.untagged", "タグなし"),
+    ("equipment_search.timeline_name", "🗓️ 今後7日間"),
+    ("tools.header_timeline_name", "🗓️ ヘッダーのタイムライン"),
+    ("tools.header_timeline_on", "ヘッダーの下にタグごとの週間タイムラインを表示しています"),
+    ("tools.header_timeline_off", "表示していません"),
+    ("tools.header_timeline_show_button", "🗓️ タイムラインを表示"),
+    ("tools.header_timeline_hide_button", "🗓️ タイムラインを隠す"),
+
+    // Equipment display updates
+    ("display.update_counts", "作成 {created} · 更新 {edited} · 変更なし {skipped} · 削除 {deleted}"),
+    ("tools.display_updates_name", "📊 起動後の表示更新"),
+
+    // Channel rebuild
+    ("tools.rebuild_name", "🧱 最後のチャンネル再構築"),
+    ("tools.rebuild_never", "再構築したことはありません"),
+    (
+        "tools.rebuild_value",
+        "{at}（{by}）: 欠落 {missing} · 不明 {foreign} · 削除 {deleted} · 投稿 {created}",
+    ),
+    ("tools.rebuild_by_bot", "ボット（自動修復）"),
+    ("tools.rebuild_button", "🧱 チャンネルを再構築"),
+    ("tools.rebuilding", "🧱 すべての機材メッセージを削除して投稿し直しています。しばらくかかることがあります..."),
+    ("tools.rebuilt", "✅ 予約チャンネルを再構築しました。"),
+    ("tools.rebuild_failed", "❌ 予約チャンネルの再構築に失敗しました。詳細はログを確認してください。"),
+    ("tools.rebuild_no_channel", "❌ 予約チャンネルが設定されていません。先に /setup を実行してください。"),
+];
@@ -7,8 +7,9 @@ use std::time::Duration as StdDuration;
 use tokio::time::sleep;
 use tracing::{error, info, warn};
 
+use crate::i18n::{language_or_default, tf, user_language};
 use crate::models::{DeliveryMethod, Job, ReminderKind};
-use crate::time::utc_to_jst_string;
+use crate::time::{time_zone_or_default, utc_to_local_string};
 use crate::traits::DiscordApi;
 // use crate::transfer_notifications::TransferNotificationService;
 // use crate::transfer_notifications::TransferNotificationType;
@@ -142,7 +143,7 @@ impl JobWorker {
         // Re-validate the transfer request and reservation
         let reservation = sqlx::query!(
             "SELECT r.id, r.equipment_id, r.user_id, r.start_time, r.end_time, r.status, r.returned_at,
-                    e.name as equipment_name
+                    e.name as equipment_name, e.guild_id
              FROM reservations r
              JOIN equipment e ON r.equipment_id = e.id
              WHERE r.id = ? AND r.status = 'Confirmed'",
@@ -186,6 +187,36 @@ impl JobWorker {
             return Ok(());
         }
 
+        // Recipient may have been blocked after the transfer was scheduled
+        if crate::blocklist::BlocklistService::new(self.db.clone())
+            .find_active_block_for_equipment(transfer.to_user_id, reservation.equipment_id)
+            .await?
+            .is_some()
+        {
+            warn!(
+                "Recipient {} of transfer {} is blocked for equipment {}",
+                transfer.to_user_id, transfer.id, reservation.equipment_id
+            );
+            tx.rollback().await?;
+            self.mark_transfer_failed(transfer).await?;
+            return Ok(());
+        }
+
+        // Or had reservations suspended
+        if crate::penalties::PenaltyService::new(self.db.clone())
+            .active_suspension(reservation.guild_id, transfer.to_user_id)
+            .await?
+            .is_some()
+        {
+            warn!(
+                "Recipient {} of transfer {} has reservations suspended",
+                transfer.to_user_id, transfer.id
+            );
+            tx.rollback().await?;
+            self.mark_transfer_failed(transfer).await?;
+            return Ok(());
+        }
+
         // Execute the transfer
         // Update reservation owner
         sqlx::query!(
@@ -379,6 +410,7 @@ impl JobWorker {
         // Process based on job type
         match job.job_type.as_str() {
             "reminder" => self.process_reminder(job).await?,
+            "no_show_check" => self.process_no_show_check(job).await?,
             "transfer_timeout" => self.process_transfer_timeout(job).await?,
             "retry_dm" => self.process_retry_dm(job).await?,
             "session_cleanup" => self.process_session_cleanup(job).await?,
@@ -494,28 +526,40 @@ impl JobWorker {
         // Convert to UTC DateTime
         let start_time_utc = DateTime::<Utc>::from_naive_utc_and_offset(start_time_naive, Utc);
         let end_time_utc = DateTime::<Utc>::from_naive_utc_and_offset(end_time_naive, Utc);
+        let tz = time_zone_or_default(Some(&guild_row.time_zone));
+        let lang = user_language(
+            &self.db,
+            Some(equipment_row.guild_id),
+            reservation_row.user_id,
+        )
+        .await?;
+        let start_local = utc_to_local_string(start_time_utc, tz);
+        let end_local = utc_to_local_string(end_time_utc, tz);
 
         let message = match &reminder_kind {
-            ReminderKind::PreStart => format!(
-                "📅 リマインダー: 「{}」の貸出開始まで15分です。\n開始時刻: {}",
-                equipment_name,
-                utc_to_jst_string(start_time_utc)
+            ReminderKind::PreStart => tf(
+                lang,
+                "reminder.pre_start",
+                &[("equipment", &equipment_name), ("time", &start_local)],
             ),
-            ReminderKind::Start => format!(
-                "📅 貸出開始: 「{}」の貸出が開始されました。\n貸出時刻: {}",
-                equipment_name,
-                utc_to_jst_string(start_time_utc)
+            ReminderKind::Start => tf(
+                lang,
+                "reminder.start",
+                &[("equipment", &equipment_name), ("time", &start_local)],
             ),
-            ReminderKind::PreEnd => format!(
-                "📅 リマインダー: 「{}」の貸出期限まで15分です。\n返却時刻: {}",
-                equipment_name,
-                utc_to_jst_string(end_time_utc)
+            ReminderKind::PreEnd => tf(
+                lang,
+                "reminder.pre_end",
+                &[("equipment", &equipment_name), ("time", &end_local)],
             ),
-            ReminderKind::Overdue(count) => format!(
-                "⚠️ 返却遅延 #{}: 「{}」の返却期限が過ぎています。\n期限: {}",
-                count,
-                equipment_name,
-                utc_to_jst_string(end_time_utc)
+            ReminderKind::Overdue(count) => tf(
+                lang,
+                "reminder.overdue",
+                &[
+                    ("count", count),
+                    ("equipment", &equipment_name),
+                    ("time", &end_local),
+                ],
             ),
         };
 
@@ -602,6 +646,124 @@ impl JobWorker {
         Ok(DeliveryMethod::Failed)
     }
 
+    async fn process_no_show_check(&self, job: &Job) -> Result<()> {
+        let payload: Value = serde_json::from_str(&job.payload)?;
+        let reservation_id = payload["reservation_id"]
+            .as_i64()
+            .ok_or_else(|| anyhow::anyhow!("Missing reservation_id in job payload"))?;
+
+        let row = sqlx::query!(
+            "SELECT r.user_id, r.status, r.start_time, r.end_time, r.picked_up_at, r.returned_at,
+                    e.name as equipment_name, g.reservation_channel_id,
+                    g.dm_fallback_channel_enabled, g.no_show_grace_minutes, g.time_zone,
+                    g.id as guild_id, g.language
+             FROM reservations r
+             JOIN equipment e ON r.equipment_id = e.id
+             JOIN guilds g ON e.guild_id = g.id
+             WHERE r.id = ?",
+            reservation_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        let row = match row {
+            Some(r) => r,
+            None => {
+                info!(
+                    "Reservation {} not found, skipping no-show check",
+                    reservation_id
+                );
+                return Ok(());
+            }
+        };
+
+        // Skip if already picked up, returned, cancelled or released
+        if row.status != "Confirmed" || row.picked_up_at.is_some() || row.returned_at.is_some()
+        {
+            info!(
+                "Reservation {} is no longer awaiting pickup, skipping no-show check",
+                reservation_id
+            );
+            return Ok(());
+        }
+
+        let grace_minutes = row
+            .no_show_grace_minutes
+            .unwrap_or(crate::constants::Constants::DEFAULT_NO_SHOW_GRACE_MINUTES);
+        if grace_minutes <= 0 {
+            info!("No-show detection disabled, skipping reservation {}", reservation_id);
+            return Ok(());
+        }
+
+        // The reservation may have been moved since this check was scheduled
+        let start_time_utc = naive_to_utc(row.start_time);
+        let deadline = start_time_utc + Duration::minutes(grace_minutes);
+        if Utc::now() < deadline {
+            Self::schedule_no_show_check(&self.db, reservation_id, start_time_utc, grace_minutes)
+                .await?;
+            return Ok(());
+        }
+
+        if !Self::release_no_show_reservation(&self.db, reservation_id).await? {
+            return Ok(());
+        }
+
+        if let Some(channel_id) = row.reservation_channel_id {
+            crate::reconcile::request_background_reconcile(&self.db, row.guild_id, channel_id);
+        }
+
+        let Some(discord_api) = &self.discord_api else {
+            return Ok(());
+        };
+
+        let tz = time_zone_or_default(Some(&row.time_zone));
+        let start_local = utc_to_local_string(start_time_utc, tz);
+        let end_local = utc_to_local_string(naive_to_utc(row.end_time), tz);
+
+        // Notify the owner that the reservation was released
+        let owner_lang = user_language(&self.db, Some(row.guild_id), row.user_id).await?;
+        let owner_message = tf(
+            owner_lang,
+            "reminder.no_show_owner",
+            &[
+                ("equipment", &row.equipment_name),
+                ("minutes", &grace_minutes),
+                ("start", &start_local),
+                ("end", &end_local),
+            ],
+        );
+        self.send_reminder_with_fallback(
+            discord_api.as_ref(),
+            row.user_id,
+            &owner_message,
+            row.reservation_channel_id,
+            row.dm_fallback_channel_enabled.unwrap_or(true),
+        )
+        .await?;
+
+        // Announce the freed slot so other members can pick it up. There is no waitlist
+        // to notify individually, so the channel announcement is the only notice.
+        if let Some(channel_id) = row.reservation_channel_id {
+            let announcement = tf(
+                language_or_default(Some(&row.language)),
+                "reminder.no_show_announcement",
+                &[
+                    ("equipment", &row.equipment_name),
+                    ("start", &start_local),
+                    ("end", &end_local),
+                ],
+            );
+            if let Err(e) = discord_api
+                .send_channel_message(ChannelId::new(channel_id as u64), &announcement)
+                .await
+            {
+                warn!("Failed to announce released slot: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
     async fn process_transfer_timeout(&self, _job: &Job) -> Result<()> {
         // TODO: Implement transfer timeout processing
         Ok(())
@@ -761,6 +923,190 @@ impl JobWorker {
         Ok(())
     }
 
+    /// Schedule the no-show check for a reservation (START + grace period)
+    pub async fn schedule_no_show_check(
+        db: &SqlitePool,
+        reservation_id: i64,
+        reservation_start: DateTime<Utc>,
+        grace_minutes: i64,
+    ) -> Result<()> {
+        if grace_minutes <= 0 {
+            return Ok(());
+        }
+
+        let scheduled_for = reservation_start + Duration::minutes(grace_minutes);
+        let payload_str = serde_json::json!({ "reservation_id": reservation_id }).to_string();
+
+        sqlx::query!(
+            "INSERT INTO jobs (job_type, payload, scheduled_for)
+             VALUES ('no_show_check', ?, ?)",
+            payload_str,
+            scheduled_for
+        )
+        .execute(db)
+        .await?;
+
+        info!(
+            "Scheduled no-show check for reservation {} at {}",
+            reservation_id, scheduled_for
+        );
+
+        Ok(())
+    }
+
+    /// Replace the pending no-show checks of a guild after its grace period changed.
+    /// Reservations that have not started yet get a check at the new deadline, so enabling
+    /// detection also covers bookings made while it was off. Returns the number scheduled.
+    pub async fn reschedule_no_show_checks(
+        db: &SqlitePool,
+        guild_id: i64,
+        grace_minutes: i64,
+    ) -> Result<usize> {
+        let now = Utc::now();
+        let mut tx = db.begin().await?;
+
+        sqlx::query!(
+            "DELETE FROM jobs
+             WHERE job_type = 'no_show_check' AND status = 'Pending'
+             AND json_extract(payload, '$.reservation_id') IN (
+                 SELECT r.id FROM reservations r
+                 JOIN equipment e ON r.equipment_id = e.id
+                 WHERE e.guild_id = ?
+             )",
+            guild_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if grace_minutes <= 0 {
+            tx.commit().await?;
+            return Ok(0);
+        }
+
+        let upcoming = sqlx::query!(
+            "SELECT r.id, r.start_time
+             FROM reservations r
+             JOIN equipment e ON r.equipment_id = e.id
+             WHERE e.guild_id = ? AND r.status = 'Confirmed'
+             AND r.picked_up_at IS NULL AND r.returned_at IS NULL AND r.start_time > ?",
+            guild_id,
+            now
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for reservation in &upcoming {
+            let scheduled_for =
+                naive_to_utc(reservation.start_time) + Duration::minutes(grace_minutes);
+            let payload_str = serde_json::json!({ "reservation_id": reservation.id }).to_string();
+
+            sqlx::query!(
+                "INSERT INTO jobs (job_type, payload, scheduled_for)
+                 VALUES ('no_show_check', ?, ?)",
+                payload_str,
+                scheduled_for
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        info!(
+            "Rescheduled {} no-show checks for guild {} with a {} minute grace period",
+            upcoming.len(),
+            guild_id,
+            grace_minutes
+        );
+
+        Ok(upcoming.len())
+    }
+
+    /// Get the configured no-show grace period for a guild
+    pub async fn get_no_show_grace_minutes(db: &SqlitePool, guild_id: i64) -> Result<i64> {
+        let grace = sqlx::query_scalar!(
+            "SELECT no_show_grace_minutes FROM guilds WHERE id = ?",
+            guild_id
+        )
+        .fetch_optional(db)
+        .await?
+        .flatten();
+
+        Ok(grace.unwrap_or(crate::constants::Constants::DEFAULT_NO_SHOW_GRACE_MINUTES))
+    }
+
+    /// Mark an unclaimed reservation as no-show, release its slot and count it against the owner.
+    /// Returns false if the reservation was picked up, returned or is no longer confirmed.
+    pub async fn release_no_show_reservation(db: &SqlitePool, reservation_id: i64) -> Result<bool> {
+        let no_show = crate::constants::Constants::STATUS_NO_SHOW;
+        let mut tx = db.begin().await?;
+
+        let result = sqlx::query!(
+            "UPDATE reservations
+             SET status = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND status = 'Confirmed' AND picked_up_at IS NULL AND returned_at IS NULL",
+            no_show,
+            reservation_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Ok(false);
+        }
+
+        let reservation = sqlx::query!(
+            "SELECT r.equipment_id, r.user_id, e.guild_id
+             FROM reservations r
+             JOIN equipment e ON r.equipment_id = e.id
+             WHERE r.id = ?",
+            reservation_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let now = Utc::now();
+        let log_notes = format!("Reservation {} released: not picked up", reservation_id);
+        sqlx::query!(
+            "INSERT INTO equipment_logs (equipment_id, user_id, action, location, previous_status, new_status, notes, timestamp)
+             VALUES (?, ?, ?, NULL, 'Confirmed', ?, ?, ?)",
+            reservation.equipment_id,
+            reservation.user_id,
+            no_show,
+            no_show,
+            log_notes,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "INSERT INTO user_no_show_counts (guild_id, user_id, no_show_count, last_no_show_at, updated_at)
+             VALUES (?, ?, 1, ?, ?)
+             ON CONFLICT (guild_id, user_id) DO UPDATE SET
+                 no_show_count = no_show_count + 1,
+                 last_no_show_at = excluded.last_no_show_at,
+                 updated_at = excluded.updated_at",
+            reservation.guild_id,
+            reservation.user_id,
+            now,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Self::cancel_reservation_reminders(db, reservation_id).await?;
+
+        info!(
+            "Released no-show reservation {} for user {}",
+            reservation_id, reservation.user_id
+        );
+        Ok(true)
+    }
+
     /// Cancel all future reminders for a reservation (when returned)
     pub async fn cancel_reservation_reminders(db: &SqlitePool, reservation_id: i64) -> Result<()> {
         // Cancel pending reminder jobs
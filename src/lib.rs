@@ -1,12 +1,24 @@
 // Library interface for testing
+pub mod blocklist;
+pub mod buffers;
 pub mod commands;
+pub mod condition_reports;
 pub mod constants;
 pub mod database;
 pub mod equipment;
 pub mod handlers;
+pub mod i18n;
 pub mod jobs;
 pub mod models;
+pub mod operating_hours;
+pub mod penalties;
+pub mod reconcile;
+pub mod reservations;
+pub mod returns;
+pub mod slots;
+pub mod tag_access;
 pub mod time;
+pub mod timeline;
 pub mod traits;
 pub mod transfer_notifications;
 pub mod utils;
@@ -5,15 +5,27 @@ use std::env;
 use tracing::{error, info};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+mod blocklist;
+mod buffers;
 mod commands;
+mod condition_reports;
 mod config;
 mod constants;
 mod database;
 mod equipment;
 mod handlers;
+mod i18n;
 mod jobs;
 mod models;
+mod operating_hours;
+mod penalties;
+mod reconcile;
+mod reservations;
+mod returns;
+mod slots;
+mod tag_access;
 pub mod time;
+mod timeline;
 pub mod traits;
 pub mod utils;
 
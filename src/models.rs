@@ -15,6 +15,17 @@ pub struct Guild {
     pub overdue_max_count: Option<i64>,
     pub pre_start_minutes: Option<i64>,
     pub pre_end_minutes: Option<i64>,
+    // No-show detection (0 disables)
+    pub no_show_grace_minutes: Option<i64>,
+    // Late-return penalties (threshold 0 disables suspension)
+    pub penalty_threshold_points: Option<i64>,
+    pub penalty_suspension_days: Option<i64>,
+    // IANA time zone used for all times shown to and entered by members
+    pub time_zone: Option<String>,
+    // Default message language ("ja" or "en"); members may override it
+    pub language: Option<String>,
+    // Weekly timeline images per tag under the header message
+    pub header_timeline: Option<bool>,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -23,6 +34,8 @@ pub struct Tag {
     pub guild_id: i64,
     pub name: String,
     pub sort_order: i64,
+    pub allowed_roles: Option<String>, // JSON array of role IDs allowed to reserve
+    pub manager_roles: Option<String>, // JSON array of role IDs that manage the tag's equipment
     pub created_at: DateTime<Utc>,
 }
 
@@ -57,7 +70,7 @@ pub struct Reservation {
     pub start_time: DateTime<Utc>,
     pub end_time: DateTime<Utc>,
     pub location: Option<String>,
-    pub status: String, // Confirmed, Cancelled
+    pub status: String, // Confirmed, Cancelled, NoShow
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub returned_at: Option<DateTime<Utc>>,
@@ -117,6 +130,60 @@ pub struct ManagedMessage {
     pub equipment_id: Option<i64>,
     pub sort_order: Option<i64>,
     pub created_at: DateTime<Utc>,
+    pub content_hash: Option<String>,
+}
+
+/// A full teardown and rebuild of a reservation channel
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ChannelRebuild {
+    pub id: i64,
+    pub guild_id: i64,
+    pub channel_id: i64,
+    pub triggered_by: Option<i64>, // None when the bot repaired the channel by itself
+    pub missing_count: i64,
+    pub foreign_count: i64,
+    pub deleted_count: i64,
+    pub created_count: i64,
+    pub rebuilt_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct UserPenalty {
+    pub guild_id: i64,
+    pub user_id: i64,
+    pub points: i64,
+    pub suspended_until: Option<DateTime<Utc>>, // NULL when not suspended
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct UserBlock {
+    pub id: i64,
+    pub guild_id: i64,
+    pub user_id: i64,
+    pub tag_id: Option<i64>, // None blocks all equipment
+    pub tag_name: Option<String>,
+    pub reason: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>, // None means no expiry
+    pub created_by_user_id: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ConditionReport {
+    pub id: i64,
+    pub reservation_id: i64,
+    pub equipment_id: i64,
+    pub guild_id: i64,
+    pub user_id: i64,
+    pub rating: i64, // 1 (broken) to 5 (perfect)
+    pub damage_note: Option<String>,
+    pub image_urls: Vec<String>,
+    pub photos_accepted_until: Option<DateTime<Utc>>, // None when photos are not requested
+    pub alert_channel_id: Option<i64>,
+    pub alert_message_id: Option<i64>,
+    pub marked_unavailable: bool,
+    pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
@@ -162,6 +229,7 @@ impl From<EquipmentStatus> for String {
 pub enum ReservationStatus {
     Confirmed,
     Cancelled,
+    NoShow,
 }
 
 impl From<String> for ReservationStatus {
@@ -169,6 +237,7 @@ impl From<String> for ReservationStatus {
         match s.as_str() {
             "Confirmed" => Self::Confirmed,
             "Cancelled" => Self::Cancelled,
+            "NoShow" => Self::NoShow,
             _ => Self::Confirmed,
         }
     }
@@ -179,6 +248,7 @@ impl From<ReservationStatus> for String {
         match status {
             ReservationStatus::Confirmed => "Confirmed".to_string(),
             ReservationStatus::Cancelled => "Cancelled".to_string(),
+            ReservationStatus::NoShow => "NoShow".to_string(),
         }
     }
 }
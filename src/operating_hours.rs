@@ -0,0 +1,782 @@
+use anyhow::Result;
+use chrono::{
+    DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+    Weekday,
+};
+use chrono_tz::Tz;
+use sqlx::SqlitePool;
+use tracing::info;
+
+use crate::constants::Constants;
+use crate::i18n::{t, tf, Language};
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// One opening window on a weekday, in minutes from local midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpeningHours {
+    pub weekday: Weekday,
+    pub open_minute: u32,
+    pub close_minute: u32,
+}
+
+impl OpeningHours {
+    /// "09:00-21:00"
+    pub fn describe(&self) -> String {
+        format!(
+            "{}-{}",
+            format_minute(self.open_minute),
+            format_minute(self.close_minute)
+        )
+    }
+}
+
+/// A date the clubroom is closed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosedDate {
+    pub date: NaiveDate,
+    pub reason: Option<String>,
+}
+
+/// Why a reservation time is not allowed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleViolation {
+    ClosedDate {
+        date: NaiveDate,
+        reason: Option<String>,
+    },
+    ClosedWeekday {
+        weekday: Weekday,
+    },
+    OutsideHours {
+        time: NaiveDateTime,
+        hours: Vec<OpeningHours>,
+    },
+}
+
+impl ScheduleViolation {
+    /// Explanation shown to the member
+    pub fn message(&self, lang: Language) -> String {
+        match self {
+            ScheduleViolation::ClosedDate { date, reason } => {
+                let date = date.format("%Y/%m/%d");
+                match reason {
+                    Some(reason) => tf(
+                        lang,
+                        "schedule.closed_date_reason",
+                        &[("date", &date), ("reason", reason)],
+                    ),
+                    None => tf(lang, "schedule.closed_date", &[("date", &date)]),
+                }
+            }
+            ScheduleViolation::ClosedWeekday { weekday } => tf(
+                lang,
+                "schedule.closed_weekday",
+                &[("weekday", &weekday_name(*weekday, lang))],
+            ),
+            ScheduleViolation::OutsideHours { time, hours } => {
+                let hours = hours
+                    .iter()
+                    .map(|h| h.describe())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                tf(
+                    lang,
+                    "schedule.outside_hours",
+                    &[("time", &time.format("%Y/%m/%d %H:%M")), ("hours", &hours)],
+                )
+            }
+        }
+    }
+}
+
+/// What is wrong with a line of opening hours or closed dates entered by an admin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputProblem {
+    MissingHours,
+    UnknownWeekday,
+    WeekdayOrder,
+    HoursFormat,
+    InvalidTime,
+    CloseBeforeOpen,
+    InvalidDate,
+    DateRange,
+}
+
+/// A line of admin input that could not be parsed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    /// Line number, starting at 1
+    pub line: usize,
+    pub text: String,
+    pub problem: InputProblem,
+}
+
+impl InputError {
+    fn new(index: usize, text: &str, problem: InputProblem) -> Self {
+        Self {
+            line: index + 1,
+            text: text.to_string(),
+            problem,
+        }
+    }
+
+    /// Explanation shown to the admin
+    pub fn message(&self, lang: Language) -> String {
+        let problem = match self.problem {
+            InputProblem::MissingHours => t(lang, "hours.error_missing_hours").to_string(),
+            InputProblem::UnknownWeekday => t(lang, "hours.error_unknown_weekday").to_string(),
+            InputProblem::WeekdayOrder => t(lang, "hours.error_weekday_order").to_string(),
+            InputProblem::HoursFormat => t(lang, "hours.error_hours_format").to_string(),
+            InputProblem::InvalidTime => t(lang, "hours.error_invalid_time").to_string(),
+            InputProblem::CloseBeforeOpen => t(lang, "hours.error_close_before_open").to_string(),
+            InputProblem::InvalidDate => t(lang, "hours.error_invalid_date").to_string(),
+            InputProblem::DateRange => tf(
+                lang,
+                "hours.error_date_range",
+                &[("days", &Constants::MAX_CLOSED_DATE_RANGE_DAYS)],
+            ),
+        };
+        tf(
+            lang,
+            "hours.input_error",
+            &[
+                ("line", &self.line),
+                ("problem", &problem),
+                ("text", &self.text),
+            ],
+        )
+    }
+}
+
+/// Opening hours and closed dates of a guild, in the guild's time zone.
+/// No opening hours at all means the guild is always open.
+#[derive(Debug, Clone)]
+pub struct OperatingSchedule {
+    pub weekly_hours: Vec<OpeningHours>,
+    pub closed_dates: Vec<ClosedDate>,
+    pub time_zone: Tz,
+}
+
+impl Default for OperatingSchedule {
+    fn default() -> Self {
+        Self {
+            weekly_hours: Vec::new(),
+            closed_dates: Vec::new(),
+            time_zone: crate::time::DEFAULT_TIME_ZONE,
+        }
+    }
+}
+
+impl OperatingSchedule {
+    /// Opening windows on a weekday, earliest first
+    pub fn hours_on(&self, weekday: Weekday) -> Vec<OpeningHours> {
+        let mut hours: Vec<OpeningHours> = self
+            .weekly_hours
+            .iter()
+            .filter(|h| h.weekday == weekday)
+            .copied()
+            .collect();
+        hours.sort_by_key(|h| h.open_minute);
+        hours
+    }
+
+    /// Check a reservation boundary. A reservation may start from the opening time until
+    /// before closing and end from the opening time up to closing.
+    pub fn check_time(&self, time: DateTime<Utc>, is_end: bool) -> Result<(), ScheduleViolation> {
+        let local = self
+            .time_zone
+            .from_utc_datetime(&time.naive_utc())
+            .naive_local();
+        let mut date = local.date();
+        let mut minute = local.hour() * 60 + local.minute();
+
+        // Ending at midnight is 24:00 of the previous day, whatever the new day brings
+        if is_end && minute == 0 {
+            if let Some(previous) = date.pred_opt() {
+                date = previous;
+                minute = 24 * 60;
+            }
+        }
+
+        if let Some(closed) = self.closed_dates.iter().find(|c| c.date == date) {
+            return Err(ScheduleViolation::ClosedDate {
+                date,
+                reason: closed.reason.clone(),
+            });
+        }
+
+        if self.weekly_hours.is_empty() {
+            return Ok(());
+        }
+
+        let hours = self.hours_on(date.weekday());
+        if hours.is_empty() {
+            return Err(ScheduleViolation::ClosedWeekday {
+                weekday: date.weekday(),
+            });
+        }
+
+        let within = hours.iter().any(|h| {
+            if is_end {
+                h.open_minute <= minute && minute <= h.close_minute
+            } else {
+                h.open_minute <= minute && minute < h.close_minute
+            }
+        });
+
+        if within {
+            Ok(())
+        } else {
+            Err(ScheduleViolation::OutsideHours { time: local, hours })
+        }
+    }
+
+    /// Both the start and the end of a reservation must fall within opening hours
+    pub fn check_reservation(
+        &self,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<(), ScheduleViolation> {
+        self.check_time(start_time, false)?;
+        self.check_time(end_time, true)
+    }
+
+    /// A minute of a local date in UTC; 1440 is the next midnight. A time skipped when the
+    /// clocks go forward is taken an hour later.
+    pub fn local_minute(&self, date: NaiveDate, minute: u32) -> DateTime<Utc> {
+        let local = date.and_time(NaiveTime::MIN) + Duration::minutes(minute as i64);
+        crate::time::local_to_utc(local, self.time_zone)
+            .or_else(|_| crate::time::local_to_utc(local + Duration::hours(1), self.time_zone))
+            .unwrap_or_else(|_| Utc.from_utc_datetime(&local))
+    }
+
+    /// Stretches of a local date in which the guild is closed, earliest first
+    pub fn closed_periods(&self, date: NaiveDate) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        let whole_day = vec![(self.local_minute(date, 0), self.local_minute(date, 24 * 60))];
+        if self.closed_dates.iter().any(|c| c.date == date) {
+            return whole_day;
+        }
+        if self.weekly_hours.is_empty() {
+            return Vec::new();
+        }
+
+        let mut periods = Vec::new();
+        let mut cursor = 0;
+        for hours in self.hours_on(date.weekday()) {
+            if hours.open_minute > cursor {
+                periods.push((
+                    self.local_minute(date, cursor),
+                    self.local_minute(date, hours.open_minute),
+                ));
+            }
+            cursor = cursor.max(hours.close_minute);
+        }
+        if cursor < 24 * 60 {
+            periods.push((
+                self.local_minute(date, cursor),
+                self.local_minute(date, 24 * 60),
+            ));
+        }
+        periods
+    }
+}
+
+/// Format a minute of the day as HH:MM (1440 is 24:00)
+pub fn format_minute(minute: u32) -> String {
+    format!("{:02}:{:02}", minute / 60, minute % 60)
+}
+
+pub fn weekday_name(weekday: Weekday, lang: Language) -> &'static str {
+    let key = match weekday {
+        Weekday::Mon => "weekday.mon",
+        Weekday::Tue => "weekday.tue",
+        Weekday::Wed => "weekday.wed",
+        Weekday::Thu => "weekday.thu",
+        Weekday::Fri => "weekday.fri",
+        Weekday::Sat => "weekday.sat",
+        Weekday::Sun => "weekday.sun",
+    };
+    t(lang, key)
+}
+
+fn parse_weekday(input: &str) -> Option<Weekday> {
+    let lower = input.trim().to_lowercase();
+    let prefix = lower.get(..3)?;
+    WEEKDAYS.iter().copied().find(|w| {
+        let name = format!("{:?}", w).to_lowercase();
+        name == prefix
+    })
+}
+
+fn parse_minute(input: &str) -> Option<u32> {
+    let (hour, minute) = input.trim().split_once(':')?;
+    let hour: u32 = hour.trim().parse().ok()?;
+    let minute: u32 = minute.trim().parse().ok()?;
+    if minute >= 60 || hour > 24 || (hour == 24 && minute != 0) {
+        return None;
+    }
+    Some(hour * 60 + minute)
+}
+
+/// Format weekly hours one weekday per line, in the format `parse_weekly_hours` accepts
+pub fn format_weekly_hours(hours: &[OpeningHours]) -> String {
+    WEEKDAYS
+        .iter()
+        .map(|weekday| {
+            let mut day_hours: Vec<&OpeningHours> =
+                hours.iter().filter(|h| h.weekday == *weekday).collect();
+            day_hours.sort_by_key(|h| h.open_minute);
+            let desc = if day_hours.is_empty() {
+                "closed".to_string()
+            } else {
+                day_hours
+                    .iter()
+                    .map(|h| h.describe())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            format!("{:?} {}", weekday, desc)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parse weekly opening hours entered by an admin, one entry per line:
+/// `Mon 09:00-21:00`, `Mon-Fri 09:00-12:00, 13:00-21:00` or `Sun closed`.
+/// Weekdays that are not listed are closed; empty input clears the hours.
+pub fn parse_weekly_hours(input: &str) -> Result<Vec<OpeningHours>, InputError> {
+    let mut hours = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let line_error = |problem| InputError::new(index, line, problem);
+
+        let (days, times) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| line_error(InputProblem::MissingHours))?;
+
+        let weekdays: Vec<Weekday> = match days.split_once('-') {
+            Some((first, last)) => {
+                let first =
+                    parse_weekday(first).ok_or_else(|| line_error(InputProblem::UnknownWeekday))?;
+                let last =
+                    parse_weekday(last).ok_or_else(|| line_error(InputProblem::UnknownWeekday))?;
+                let first_index = first.num_days_from_monday() as usize;
+                let last_index = last.num_days_from_monday() as usize;
+                if first_index > last_index {
+                    return Err(line_error(InputProblem::WeekdayOrder));
+                }
+                WEEKDAYS[first_index..=last_index].to_vec()
+            }
+            None => {
+                vec![parse_weekday(days).ok_or_else(|| line_error(InputProblem::UnknownWeekday))?]
+            }
+        };
+
+        if times.trim().eq_ignore_ascii_case("closed") {
+            continue;
+        }
+
+        for window in times.split(',') {
+            let (open, close) = window
+                .split_once('-')
+                .ok_or_else(|| line_error(InputProblem::HoursFormat))?;
+            let open_minute =
+                parse_minute(open).ok_or_else(|| line_error(InputProblem::InvalidTime))?;
+            let close_minute =
+                parse_minute(close).ok_or_else(|| line_error(InputProblem::InvalidTime))?;
+            if open_minute >= close_minute {
+                return Err(line_error(InputProblem::CloseBeforeOpen));
+            }
+            for weekday in &weekdays {
+                hours.push(OpeningHours {
+                    weekday: *weekday,
+                    open_minute,
+                    close_minute,
+                });
+            }
+        }
+    }
+
+    Ok(hours)
+}
+
+fn parse_date(input: &str) -> Option<NaiveDate> {
+    let input = input.trim();
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(input, "%Y/%m/%d"))
+        .ok()
+}
+
+fn date_range(first: NaiveDate, last: NaiveDate) -> Option<Vec<NaiveDate>> {
+    let days = (last - first).num_days();
+    if !(0..Constants::MAX_CLOSED_DATE_RANGE_DAYS).contains(&days) {
+        return None;
+    }
+    Some(
+        (0..=days)
+            .map(|offset| first + Duration::days(offset))
+            .collect(),
+    )
+}
+
+/// Parse closed dates entered by an admin, one entry per line:
+/// `2025-01-01 New Year's Day` or `2024-12-28..2025-01-05 Winter break`
+pub fn parse_closed_dates(input: &str) -> Result<Vec<ClosedDate>, InputError> {
+    let mut dates = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let line_error = |problem| InputError::new(index, line, problem);
+
+        let (range, reason) = match line.split_once(char::is_whitespace) {
+            Some((range, reason)) => (range, Some(reason.trim().to_string())),
+            None => (line, None),
+        };
+        let reason = reason.filter(|r| !r.is_empty());
+
+        let range_dates = match range.split_once("..") {
+            Some((first, last)) => {
+                let first =
+                    parse_date(first).ok_or_else(|| line_error(InputProblem::InvalidDate))?;
+                let last = parse_date(last).ok_or_else(|| line_error(InputProblem::InvalidDate))?;
+                date_range(first, last).ok_or_else(|| line_error(InputProblem::DateRange))?
+            }
+            None => vec![parse_date(range).ok_or_else(|| line_error(InputProblem::InvalidDate))?],
+        };
+
+        dates.extend(range_dates.into_iter().map(|date| ClosedDate {
+            date,
+            reason: reason.clone(),
+        }));
+    }
+
+    Ok(dates)
+}
+
+/// Parse an ICS date or date-time value into a local date and whether it had a time part
+fn parse_ics_value(value: &str, tz: Tz) -> Option<(NaiveDate, bool)> {
+    let value = value.trim();
+    if value.len() == 8 {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()
+            .map(|date| (date, false));
+    }
+
+    let (naive, utc) = match value.strip_suffix('Z') {
+        Some(value) => (value, true),
+        None => (value, false),
+    };
+    let datetime = NaiveDateTime::parse_from_str(naive, "%Y%m%dT%H%M%S").ok()?;
+    let local = if utc {
+        tz.from_utc_datetime(&datetime).naive_local()
+    } else {
+        datetime
+    };
+    Some((local.date(), local.time() != chrono::NaiveTime::MIN))
+}
+
+fn unescape_ics_text(value: &str) -> String {
+    value
+        .replace("\\n", " ")
+        .replace("\\N", " ")
+        .replace("\\,", ",")
+        .replace("\\;", ";")
+        .replace("\\\\", "\\")
+        .trim()
+        .to_string()
+}
+
+/// Closed dates from the events of an ICS calendar (e.g. a university holiday calendar).
+/// All-day events close every day they cover; timed events close the days they touch.
+/// UTC times are read in `tz`. Recurrence rules are not expanded.
+pub fn parse_ics_closed_dates(ics: &str, tz: Tz) -> Vec<ClosedDate> {
+    // Unfold continuation lines (RFC 5545 3.1)
+    let mut lines: Vec<String> = Vec::new();
+    for raw in ics.lines() {
+        let raw = raw.trim_end_matches('\r');
+        if let Some(continuation) = raw.strip_prefix(' ').or_else(|| raw.strip_prefix('\t')) {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(continuation);
+                continue;
+            }
+        }
+        lines.push(raw.to_string());
+    }
+
+    let mut dates = Vec::new();
+    let mut in_event = false;
+    let mut start: Option<(NaiveDate, bool)> = None;
+    let mut end: Option<(NaiveDate, bool)> = None;
+    let mut summary: Option<String> = None;
+
+    for line in &lines {
+        match line.as_str() {
+            "BEGIN:VEVENT" => {
+                in_event = true;
+                start = None;
+                end = None;
+                summary = None;
+                continue;
+            }
+            "END:VEVENT" => {
+                in_event = false;
+                let Some((first, _)) = start else {
+                    continue;
+                };
+                let last = match end {
+                    // DTEND is exclusive for all-day events and for events ending at midnight
+                    Some((end_date, false)) if end_date > first => end_date - Duration::days(1),
+                    Some((end_date, _)) => end_date.max(first),
+                    None => first,
+                };
+                if let Some(range) = date_range(first, last) {
+                    dates.extend(range.into_iter().map(|date| ClosedDate {
+                        date,
+                        reason: summary.clone(),
+                    }));
+                }
+                continue;
+            }
+            _ => {}
+        }
+
+        if !in_event {
+            continue;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let property = name.split(';').next().unwrap_or_default().to_uppercase();
+        match property.as_str() {
+            "DTSTART" => start = parse_ics_value(value, tz),
+            "DTEND" => end = parse_ics_value(value, tz),
+            "SUMMARY" => summary = Some(unescape_ics_text(value)).filter(|s| !s.is_empty()),
+            _ => {}
+        }
+    }
+
+    dates
+}
+
+fn weekday_from_index(index: i64) -> Weekday {
+    WEEKDAYS[index.clamp(0, 6) as usize]
+}
+
+/// Opening hours and closed dates per guild
+pub struct OperatingHoursService {
+    db: SqlitePool,
+}
+
+impl OperatingHoursService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    pub async fn weekly_hours(&self, guild_id: i64) -> Result<Vec<OpeningHours>> {
+        let rows = sqlx::query!(
+            "SELECT weekday, open_minute, close_minute FROM operating_hours
+             WHERE guild_id = ?
+             ORDER BY weekday, open_minute",
+            guild_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| OpeningHours {
+                weekday: weekday_from_index(row.weekday),
+                open_minute: row.open_minute as u32,
+                close_minute: row.close_minute as u32,
+            })
+            .collect())
+    }
+
+    /// Replace the weekly opening hours of a guild; an empty list means always open
+    pub async fn set_weekly_hours(&self, guild_id: i64, hours: &[OpeningHours]) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+
+        sqlx::query!("DELETE FROM operating_hours WHERE guild_id = ?", guild_id)
+            .execute(&mut *tx)
+            .await?;
+
+        for h in hours {
+            let weekday = h.weekday.num_days_from_monday() as i64;
+            let open_minute = h.open_minute as i64;
+            let close_minute = h.close_minute as i64;
+            sqlx::query!(
+                "INSERT INTO operating_hours (guild_id, weekday, open_minute, close_minute)
+                 VALUES (?, ?, ?, ?)",
+                guild_id,
+                weekday,
+                open_minute,
+                close_minute
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        info!(
+            "Set {} opening window(s) for guild {}",
+            hours.len(),
+            guild_id
+        );
+        Ok(())
+    }
+
+    /// Add closed dates, replacing the reason of dates that already exist.
+    /// Returns the number of dates stored.
+    pub async fn add_closed_dates(&self, guild_id: i64, dates: &[ClosedDate]) -> Result<usize> {
+        let mut tx = self.db.begin().await?;
+
+        for closed in dates {
+            sqlx::query!(
+                "INSERT INTO closed_dates (guild_id, date, reason)
+                 VALUES (?, ?, ?)
+                 ON CONFLICT (guild_id, date) DO UPDATE SET reason = excluded.reason",
+                guild_id,
+                closed.date,
+                closed.reason
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        info!(
+            "Added {} closed date(s) for guild {}",
+            dates.len(),
+            guild_id
+        );
+        Ok(dates.len())
+    }
+
+    pub async fn remove_closed_dates(&self, guild_id: i64, dates: &[NaiveDate]) -> Result<()> {
+        for date in dates {
+            sqlx::query!(
+                "DELETE FROM closed_dates WHERE guild_id = ? AND date = ?",
+                guild_id,
+                date
+            )
+            .execute(&self.db)
+            .await?;
+        }
+
+        info!(
+            "Removed {} closed date(s) for guild {}",
+            dates.len(),
+            guild_id
+        );
+        Ok(())
+    }
+
+    /// Closed dates from `from` onwards, earliest first
+    pub async fn upcoming_closed_dates(
+        &self,
+        guild_id: i64,
+        from: NaiveDate,
+        limit: i64,
+    ) -> Result<Vec<ClosedDate>> {
+        let rows = sqlx::query!(
+            "SELECT date, reason FROM closed_dates
+             WHERE guild_id = ? AND date >= ?
+             ORDER BY date
+             LIMIT ?",
+            guild_id,
+            from,
+            limit
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ClosedDate {
+                date: row.date,
+                reason: row.reason,
+            })
+            .collect())
+    }
+
+    /// Opening hours plus the closed dates within [from, until]
+    pub async fn schedule_between(
+        &self,
+        guild_id: i64,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<OperatingSchedule> {
+        let weekly_hours = self.weekly_hours(guild_id).await?;
+        let time_zone = crate::time::guild_time_zone(&self.db, guild_id).await?;
+        let closed_dates = sqlx::query!(
+            "SELECT date, reason FROM closed_dates
+             WHERE guild_id = ? AND date BETWEEN ? AND ?
+             ORDER BY date",
+            guild_id,
+            from,
+            until
+        )
+        .fetch_all(&self.db)
+        .await?
+        .into_iter()
+        .map(|row| ClosedDate {
+            date: row.date,
+            reason: row.reason,
+        })
+        .collect();
+
+        Ok(OperatingSchedule {
+            weekly_hours,
+            closed_dates,
+            time_zone,
+        })
+    }
+
+    /// Check that a reservation starts and ends while the guild is open
+    pub async fn check_reservation(
+        &self,
+        guild_id: i64,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Option<ScheduleViolation>> {
+        let tz = crate::time::guild_time_zone(&self.db, guild_id).await?;
+        let local_date = |time: DateTime<Utc>| crate::time::local_date(time, tz);
+        let schedule = self
+            .schedule_between(guild_id, local_date(start_time), local_date(end_time))
+            .await?;
+        Ok(schedule.check_reservation(start_time, end_time).err())
+    }
+
+    /// Check a single reservation boundary, as entered in a wizard step
+    pub async fn check_time(
+        &self,
+        guild_id: i64,
+        time: DateTime<Utc>,
+        is_end: bool,
+    ) -> Result<Option<ScheduleViolation>> {
+        let tz = crate::time::guild_time_zone(&self.db, guild_id).await?;
+        let local_date = crate::time::local_date(time, tz);
+        let schedule = self
+            .schedule_between(guild_id, local_date, local_date)
+            .await?;
+        Ok(schedule.check_time(time, is_end).err())
+    }
+}
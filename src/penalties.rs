@@ -0,0 +1,508 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use sqlx::{SqliteConnection, SqlitePool};
+use tracing::info;
+
+use crate::constants::Constants;
+use crate::models::UserPenalty;
+
+/// Guild penalty policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PenaltyPolicy {
+    /// Points at which new reservations are suspended (0 disables suspension)
+    pub threshold_points: i64,
+    /// Length of a suspension in days (0 disables suspension)
+    pub suspension_days: i64,
+}
+
+impl PenaltyPolicy {
+    /// Whether crossing the threshold suspends the member
+    pub fn suspends(&self) -> bool {
+        self.threshold_points > 0 && self.suspension_days > 0
+    }
+}
+
+impl Default for PenaltyPolicy {
+    fn default() -> Self {
+        Self {
+            threshold_points: Constants::DEFAULT_PENALTY_THRESHOLD_POINTS,
+            suspension_days: Constants::DEFAULT_PENALTY_SUSPENSION_DAYS,
+        }
+    }
+}
+
+/// Result of changing a member's points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PenaltyOutcome {
+    pub points_added: i64,
+    pub total_points: i64,
+    pub suspended_until: Option<DateTime<Utc>>,
+}
+
+/// Points for returning late: LATE_RETURN_POINTS_PER_HOUR for every started hour past end_time
+pub fn late_return_points(end_time: DateTime<Utc>, returned_at: DateTime<Utc>) -> i64 {
+    let late_minutes = (returned_at - end_time).num_minutes();
+    if late_minutes <= 0 {
+        return 0;
+    }
+
+    let started_hours = (late_minutes + 59) / 60;
+    started_hours * Constants::LATE_RETURN_POINTS_PER_HOUR
+}
+
+/// Late-return penalty points and booking suspensions
+pub struct PenaltyService {
+    db: SqlitePool,
+}
+
+impl PenaltyService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// Get the penalty policy configured for a guild
+    pub async fn get_policy(&self, guild_id: i64) -> Result<PenaltyPolicy> {
+        let row = sqlx::query!(
+            "SELECT penalty_threshold_points, penalty_suspension_days FROM guilds WHERE id = ?",
+            guild_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        let default = PenaltyPolicy::default();
+        Ok(match row {
+            Some(row) => PenaltyPolicy {
+                threshold_points: row
+                    .penalty_threshold_points
+                    .unwrap_or(default.threshold_points),
+                suspension_days: row
+                    .penalty_suspension_days
+                    .unwrap_or(default.suspension_days),
+            },
+            None => default,
+        })
+    }
+
+    /// Update the penalty policy for a guild
+    pub async fn set_policy(&self, guild_id: i64, policy: PenaltyPolicy) -> Result<()> {
+        sqlx::query!(
+            "UPDATE guilds SET penalty_threshold_points = ?, penalty_suspension_days = ?,
+             updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+            policy.threshold_points,
+            policy.suspension_days,
+            guild_id
+        )
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get the current penalty state of a member
+    pub async fn get_user_penalty(&self, guild_id: i64, user_id: i64) -> Result<UserPenalty> {
+        let row = sqlx::query!(
+            "SELECT points, suspended_until, updated_at FROM user_penalties
+             WHERE guild_id = ? AND user_id = ?",
+            guild_id,
+            user_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(match row {
+            Some(row) => UserPenalty {
+                guild_id,
+                user_id,
+                points: row.points,
+                suspended_until: row.suspended_until.map(crate::time::naive_to_utc),
+                updated_at: crate::time::naive_to_utc(row.updated_at),
+            },
+            None => UserPenalty {
+                guild_id,
+                user_id,
+                points: 0,
+                suspended_until: None,
+                updated_at: Utc::now(),
+            },
+        })
+    }
+
+    /// Get the end of the member's suspension if one is currently in effect
+    pub async fn active_suspension(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let penalty = self.get_user_penalty(guild_id, user_id).await?;
+        Ok(penalty.suspended_until.filter(|until| *until > Utc::now()))
+    }
+
+    /// Read a member's penalty row inside a transaction, creating it if needed.
+    /// Writing first takes the database write lock, so concurrent updates to the
+    /// same member wait for this transaction instead of overwriting its result.
+    async fn lock_user_penalty(
+        conn: &mut SqliteConnection,
+        guild_id: i64,
+        user_id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<UserPenalty> {
+        let row = sqlx::query!(
+            "INSERT INTO user_penalties (guild_id, user_id, points, suspended_until, updated_at)
+             VALUES (?, ?, 0, NULL, ?)
+             ON CONFLICT (guild_id, user_id) DO UPDATE SET updated_at = user_penalties.updated_at
+             RETURNING points, suspended_until",
+            guild_id,
+            user_id,
+            now
+        )
+        .fetch_one(conn)
+        .await?;
+
+        Ok(UserPenalty {
+            guild_id,
+            user_id,
+            points: row.points,
+            suspended_until: row.suspended_until.map(crate::time::naive_to_utc),
+            updated_at: now,
+        })
+    }
+
+    /// Write back a member's points and suspension
+    async fn store_user_penalty(conn: &mut SqliteConnection, penalty: &UserPenalty) -> Result<()> {
+        sqlx::query!(
+            "UPDATE user_penalties SET points = ?, suspended_until = ?, updated_at = ?
+             WHERE guild_id = ? AND user_id = ?",
+            penalty.points,
+            penalty.suspended_until,
+            penalty.updated_at,
+            penalty.guild_id,
+            penalty.user_id
+        )
+        .execute(conn)
+        .await?;
+        Ok(())
+    }
+
+    /// Suspend the member when their points reached the threshold, inside the transaction
+    /// that changed them. The suspension clears the points and is logged in equipment_logs
+    /// when it was caused by a return. Returns the end of the new suspension.
+    async fn apply_threshold(
+        conn: &mut SqliteConnection,
+        penalty: &mut UserPenalty,
+        policy: PenaltyPolicy,
+        tz: Tz,
+        equipment_id: Option<i64>,
+        reservation_id: Option<i64>,
+        now: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let already_suspended = penalty.suspended_until.is_some_and(|until| until > now);
+        if !policy.suspends() || penalty.points < policy.threshold_points || already_suspended {
+            return Ok(None);
+        }
+
+        let until = now + Duration::days(policy.suspension_days);
+        let suspension_notes = format!(
+            "Reservations suspended until {} ({} penalty points, threshold {})",
+            crate::time::utc_to_local_string(until, tz),
+            penalty.points,
+            policy.threshold_points
+        );
+
+        if let Some(equipment_id) = equipment_id {
+            sqlx::query!(
+                "INSERT INTO equipment_logs (equipment_id, user_id, action, location, previous_status, new_status, notes, timestamp)
+                 VALUES (?, ?, 'Suspended', NULL, NULL, NULL, ?, ?)",
+                equipment_id,
+                penalty.user_id,
+                suspension_notes,
+                now
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        let reset_delta = -penalty.points;
+        sqlx::query!(
+            "INSERT INTO penalty_events (guild_id, user_id, reservation_id, points_delta, reason, actor_user_id, notes, created_at)
+             VALUES (?, ?, ?, ?, 'Suspended', NULL, ?, ?)",
+            penalty.guild_id,
+            penalty.user_id,
+            reservation_id,
+            reset_delta,
+            suspension_notes,
+            now
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        penalty.points = 0;
+        penalty.suspended_until = Some(until);
+        Ok(Some(until))
+    }
+
+    /// Add points for a late return and suspend the member if the threshold is crossed.
+    /// Suspensions are logged in equipment_logs against the returned equipment.
+    pub async fn record_late_return(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+        equipment_id: i64,
+        reservation_id: i64,
+        end_time: DateTime<Utc>,
+        returned_at: DateTime<Utc>,
+    ) -> Result<PenaltyOutcome> {
+        let points = late_return_points(end_time, returned_at);
+
+        if points == 0 {
+            let current = self.get_user_penalty(guild_id, user_id).await?;
+            return Ok(PenaltyOutcome {
+                points_added: 0,
+                total_points: current.points,
+                suspended_until: None,
+            });
+        }
+
+        let policy = self.get_policy(guild_id).await?;
+        let tz = crate::time::guild_time_zone(&self.db, guild_id).await?;
+        let now = Utc::now();
+        let late_minutes = (returned_at - end_time).num_minutes();
+
+        let mut tx = self.db.begin().await?;
+
+        let mut penalty = Self::lock_user_penalty(&mut tx, guild_id, user_id, now).await?;
+        penalty.points += points;
+
+        let event_notes = format!("Returned {} minutes late", late_minutes);
+        sqlx::query!(
+            "INSERT INTO penalty_events (guild_id, user_id, reservation_id, points_delta, reason, actor_user_id, notes, created_at)
+             VALUES (?, ?, ?, ?, 'LateReturn', NULL, ?, ?)",
+            guild_id,
+            user_id,
+            reservation_id,
+            points,
+            event_notes,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let suspended_until = Self::apply_threshold(
+            &mut tx,
+            &mut penalty,
+            policy,
+            tz,
+            Some(equipment_id),
+            Some(reservation_id),
+            now,
+        )
+        .await?;
+        Self::store_user_penalty(&mut tx, &penalty).await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Recorded late return for user {} in guild {}: +{} points (total {})",
+            user_id, guild_id, points, penalty.points
+        );
+
+        Ok(PenaltyOutcome {
+            points_added: points,
+            total_points: penalty.points,
+            suspended_until,
+        })
+    }
+
+    /// Remove the late-return points recorded for a reservation whose return was cancelled.
+    /// Returns the number of points removed; an existing suspension is left in place.
+    pub async fn revert_late_return(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+        reservation_id: i64,
+    ) -> Result<i64> {
+        let recorded = sqlx::query_scalar!(
+            "SELECT COALESCE(SUM(points_delta), 0) as \"points!: i64\" FROM penalty_events
+             WHERE guild_id = ? AND user_id = ? AND reservation_id = ?
+               AND reason IN ('LateReturn', 'ReturnCancelled')",
+            guild_id,
+            user_id,
+            reservation_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        if recorded <= 0 {
+            return Ok(0);
+        }
+
+        let now = Utc::now();
+
+        let mut tx = self.db.begin().await?;
+
+        let current = Self::lock_user_penalty(&mut tx, guild_id, user_id, now).await?;
+        let total_points = (current.points - recorded).max(0);
+
+        let reverted_delta = -recorded;
+        sqlx::query!(
+            "INSERT INTO penalty_events (guild_id, user_id, reservation_id, points_delta, reason, actor_user_id, notes, created_at)
+             VALUES (?, ?, ?, ?, 'ReturnCancelled', ?, 'Return cancelled', ?)",
+            guild_id,
+            user_id,
+            reservation_id,
+            reverted_delta,
+            user_id,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE user_penalties SET points = ?, updated_at = ?
+             WHERE guild_id = ? AND user_id = ?",
+            total_points,
+            now,
+            guild_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Reverted {} late-return point(s) for user {} in guild {} (reservation {})",
+            recorded, user_id, guild_id, reservation_id
+        );
+        Ok(recorded)
+    }
+
+    /// Adjust a member's points by an admin (never below zero). Reaching the threshold
+    /// suspends the member just like a late return does.
+    pub async fn adjust_points(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+        delta: i64,
+        actor_user_id: i64,
+        notes: Option<String>,
+    ) -> Result<PenaltyOutcome> {
+        let policy = self.get_policy(guild_id).await?;
+        let tz = crate::time::guild_time_zone(&self.db, guild_id).await?;
+        let now = Utc::now();
+
+        let mut tx = self.db.begin().await?;
+
+        let mut penalty = Self::lock_user_penalty(&mut tx, guild_id, user_id, now).await?;
+        let total_points = (penalty.points + delta).max(0);
+        let applied_delta = total_points - penalty.points;
+        penalty.points = total_points;
+
+        sqlx::query!(
+            "INSERT INTO penalty_events (guild_id, user_id, reservation_id, points_delta, reason, actor_user_id, notes, created_at)
+             VALUES (?, ?, NULL, ?, 'Adjusted', ?, ?, ?)",
+            guild_id,
+            user_id,
+            applied_delta,
+            actor_user_id,
+            notes,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let suspended_until =
+            Self::apply_threshold(&mut tx, &mut penalty, policy, tz, None, None, now).await?;
+        Self::store_user_penalty(&mut tx, &penalty).await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Penalty points for user {} in guild {} adjusted by {} ({:+})",
+            user_id, guild_id, actor_user_id, applied_delta
+        );
+        Ok(PenaltyOutcome {
+            points_added: applied_delta,
+            total_points: penalty.points,
+            suspended_until,
+        })
+    }
+
+    /// Clear a member's points and lift any suspension
+    pub async fn forgive(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+        actor_user_id: i64,
+        notes: Option<String>,
+    ) -> Result<()> {
+        let now = Utc::now();
+
+        let mut tx = self.db.begin().await?;
+
+        let current = Self::lock_user_penalty(&mut tx, guild_id, user_id, now).await?;
+        let reset_delta = -current.points;
+
+        sqlx::query!(
+            "INSERT INTO penalty_events (guild_id, user_id, reservation_id, points_delta, reason, actor_user_id, notes, created_at)
+             VALUES (?, ?, NULL, ?, 'Forgiven', ?, ?, ?)",
+            guild_id,
+            user_id,
+            reset_delta,
+            actor_user_id,
+            notes,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            "UPDATE user_penalties SET points = 0, suspended_until = NULL, updated_at = ?
+             WHERE guild_id = ? AND user_id = ?",
+            now,
+            guild_id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Penalties for user {} in guild {} forgiven by {}",
+            user_id, guild_id, actor_user_id
+        );
+        Ok(())
+    }
+
+    /// Members with points or an active suspension, highest first
+    pub async fn list_penalized_members(
+        &self,
+        guild_id: i64,
+        limit: i64,
+    ) -> Result<Vec<UserPenalty>> {
+        let now = Utc::now();
+        let rows = sqlx::query!(
+            "SELECT user_id, points, suspended_until, updated_at FROM user_penalties
+             WHERE guild_id = ? AND (points > 0 OR suspended_until > ?)
+             ORDER BY suspended_until IS NULL, points DESC, updated_at DESC
+             LIMIT ?",
+            guild_id,
+            now,
+            limit
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| UserPenalty {
+                guild_id,
+                user_id: row.user_id,
+                points: row.points,
+                suspended_until: row.suspended_until.map(crate::time::naive_to_utc),
+                updated_at: crate::time::naive_to_utc(row.updated_at),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,368 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use serenity::all::Context;
+use sqlx::SqlitePool;
+use tracing::{error, info};
+
+use crate::constants::Constants;
+use crate::equipment::EquipmentRenderer;
+use crate::i18n::{tf, Language};
+
+/// Token bucket that paces message edits in one channel. It starts full, so a
+/// short burst goes out at once, then refills at a steady rate.
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(capacity: u32, refill_per_sec: f64, now: Instant) -> Self {
+        Self {
+            capacity: capacity as f64,
+            tokens: capacity as f64,
+            refill_per_sec,
+            updated_at: now,
+        }
+    }
+
+    /// Take a token, or return how long to wait until one is available
+    pub fn try_take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.updated_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.refill_per_sec,
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RefreshState {
+    Pending,
+    Running,
+    RunningDirty,
+}
+
+/// Refresh requests per channel. Requests that arrive while a refresh is waiting
+/// are folded into it; requests during a run cause exactly one more run. If any of
+/// the folded requests asked for a repair, the run checks the channel history.
+#[derive(Debug, Default)]
+pub struct RefreshQueue {
+    channels: Mutex<HashMap<i64, (RefreshState, bool)>>,
+}
+
+impl RefreshQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a request; true when the caller should start a worker for the channel
+    pub fn request(&self, channel_id: i64, repair: bool) -> bool {
+        let mut channels = self.channels.lock().unwrap();
+        match channels.get_mut(&channel_id) {
+            None => {
+                channels.insert(channel_id, (RefreshState::Pending, repair));
+                true
+            }
+            Some((state, pending_repair)) => {
+                if *state == RefreshState::Running {
+                    *state = RefreshState::RunningDirty;
+                }
+                *pending_repair |= repair;
+                false
+            }
+        }
+    }
+
+    /// Mark the channel's pending refresh as running; true when it should repair
+    pub fn start(&self, channel_id: i64) -> bool {
+        let mut channels = self.channels.lock().unwrap();
+        let (_, repair) = channels
+            .insert(channel_id, (RefreshState::Running, false))
+            .unwrap_or((RefreshState::Pending, false));
+        repair
+    }
+
+    /// Finish a run; true when requests came in meanwhile and it should run again
+    pub fn finish(&self, channel_id: i64) -> bool {
+        let mut channels = self.channels.lock().unwrap();
+        match channels.get_mut(&channel_id) {
+            Some((state, _)) if *state == RefreshState::RunningDirty => {
+                *state = RefreshState::Pending;
+                true
+            }
+            _ => {
+                channels.remove(&channel_id);
+                false
+            }
+        }
+    }
+
+    pub fn is_idle(&self, channel_id: i64) -> bool {
+        !self.channels.lock().unwrap().contains_key(&channel_id)
+    }
+}
+
+/// Where a channel's history and its tracked messages disagree
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChannelDrift {
+    /// Tracked messages that are no longer in the channel
+    pub missing: Vec<i64>,
+    /// Untracked bot messages that look like the header or an equipment message
+    pub foreign: Vec<i64>,
+}
+
+impl ChannelDrift {
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.foreign.is_empty()
+    }
+}
+
+/// Whether a bot message has the shape of the display: the header or setup guide
+/// (Overall Management button) or an equipment message (its settings button).
+/// Damage alerts and notification fallbacks the bot also posts in the channel
+/// have neither and are left alone.
+pub fn is_display_message<'a>(custom_ids: impl IntoIterator<Item = &'a str>) -> bool {
+    custom_ids
+        .into_iter()
+        .any(|id| id == "overall_mgmt_open" || id.starts_with("eq_settings_"))
+}
+
+/// Compare tracked message IDs with the channel's history, given as
+/// `(message_id, is_display_message)` with the flag only set for the bot's own
+/// messages. When the history was cut short, only tracked messages newer than the
+/// oldest one fetched can be judged missing.
+pub fn find_channel_drift(
+    tracked: &[i64],
+    history: &[(i64, bool)],
+    complete: bool,
+) -> ChannelDrift {
+    let present: HashSet<i64> = history.iter().map(|(id, _)| *id).collect();
+    let tracked_set: HashSet<i64> = tracked.iter().copied().collect();
+    let oldest = history.iter().map(|(id, _)| *id).min();
+
+    let missing = tracked
+        .iter()
+        .copied()
+        .filter(|id| !present.contains(id))
+        .filter(|id| complete || oldest.is_some_and(|oldest| *id > oldest))
+        .collect();
+    let foreign = history
+        .iter()
+        .filter(|(id, is_display)| *is_display && !tracked_set.contains(id))
+        .map(|(id, _)| *id)
+        .collect();
+    ChannelDrift { missing, foreign }
+}
+
+/// Messages touched by one reconciliation
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ReconcileStats {
+    pub created: u64,
+    pub edited: u64,
+    pub skipped: u64,
+    pub deleted: u64,
+}
+
+impl ReconcileStats {
+    /// One line such as "2 created · 1 updated · 30 unchanged · 0 removed"
+    pub fn summary(&self, lang: Language) -> String {
+        tf(
+            lang,
+            "display.update_counts",
+            &[
+                ("created", &self.created),
+                ("edited", &self.edited),
+                ("skipped", &self.skipped),
+                ("deleted", &self.deleted),
+            ],
+        )
+    }
+}
+
+/// Running totals since startup
+#[derive(Debug, Default)]
+pub struct ReconcileMetrics {
+    created: AtomicU64,
+    edited: AtomicU64,
+    skipped: AtomicU64,
+    deleted: AtomicU64,
+    coalesced: AtomicU64,
+}
+
+impl ReconcileMetrics {
+    pub const fn new() -> Self {
+        Self {
+            created: AtomicU64::new(0),
+            edited: AtomicU64::new(0),
+            skipped: AtomicU64::new(0),
+            deleted: AtomicU64::new(0),
+            coalesced: AtomicU64::new(0),
+        }
+    }
+
+    pub fn record(&self, stats: &ReconcileStats) {
+        self.created.fetch_add(stats.created, Ordering::Relaxed);
+        self.edited.fetch_add(stats.edited, Ordering::Relaxed);
+        self.skipped.fetch_add(stats.skipped, Ordering::Relaxed);
+        self.deleted.fetch_add(stats.deleted, Ordering::Relaxed);
+    }
+
+    pub fn record_coalesced(&self) {
+        self.coalesced.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn totals(&self) -> ReconcileStats {
+        ReconcileStats {
+            created: self.created.load(Ordering::Relaxed),
+            edited: self.edited.load(Ordering::Relaxed),
+            skipped: self.skipped.load(Ordering::Relaxed),
+            deleted: self.deleted.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn coalesced(&self) -> u64 {
+        self.coalesced.load(Ordering::Relaxed)
+    }
+}
+
+pub static METRICS: ReconcileMetrics = ReconcileMetrics::new();
+
+lazy_static::lazy_static! {
+    static ref CHANNEL_BUCKETS: Mutex<HashMap<i64, TokenBucket>> = Mutex::new(HashMap::new());
+    static ref REFRESH_QUEUE: RefreshQueue = RefreshQueue::new();
+    static ref GATEWAY_CONTEXT: Mutex<Option<Context>> = Mutex::new(None);
+}
+
+/// Wait until the channel may take another message create, edit or delete
+pub async fn acquire_channel_slot(channel_id: i64) {
+    loop {
+        let wait = {
+            let now = Instant::now();
+            let mut buckets = CHANNEL_BUCKETS.lock().unwrap();
+            buckets
+                .entry(channel_id)
+                .or_insert_with(|| {
+                    TokenBucket::new(
+                        Constants::CHANNEL_EDIT_BURST,
+                        Constants::CHANNEL_EDITS_PER_SECOND,
+                        now,
+                    )
+                })
+                .try_take(now)
+        };
+        match wait {
+            Ok(()) => return,
+            Err(wait) => tokio::time::sleep(wait).await,
+        }
+    }
+}
+
+/// Ask for the channel's equipment display to be reconciled. Requests within the
+/// debounce window share one run.
+pub fn request_reconcile(ctx: &Context, db: &SqlitePool, guild_id: i64, channel_id: i64) {
+    schedule(ctx, db, guild_id, channel_id, false);
+}
+
+/// Remember the gateway context so background jobs can refresh displays too
+pub fn set_gateway_context(ctx: &Context) {
+    *GATEWAY_CONTEXT.lock().unwrap() = Some(ctx.clone());
+}
+
+/// Like `request_reconcile`, for code running outside an interaction such as the
+/// job worker. Does nothing until the bot has connected.
+pub fn request_background_reconcile(db: &SqlitePool, guild_id: i64, channel_id: i64) {
+    let ctx = GATEWAY_CONTEXT.lock().unwrap().clone();
+    match ctx {
+        Some(ctx) => schedule(&ctx, db, guild_id, channel_id, false),
+        None => info!(
+            "Not connected yet, skipping display refresh for channel {}",
+            channel_id
+        ),
+    }
+}
+
+/// Ask for the channel to be checked against its history and rebuilt if managed
+/// messages are gone, e.g. after someone deleted one
+pub fn request_repair(ctx: &Context, db: &SqlitePool, guild_id: i64, channel_id: i64) {
+    schedule(ctx, db, guild_id, channel_id, true);
+}
+
+fn schedule(ctx: &Context, db: &SqlitePool, guild_id: i64, channel_id: i64, repair: bool) {
+    if !REFRESH_QUEUE.request(channel_id, repair) {
+        METRICS.record_coalesced();
+        return;
+    }
+
+    let ctx = ctx.clone();
+    let renderer = EquipmentRenderer::new(db.clone());
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(Duration::from_millis(Constants::RECONCILE_DEBOUNCE_MS)).await;
+            let result = if REFRESH_QUEUE.start(channel_id) {
+                renderer.repair_channel(&ctx, guild_id, channel_id).await
+            } else {
+                renderer
+                    .reconcile_equipment_display(&ctx, guild_id, channel_id)
+                    .await
+                    .map(|_| ())
+            };
+            if let Err(e) = result {
+                error!(
+                    "Failed to reconcile equipment display in channel {}: {}",
+                    channel_id, e
+                );
+            }
+            if !REFRESH_QUEUE.finish(channel_id) {
+                break;
+            }
+        }
+    });
+}
+
+/// Guild of the channel when any of the deleted messages was managed there
+pub async fn find_managed_channel(
+    db: &SqlitePool,
+    channel_id: i64,
+    message_ids: &[i64],
+) -> Result<Option<i64>> {
+    for message_id in message_ids {
+        let guild_id = sqlx::query_scalar!(
+            "SELECT guild_id FROM managed_messages WHERE channel_id = ? AND message_id = ?",
+            channel_id,
+            message_id
+        )
+        .fetch_optional(db)
+        .await?;
+        if guild_id.is_some() {
+            return Ok(guild_id);
+        }
+    }
+    Ok(None)
+}
+
+/// Log the running totals
+pub fn log_totals() {
+    let totals = METRICS.totals();
+    info!(
+        "Display totals since start: {} created, {} edited, {} unchanged, {} deleted, {} requests coalesced",
+        totals.created,
+        totals.edited,
+        totals.skipped,
+        totals.deleted,
+        METRICS.coalesced()
+    );
+}
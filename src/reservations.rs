@@ -0,0 +1,68 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+/// One of a member's reservations, as listed by /my-reservations and "View loans"
+#[derive(Debug, Clone)]
+pub struct UserReservation {
+    pub reservation_id: i64,
+    pub equipment_id: i64,
+    pub equipment_name: String,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub location: Option<String>,
+}
+
+impl UserReservation {
+    /// Started and not returned yet; overdue loans count as active
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.start_time <= now
+    }
+}
+
+/// Reservation lookups for a single member
+pub struct ReservationService {
+    db: SqlitePool,
+}
+
+impl ReservationService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// A member's confirmed reservations that are not returned yet, active ones first
+    pub async fn open_reservations_for_user(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+        limit: i64,
+    ) -> Result<Vec<UserReservation>> {
+        let rows = sqlx::query!(
+            "SELECT r.id as \"id!\", r.equipment_id, e.name as equipment_name,
+                    r.start_time, r.end_time, r.location
+             FROM reservations r
+             JOIN equipment e ON r.equipment_id = e.id
+             WHERE e.guild_id = ? AND r.user_id = ? AND r.status = 'Confirmed'
+               AND r.returned_at IS NULL
+             ORDER BY r.start_time ASC
+             LIMIT ?",
+            guild_id,
+            user_id,
+            limit
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| UserReservation {
+                reservation_id: row.id,
+                equipment_id: row.equipment_id,
+                equipment_name: row.equipment_name,
+                start_time: crate::time::naive_to_utc(row.start_time),
+                end_time: crate::time::naive_to_utc(row.end_time),
+                location: row.location,
+            })
+            .collect())
+    }
+}
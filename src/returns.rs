@@ -0,0 +1,620 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use tracing::{error, info};
+
+use crate::constants::Constants;
+use crate::i18n::{t, Language};
+use crate::jobs::JobWorker;
+use crate::penalties::PenaltyService;
+
+/// Latest time a return can be corrected: RETURN_CORRECTION_WINDOW_HOURS after the return,
+/// but no later than NEXT_RESERVATION_BUFFER_MINUTES before the next reservation starts
+pub fn return_correction_deadline(
+    returned_at: DateTime<Utc>,
+    next_start: Option<DateTime<Utc>>,
+) -> DateTime<Utc> {
+    let window_end = returned_at + Duration::hours(Constants::RETURN_CORRECTION_WINDOW_HOURS);
+    match next_start {
+        Some(start) => {
+            window_end.min(start - Duration::minutes(Constants::NEXT_RESERVATION_BUFFER_MINUTES))
+        }
+        None => window_end,
+    }
+}
+
+/// Whether a return location matches the equipment's default (ignoring case and surrounding spaces).
+/// Equipment without a default accepts any location.
+pub fn is_default_return_location(return_location: &str, default_location: Option<&str>) -> bool {
+    match default_location {
+        Some(default) => default.trim().eq_ignore_ascii_case(return_location.trim()),
+        None => true,
+    }
+}
+
+/// Return to a restricted location that still needs an admin's acknowledgment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingReturnAck {
+    pub reservation_id: i64,
+    pub user_id: i64,
+    pub return_location: Option<String>,
+    pub returned_at: DateTime<Utc>,
+}
+
+/// Whether a member may correct a return right now
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReturnCorrectionCheck {
+    /// Correction is allowed until the deadline
+    Allowed { deadline: DateTime<Utc> },
+    /// The reservation does not exist or has not been returned
+    NotReturned,
+    /// Only the member who returned the equipment may correct it
+    NotReturner,
+    /// The correction window has closed
+    Expired,
+}
+
+impl ReturnCorrectionCheck {
+    /// Message shown when the correction is refused
+    pub fn denial_message(&self, lang: Language) -> Option<&'static str> {
+        match self {
+            Self::Allowed { .. } => None,
+            Self::NotReturned => Some(t(lang, "return_correction.not_returned")),
+            Self::NotReturner => Some(t(lang, "return_correction.not_returner")),
+            Self::Expired => Some(t(lang, "error.operation_not_possible")),
+        }
+    }
+}
+
+/// Returned reservation as needed for corrections
+struct ReturnedReservation {
+    equipment_id: i64,
+    guild_id: i64,
+    owner_id: i64,
+    returned_by_user_id: i64,
+    start_time: DateTime<Utc>,
+    end_time: DateTime<Utc>,
+    location: Option<String>,
+    returned_at: DateTime<Utc>,
+    return_location: Option<String>,
+}
+
+/// Started, unreturned reservation that staff can check in for the borrower
+#[derive(Debug, Clone)]
+pub struct ActiveLoan {
+    pub reservation_id: i64,
+    pub equipment_id: i64,
+    pub equipment_name: String,
+    pub user_id: i64,
+    pub start_time: DateTime<Utc>,
+    pub end_time: DateTime<Utc>,
+    pub default_return_location: Option<String>,
+}
+
+impl ActiveLoan {
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.end_time < now
+    }
+}
+
+/// Return corrections within the correction window
+pub struct ReturnService {
+    db: SqlitePool,
+}
+
+impl ReturnService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    async fn get_returned_reservation(
+        &self,
+        reservation_id: i64,
+    ) -> Result<Option<ReturnedReservation>> {
+        let row = sqlx::query!(
+            "SELECT r.equipment_id, r.user_id, r.start_time, r.end_time, r.location,
+                    r.returned_at, r.return_location, r.returned_by_user_id, e.guild_id
+             FROM reservations r
+             JOIN equipment e ON r.equipment_id = e.id
+             WHERE r.id = ? AND r.status = 'Confirmed'",
+            reservation_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(row.and_then(|row| {
+            let returned_at = row.returned_at?;
+            Some(ReturnedReservation {
+                equipment_id: row.equipment_id,
+                guild_id: row.guild_id,
+                owner_id: row.user_id,
+                // Returns recorded before returned_by_user_id existed were made by the owner
+                returned_by_user_id: row.returned_by_user_id.unwrap_or(row.user_id),
+                start_time: crate::time::naive_to_utc(row.start_time),
+                end_time: crate::time::naive_to_utc(row.end_time),
+                location: row.location,
+                returned_at: crate::time::naive_to_utc(returned_at),
+                return_location: row.return_location,
+            })
+        }))
+    }
+
+    /// Start of the next confirmed reservation of the equipment after this one
+    async fn next_reservation_start(
+        &self,
+        reservation_id: i64,
+        equipment_id: i64,
+        start_time: DateTime<Utc>,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let next_start = sqlx::query_scalar!(
+            "SELECT start_time FROM reservations
+             WHERE equipment_id = ? AND id != ? AND status = 'Confirmed' AND start_time >= ?
+             ORDER BY start_time ASC
+             LIMIT 1",
+            equipment_id,
+            reservation_id,
+            start_time
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(next_start.map(crate::time::naive_to_utc))
+    }
+
+    async fn check(
+        &self,
+        reservation_id: i64,
+        user_id: i64,
+    ) -> Result<(ReturnCorrectionCheck, Option<ReturnedReservation>)> {
+        let Some(reservation) = self.get_returned_reservation(reservation_id).await? else {
+            return Ok((ReturnCorrectionCheck::NotReturned, None));
+        };
+
+        if reservation.returned_by_user_id != user_id {
+            return Ok((ReturnCorrectionCheck::NotReturner, Some(reservation)));
+        }
+
+        let next_start = self
+            .next_reservation_start(
+                reservation_id,
+                reservation.equipment_id,
+                reservation.start_time,
+            )
+            .await?;
+        let deadline = return_correction_deadline(reservation.returned_at, next_start);
+
+        let check = if Utc::now() < deadline {
+            ReturnCorrectionCheck::Allowed { deadline }
+        } else {
+            ReturnCorrectionCheck::Expired
+        };
+        Ok((check, Some(reservation)))
+    }
+
+    /// Check whether a member may correct the return of a reservation
+    pub async fn check_correction(
+        &self,
+        reservation_id: i64,
+        user_id: i64,
+    ) -> Result<ReturnCorrectionCheck> {
+        Ok(self.check(reservation_id, user_id).await?.0)
+    }
+
+    /// Undo a return: the reservation is open again, the equipment goes back on loan and
+    /// overdue reminders are rescheduled. Late-return points for the reservation are removed.
+    pub async fn cancel_return(
+        &self,
+        reservation_id: i64,
+        user_id: i64,
+    ) -> Result<ReturnCorrectionCheck> {
+        let (check, reservation) = self.check(reservation_id, user_id).await?;
+        let (ReturnCorrectionCheck::Allowed { .. }, Some(reservation)) = (&check, reservation)
+        else {
+            return Ok(check);
+        };
+
+        let now = Utc::now();
+        let mut tx = self.db.begin().await?;
+
+        let result = sqlx::query!(
+            "UPDATE reservations
+             SET returned_at = NULL, return_location = NULL, returned_by_user_id = NULL,
+                 return_ack_required = FALSE, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND returned_at IS NOT NULL",
+            reservation_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Ok(ReturnCorrectionCheck::NotReturned);
+        }
+
+        let previous_status = sqlx::query_scalar!(
+            "SELECT status FROM equipment WHERE id = ?",
+            reservation.equipment_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        // Only put the equipment back on loan if nobody changed its state since the return
+        let new_status = if previous_status == Constants::EQUIPMENT_AVAILABLE {
+            Constants::EQUIPMENT_LOANED.to_string()
+        } else {
+            previous_status.clone()
+        };
+
+        sqlx::query!(
+            "UPDATE equipment SET status = ?, current_location = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?",
+            new_status,
+            reservation.location,
+            reservation.equipment_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let log_notes = format!(
+            "Return of reservation {} cancelled (was returned to {})",
+            reservation_id,
+            reservation.return_location.as_deref().unwrap_or("unknown")
+        );
+        sqlx::query!(
+            "INSERT INTO equipment_logs (equipment_id, user_id, action, location, previous_status, new_status, notes, timestamp)
+             VALUES (?, ?, 'ReturnCancelled', ?, ?, ?, ?, ?)",
+            reservation.equipment_id,
+            user_id,
+            reservation.location,
+            previous_status,
+            new_status,
+            log_notes,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Return of reservation {} cancelled by user {}",
+            reservation_id, user_id
+        );
+
+        if let Err(e) = PenaltyService::new(self.db.clone())
+            .revert_late_return(reservation.guild_id, reservation.owner_id, reservation_id)
+            .await
+        {
+            error!(
+                "Failed to revert late-return points for reservation {}: {}",
+                reservation_id, e
+            );
+        }
+
+        // Replace any leftover reminders with a fresh schedule for the reopened reservation
+        JobWorker::cancel_reservation_reminders(&self.db, reservation_id).await?;
+        JobWorker::schedule_reservation_reminders(
+            &self.db,
+            reservation_id,
+            reservation.start_time,
+            reservation.end_time,
+            reservation.guild_id,
+        )
+        .await?;
+        JobWorker::schedule_overdue_reminders(
+            &self.db,
+            reservation_id,
+            reservation.end_time,
+            reservation.guild_id,
+        )
+        .await?;
+
+        Ok(check)
+    }
+
+    /// Fix the return location of a returned reservation
+    pub async fn correct_return_location(
+        &self,
+        reservation_id: i64,
+        user_id: i64,
+        return_location: &str,
+    ) -> Result<ReturnCorrectionCheck> {
+        let (check, reservation) = self.check(reservation_id, user_id).await?;
+        let (ReturnCorrectionCheck::Allowed { .. }, Some(reservation)) = (&check, reservation)
+        else {
+            return Ok(check);
+        };
+
+        let ack_required = self
+            .is_restricted_location(reservation.guild_id, return_location)
+            .await?;
+        let now = Utc::now();
+        let mut tx = self.db.begin().await?;
+
+        // Moving the return to or from a restricted location changes whether it needs acknowledgment
+        sqlx::query!(
+            "UPDATE reservations SET return_location = ?,
+                 return_ack_required = CASE WHEN return_acknowledged_at IS NULL THEN ? ELSE return_ack_required END,
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE id = ?",
+            return_location,
+            ack_required,
+            reservation_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // The equipment stays where it was returned unless it has moved on since
+        sqlx::query!(
+            "UPDATE equipment SET current_location = ?, updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND status = 'Available'",
+            return_location,
+            reservation.equipment_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let log_notes = format!(
+            "Return location of reservation {} corrected from {}",
+            reservation_id,
+            reservation.return_location.as_deref().unwrap_or("unknown")
+        );
+        sqlx::query!(
+            "INSERT INTO equipment_logs (equipment_id, user_id, action, location, previous_status, new_status, notes, timestamp)
+             VALUES (?, ?, 'ReturnCorrected', ?, NULL, NULL, ?, ?)",
+            reservation.equipment_id,
+            user_id,
+            return_location,
+            log_notes,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Return location of reservation {} corrected to {} by user {}",
+            reservation_id, return_location, user_id
+        );
+        Ok(check)
+    }
+
+    /// Whether returns to this location need an admin's acknowledgment
+    pub async fn is_restricted_location(&self, guild_id: i64, location: &str) -> Result<bool> {
+        let location = location.trim();
+        let count = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM locations
+             WHERE guild_id = ? AND is_restricted = TRUE AND name = ? COLLATE NOCASE",
+            guild_id,
+            location
+        )
+        .fetch_one(&self.db)
+        .await?;
+
+        Ok(count > 0)
+    }
+
+    /// Replace the set of restricted locations for a guild
+    pub async fn set_restricted_locations(
+        &self,
+        guild_id: i64,
+        location_ids: &[i64],
+    ) -> Result<()> {
+        let mut tx = self.db.begin().await?;
+
+        sqlx::query!(
+            "UPDATE locations SET is_restricted = FALSE WHERE guild_id = ?",
+            guild_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for location_id in location_ids {
+            sqlx::query!(
+                "UPDATE locations SET is_restricted = TRUE WHERE id = ? AND guild_id = ?",
+                location_id,
+                guild_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        info!(
+            "Restricted return locations for guild {} set to {:?}",
+            guild_id, location_ids
+        );
+        Ok(())
+    }
+
+    /// Latest return of an equipment that is waiting for an admin's acknowledgment
+    pub async fn pending_acknowledgment(
+        &self,
+        equipment_id: i64,
+    ) -> Result<Option<PendingReturnAck>> {
+        let row = sqlx::query!(
+            "SELECT id as \"id!\", user_id, return_location, returned_at as \"returned_at!\"
+             FROM reservations
+             WHERE equipment_id = ? AND return_ack_required = TRUE
+               AND return_acknowledged_at IS NULL AND returned_at IS NOT NULL
+             ORDER BY returned_at DESC
+             LIMIT 1",
+            equipment_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(row.map(|row| PendingReturnAck {
+            reservation_id: row.id,
+            user_id: row.user_id,
+            return_location: row.return_location,
+            returned_at: crate::time::naive_to_utc(row.returned_at),
+        }))
+    }
+
+    /// Acknowledge a return to a restricted location; returns false if nothing was pending
+    pub async fn acknowledge_return(
+        &self,
+        reservation_id: i64,
+        admin_user_id: i64,
+    ) -> Result<bool> {
+        let now = Utc::now();
+        let mut tx = self.db.begin().await?;
+
+        let result = sqlx::query!(
+            "UPDATE reservations
+             SET return_acknowledged_by_user_id = ?, return_acknowledged_at = ?,
+                 updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND return_ack_required = TRUE
+               AND return_acknowledged_at IS NULL AND returned_at IS NOT NULL",
+            admin_user_id,
+            now,
+            reservation_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            tx.rollback().await?;
+            return Ok(false);
+        }
+
+        let reservation = sqlx::query!(
+            "SELECT equipment_id, user_id, return_location FROM reservations WHERE id = ?",
+            reservation_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let log_notes = format!(
+            "Return of reservation {} by <@{}> to a restricted location acknowledged",
+            reservation_id, reservation.user_id
+        );
+        sqlx::query!(
+            "INSERT INTO equipment_logs (equipment_id, user_id, action, location, previous_status, new_status, notes, timestamp)
+             VALUES (?, ?, 'ReturnAcknowledged', ?, NULL, NULL, ?, ?)",
+            reservation.equipment_id,
+            admin_user_id,
+            reservation.return_location,
+            log_notes,
+            now
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        info!(
+            "Return of reservation {} acknowledged by {}",
+            reservation_id, admin_user_id
+        );
+        Ok(true)
+    }
+
+    /// Loans in a guild that have started but are not returned yet, overdue ones first
+    pub async fn active_loans(
+        &self,
+        guild_id: i64,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<ActiveLoan>> {
+        let rows = sqlx::query!(
+            "SELECT r.id as \"id!\", r.equipment_id, e.name as equipment_name, r.user_id,
+                    r.start_time, r.end_time, e.default_return_location
+             FROM reservations r
+             JOIN equipment e ON r.equipment_id = e.id
+             WHERE e.guild_id = ? AND r.status = 'Confirmed' AND r.returned_at IS NULL
+               AND r.start_time <= ?
+             ORDER BY r.end_time ASC
+             LIMIT ?",
+            guild_id,
+            now,
+            limit
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ActiveLoan {
+                reservation_id: row.id,
+                equipment_id: row.equipment_id,
+                equipment_name: row.equipment_name,
+                user_id: row.user_id,
+                start_time: crate::time::naive_to_utc(row.start_time),
+                end_time: crate::time::naive_to_utc(row.end_time),
+                default_return_location: row.default_return_location,
+            })
+            .collect())
+    }
+
+    /// The loan of an equipment that has started but is not returned yet
+    pub async fn active_loan(
+        &self,
+        equipment_id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<Option<ActiveLoan>> {
+        let row = sqlx::query!(
+            "SELECT r.id as \"id!\", r.equipment_id, e.name as equipment_name, r.user_id,
+                    r.start_time, r.end_time, e.default_return_location
+             FROM reservations r
+             JOIN equipment e ON r.equipment_id = e.id
+             WHERE r.equipment_id = ? AND r.status = 'Confirmed' AND r.returned_at IS NULL
+               AND r.start_time <= ?
+             ORDER BY r.start_time ASC
+             LIMIT 1",
+            equipment_id,
+            now
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(row.map(|row| ActiveLoan {
+            reservation_id: row.id,
+            equipment_id: row.equipment_id,
+            equipment_name: row.equipment_name,
+            user_id: row.user_id,
+            start_time: crate::time::naive_to_utc(row.start_time),
+            end_time: crate::time::naive_to_utc(row.end_time),
+            default_return_location: row.default_return_location,
+        }))
+    }
+
+    /// A member's own started, unreturned loans, oldest first, for /return
+    pub async fn user_active_loans(
+        &self,
+        guild_id: i64,
+        user_id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<ActiveLoan>> {
+        let rows = sqlx::query!(
+            "SELECT r.id as \"id!\", r.equipment_id, e.name as equipment_name, r.user_id,
+                    r.start_time, r.end_time, e.default_return_location
+             FROM reservations r
+             JOIN equipment e ON r.equipment_id = e.id
+             WHERE e.guild_id = ? AND r.user_id = ? AND r.status = 'Confirmed'
+               AND r.returned_at IS NULL AND r.start_time <= ?
+             ORDER BY r.start_time ASC",
+            guild_id,
+            user_id,
+            now
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| ActiveLoan {
+                reservation_id: row.id,
+                equipment_id: row.equipment_id,
+                equipment_name: row.equipment_name,
+                user_id: row.user_id,
+                start_time: crate::time::naive_to_utc(row.start_time),
+                end_time: crate::time::naive_to_utc(row.end_time),
+                default_return_location: row.default_return_location,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,301 @@
+use anyhow::Result;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+use sqlx::SqlitePool;
+
+use crate::buffers::{conflicts_with_buffer, BufferService, TurnaroundBuffer};
+use crate::constants::Constants;
+use crate::i18n::{t, tf, Language};
+use crate::operating_hours::{OperatingHoursService, OperatingSchedule};
+
+/// Reservation lengths offered once a start slot is picked, in minutes
+pub const DURATION_CHOICES: [i64; 15] = [
+    30, 60, 90, 120, 180, 240, 360, 480, 720, 1440, 2880, 4320, 7200, 10080, 20160,
+];
+
+/// Human readable reservation length, e.g. "1 h 30 min" or "2 days"
+pub fn describe_duration(minutes: i64, lang: Language) -> String {
+    let days = minutes / 1440;
+    let hours = (minutes % 1440) / 60;
+    let mins = minutes % 60;
+    if days > 0 && hours == 0 && mins == 0 {
+        return if days == 1 {
+            t(lang, "duration.one_day").to_string()
+        } else {
+            tf(lang, "duration.days", &[("days", &days)])
+        };
+    }
+    match (minutes / 60, mins) {
+        (0, m) => tf(lang, "duration.minutes", &[("minutes", &m)]),
+        (h, 0) => tf(lang, "duration.hours", &[("hours", &h)]),
+        (h, m) => tf(
+            lang,
+            "duration.hours_minutes",
+            &[("hours", &h), ("minutes", &m)],
+        ),
+    }
+}
+
+/// Date option label for the wizard, e.g. "2025/01/15 (Wed)"
+pub fn describe_date(date: NaiveDate, lang: Language) -> String {
+    let key = match date.weekday() {
+        Weekday::Mon => "weekday_short.mon",
+        Weekday::Tue => "weekday_short.tue",
+        Weekday::Wed => "weekday_short.wed",
+        Weekday::Thu => "weekday_short.thu",
+        Weekday::Fri => "weekday_short.fri",
+        Weekday::Sat => "weekday_short.sat",
+        Weekday::Sun => "weekday_short.sun",
+    };
+    format!("{} ({})", date.format("%Y/%m/%d"), t(lang, key))
+}
+
+/// Candidate dates, start slots and durations for the reservation wizard,
+/// leaving out anything that is closed or already taken
+#[derive(Debug, Clone)]
+pub struct SlotPlanner {
+    pub schedule: OperatingSchedule,
+    pub buffer: TurnaroundBuffer,
+    /// (start, end) of the equipment's confirmed reservations
+    pub reservations: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+    pub now: DateTime<Utc>,
+}
+
+impl SlotPlanner {
+    pub fn new(
+        schedule: OperatingSchedule,
+        buffer: TurnaroundBuffer,
+        reservations: Vec<(DateTime<Utc>, DateTime<Utc>)>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            schedule,
+            buffer,
+            reservations,
+            now,
+        }
+    }
+
+    /// Reservations may not end further ahead than this
+    pub fn latest_end(&self) -> DateTime<Utc> {
+        self.now + Duration::days(Constants::MAX_RESERVATION_DAYS_AHEAD)
+    }
+
+    fn is_free(&self, start_time: DateTime<Utc>, end_time: DateTime<Utc>) -> bool {
+        !self
+            .reservations
+            .iter()
+            .any(|(existing_start, existing_end)| {
+                conflicts_with_buffer(
+                    &self.buffer,
+                    *existing_start,
+                    *existing_end,
+                    start_time,
+                    end_time,
+                )
+            })
+    }
+
+    /// Durations from `DURATION_CHOICES` that end within opening hours without a conflict
+    pub fn durations(&self, start_time: DateTime<Utc>) -> Vec<i64> {
+        DURATION_CHOICES
+            .iter()
+            .copied()
+            .filter(|minutes| {
+                let end_time = start_time + Duration::minutes(*minutes);
+                end_time <= self.latest_end()
+                    && self.schedule.check_time(end_time, true).is_ok()
+                    && self.is_free(start_time, end_time)
+            })
+            .collect()
+    }
+
+    /// Every slot boundary on a local date. Times skipped by a daylight saving time change
+    /// are left out.
+    fn slot_grid(&self, date: NaiveDate) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        (0..24 * 60)
+            .step_by(Constants::RESERVATION_SLOT_MINUTES as usize)
+            .filter_map(move |minute: i64| {
+                let local = date.and_hms_opt((minute / 60) as u32, (minute % 60) as u32, 0)?;
+                crate::time::local_to_utc(local, self.schedule.time_zone).ok()
+            })
+    }
+
+    /// Future start slots on a local date that leave room for at least one duration
+    pub fn start_slots(&self, date: NaiveDate) -> Vec<DateTime<Utc>> {
+        self.slot_grid(date)
+            .filter(|start_time| {
+                *start_time >= self.now
+                    && self.schedule.check_time(*start_time, false).is_ok()
+                    && !self.durations(*start_time).is_empty()
+            })
+            .collect()
+    }
+
+    /// Local dates from today with at least one free start slot, up to `limit`
+    pub fn dates(&self, limit: usize) -> Vec<NaiveDate> {
+        let tz = self.schedule.time_zone;
+        let today = crate::time::local_date(self.now, tz);
+        let last = crate::time::local_date(self.latest_end(), tz);
+        today
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .filter(|date| !self.start_slots(*date).is_empty())
+            .take(limit)
+            .collect()
+    }
+
+    /// Stretches between `from` and `until` in which a reservation of `length` fits,
+    /// earliest first. Every slot in a window can start such a reservation, or lies between
+    /// two that can, and each reservation starts and ends within opening hours.
+    pub fn free_windows(
+        &self,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        length: Duration,
+    ) -> Vec<FreeWindow> {
+        let tz = self.schedule.time_zone;
+        let from = from.max(self.now);
+        let until = until.min(self.latest_end());
+        let mut windows: Vec<FreeWindow> = Vec::new();
+        if from >= until {
+            return windows;
+        }
+
+        let first = crate::time::local_date(from, tz);
+        let last = crate::time::local_date(until, tz);
+        for date in first.iter_days().take_while(|date| *date <= last) {
+            for start_time in self.slot_grid(date) {
+                let end_time = start_time + length;
+                let fits = start_time >= from
+                    && end_time <= until
+                    && self
+                        .schedule
+                        .check_reservation(start_time, end_time)
+                        .is_ok()
+                    && self.is_free(start_time, end_time);
+                if !fits {
+                    continue;
+                }
+                match windows.last_mut() {
+                    Some(window) if start_time <= window.end => window.end = end_time,
+                    _ => windows.push(FreeWindow {
+                        start: start_time,
+                        end: end_time,
+                    }),
+                }
+            }
+        }
+        windows
+    }
+}
+
+/// Time in which an equipment is free for a reservation of the requested length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreeWindow {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+impl FreeWindow {
+    pub fn minutes(&self) -> i64 {
+        (self.end - self.start).num_minutes()
+    }
+}
+
+/// A free window of one equipment, as listed by /availability
+#[derive(Debug, Clone)]
+pub struct AvailableSlot {
+    pub equipment_id: i64,
+    pub equipment_name: String,
+    pub window: FreeWindow,
+}
+
+/// Loads what the slot pickers need for one equipment
+pub struct SlotService {
+    db: SqlitePool,
+}
+
+impl SlotService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// Planner for the equipment with its schedule, prep time and upcoming reservations
+    pub async fn planner(
+        &self,
+        guild_id: i64,
+        equipment_id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<SlotPlanner> {
+        let buffer = BufferService::new(self.db.clone())
+            .buffer_for_equipment(equipment_id)
+            .await?;
+        let until = now + Duration::days(Constants::MAX_RESERVATION_DAYS_AHEAD);
+        let tz = crate::time::guild_time_zone(&self.db, guild_id).await?;
+        let schedule = OperatingHoursService::new(self.db.clone())
+            .schedule_between(
+                guild_id,
+                crate::time::local_date(now, tz),
+                crate::time::local_date(until, tz),
+            )
+            .await?;
+
+        let (window_start, window_end) = buffer.conflict_window(now, until);
+        let reservations = sqlx::query!(
+            "SELECT start_time, end_time FROM reservations
+             WHERE equipment_id = ? AND status = 'Confirmed'
+             AND start_time < ? AND end_time > ?
+             ORDER BY start_time",
+            equipment_id,
+            window_end,
+            window_start
+        )
+        .fetch_all(&self.db)
+        .await?
+        .into_iter()
+        .map(|row| {
+            (
+                crate::time::naive_to_utc(row.start_time),
+                crate::time::naive_to_utc(row.end_time),
+            )
+        })
+        .collect();
+
+        Ok(SlotPlanner::new(schedule, buffer, reservations, now))
+    }
+
+    /// Free windows across `equipment` (id, name) for a reservation of `length` between
+    /// `from` and `until`, earliest first
+    pub async fn find_available_slots(
+        &self,
+        guild_id: i64,
+        equipment: &[(i64, String)],
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+        length: Duration,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<AvailableSlot>> {
+        let mut slots = Vec::new();
+        for (equipment_id, equipment_name) in equipment {
+            let planner = self.planner(guild_id, *equipment_id, now).await?;
+            slots.extend(
+                planner
+                    .free_windows(from, until, length)
+                    .into_iter()
+                    .map(|window| AvailableSlot {
+                        equipment_id: *equipment_id,
+                        equipment_name: equipment_name.clone(),
+                        window,
+                    }),
+            );
+        }
+
+        slots.sort_by(|a, b| {
+            a.window
+                .start
+                .cmp(&b.window.start)
+                .then_with(|| a.equipment_name.cmp(&b.equipment_name))
+        });
+        Ok(slots)
+    }
+}
@@ -0,0 +1,206 @@
+use anyhow::Result;
+use sqlx::SqlitePool;
+use tracing::info;
+
+use crate::i18n::{tf, Language};
+
+/// Parse a JSON array of role IDs as stored in the database.
+/// Accepts both string and numeric elements; invalid JSON yields an empty list.
+pub fn parse_role_ids(json: Option<&str>) -> Vec<i64> {
+    let Some(json) = json else {
+        return Vec::new();
+    };
+
+    serde_json::from_str::<Vec<serde_json::Value>>(json)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|value| match value {
+            serde_json::Value::String(s) => s.parse::<i64>().ok(),
+            serde_json::Value::Number(n) => n.as_i64(),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Serialize role IDs the same way admin_roles is stored (array of strings)
+pub fn role_ids_to_json(role_ids: &[i64]) -> Result<String> {
+    let role_ids: Vec<String> = role_ids.iter().map(|id| id.to_string()).collect();
+    Ok(serde_json::to_string(&role_ids)?)
+}
+
+/// Whether a member with `member_roles` satisfies `allowed_roles`.
+/// An empty allow list means the tag is unrestricted.
+pub fn has_any_role(allowed_roles: &[i64], member_roles: &[i64]) -> bool {
+    allowed_roles.is_empty() || allowed_roles.iter().any(|role| member_roles.contains(role))
+}
+
+/// Role restriction on a tag that a member does not satisfy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagRestriction {
+    pub tag_id: i64,
+    pub tag_name: String,
+    pub allowed_roles: Vec<i64>,
+}
+
+impl TagRestriction {
+    /// Explanation shown to a member who is not eligible
+    pub fn message(&self, lang: Language) -> String {
+        let roles = self
+            .allowed_roles
+            .iter()
+            .map(|role| format!("<@&{}>", role))
+            .collect::<Vec<_>>()
+            .join(", ");
+        tf(
+            lang,
+            "tag_access.restricted",
+            &[("tag", &self.tag_name), ("roles", &roles)],
+        )
+    }
+}
+
+/// Per-tag access rules
+pub struct TagAccessService {
+    db: SqlitePool,
+}
+
+impl TagAccessService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// Get the roles allowed to reserve equipment under a tag (empty = unrestricted)
+    pub async fn get_allowed_roles(&self, tag_id: i64) -> Result<Vec<i64>> {
+        let allowed_roles =
+            sqlx::query_scalar!("SELECT allowed_roles FROM tags WHERE id = ?", tag_id)
+                .fetch_optional(&self.db)
+                .await?
+                .flatten();
+
+        Ok(parse_role_ids(allowed_roles.as_deref()))
+    }
+
+    /// Replace the roles allowed to reserve equipment under a tag; an empty list lifts the restriction
+    pub async fn set_allowed_roles(
+        &self,
+        guild_id: i64,
+        tag_id: i64,
+        role_ids: &[i64],
+    ) -> Result<()> {
+        let allowed_roles = if role_ids.is_empty() {
+            None
+        } else {
+            Some(role_ids_to_json(role_ids)?)
+        };
+
+        sqlx::query!(
+            "UPDATE tags SET allowed_roles = ? WHERE id = ? AND guild_id = ?",
+            allowed_roles,
+            tag_id,
+            guild_id
+        )
+        .execute(&self.db)
+        .await?;
+
+        info!(
+            "Allowed roles for tag {} in guild {} set to {:?}",
+            tag_id, guild_id, role_ids
+        );
+        Ok(())
+    }
+
+    /// Get the roles that manage equipment under a tag
+    pub async fn get_manager_roles(&self, tag_id: i64) -> Result<Vec<i64>> {
+        let manager_roles =
+            sqlx::query_scalar!("SELECT manager_roles FROM tags WHERE id = ?", tag_id)
+                .fetch_optional(&self.db)
+                .await?
+                .flatten();
+
+        Ok(parse_role_ids(manager_roles.as_deref()))
+    }
+
+    /// Replace the manager roles of a tag; an empty list removes all delegated managers
+    pub async fn set_manager_roles(
+        &self,
+        guild_id: i64,
+        tag_id: i64,
+        role_ids: &[i64],
+    ) -> Result<()> {
+        let manager_roles = if role_ids.is_empty() {
+            None
+        } else {
+            Some(role_ids_to_json(role_ids)?)
+        };
+
+        sqlx::query!(
+            "UPDATE tags SET manager_roles = ? WHERE id = ? AND guild_id = ?",
+            manager_roles,
+            tag_id,
+            guild_id
+        )
+        .execute(&self.db)
+        .await?;
+
+        info!(
+            "Manager roles for tag {} in guild {} set to {:?}",
+            tag_id, guild_id, role_ids
+        );
+        Ok(())
+    }
+
+    /// Whether a member manages an equipment through its tag's manager roles.
+    /// Untagged equipment and tags without managers are admin-only.
+    pub async fn is_equipment_manager(
+        &self,
+        equipment_id: i64,
+        member_roles: &[i64],
+    ) -> Result<bool> {
+        let manager_roles = sqlx::query_scalar!(
+            "SELECT t.manager_roles
+             FROM equipment e
+             JOIN tags t ON e.tag_id = t.id
+             WHERE e.id = ?",
+            equipment_id
+        )
+        .fetch_optional(&self.db)
+        .await?
+        .flatten();
+
+        let manager_roles = parse_role_ids(manager_roles.as_deref());
+        Ok(manager_roles.iter().any(|role| member_roles.contains(role)))
+    }
+
+    /// Check whether a member may reserve an equipment.
+    /// Returns the unmet restriction, or None when the member is eligible.
+    pub async fn check_equipment_access(
+        &self,
+        equipment_id: i64,
+        member_roles: &[i64],
+    ) -> Result<Option<TagRestriction>> {
+        let tag = sqlx::query!(
+            "SELECT t.id as \"id!\", t.name, t.allowed_roles
+             FROM equipment e
+             JOIN tags t ON e.tag_id = t.id
+             WHERE e.id = ?",
+            equipment_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        let Some(tag) = tag else {
+            return Ok(None);
+        };
+
+        let allowed_roles = parse_role_ids(tag.allowed_roles.as_deref());
+        if has_any_role(&allowed_roles, member_roles) {
+            return Ok(None);
+        }
+
+        Ok(Some(TagRestriction {
+            tag_id: tag.id,
+            tag_name: tag.name,
+            allowed_roles,
+        }))
+    }
+}
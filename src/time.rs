@@ -1,72 +1,378 @@
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
-use chrono_tz::Asia::Tokyo;
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    TimeZone, Utc,
+};
+use chrono_tz::{Asia::Tokyo, Tz};
+use sqlx::SqlitePool;
 
-/// Convert UTC DateTime to JST formatted string
-pub fn utc_to_jst_string(utc_time: DateTime<Utc>) -> String {
-    let jst_time = Tokyo.from_utc_datetime(&utc_time.naive_utc());
-    jst_time.format("%Y/%m/%d %H:%M").to_string()
+use crate::i18n::{t, tf, Language};
+
+/// Time zone of guilds that have not picked one in /setup
+pub const DEFAULT_TIME_ZONE: Tz = Tokyo;
+
+/// Time zones offered in the /setup select as (IANA name, label)
+pub const COMMON_TIME_ZONES: [(&str, &str); 20] = [
+    ("Asia/Tokyo", "Japan"),
+    ("Asia/Seoul", "Korea"),
+    ("Asia/Shanghai", "China"),
+    ("Asia/Taipei", "Taiwan"),
+    ("Asia/Singapore", "Singapore"),
+    ("Asia/Bangkok", "Thailand / Vietnam"),
+    ("Asia/Kolkata", "India"),
+    ("Asia/Dubai", "Gulf"),
+    ("Australia/Sydney", "Australia (Sydney)"),
+    ("Pacific/Auckland", "New Zealand"),
+    ("Europe/London", "UK / Ireland"),
+    ("Europe/Paris", "Central Europe"),
+    ("Europe/Helsinki", "Eastern Europe"),
+    ("America/New_York", "US Eastern"),
+    ("America/Chicago", "US Central"),
+    ("America/Denver", "US Mountain"),
+    ("America/Los_Angeles", "US Pacific"),
+    ("Pacific/Honolulu", "Hawaii"),
+    ("America/Sao_Paulo", "Brazil"),
+    ("UTC", "UTC"),
+];
+
+/// Parse an IANA time zone name such as `Europe/Paris`
+pub fn parse_time_zone(name: &str) -> Option<Tz> {
+    name.trim().parse().ok()
 }
 
-/// Convert UTC DateTime to JST date string
-pub fn utc_to_jst_date_string(utc_time: DateTime<Utc>) -> String {
-    let jst_time = Tokyo.from_utc_datetime(&utc_time.naive_utc());
-    jst_time.format("%Y/%m/%d").to_string()
+/// Time zone stored for a guild; missing or unknown names fall back to the default
+pub fn time_zone_or_default(name: Option<&str>) -> Tz {
+    name.and_then(parse_time_zone).unwrap_or(DEFAULT_TIME_ZONE)
 }
 
-/// Convert UTC DateTime to JST time string
-pub fn utc_to_jst_time_string(utc_time: DateTime<Utc>) -> String {
-    let jst_time = Tokyo.from_utc_datetime(&utc_time.naive_utc());
-    jst_time.format("%H:%M").to_string()
+/// Time zone configured for a guild in /setup
+pub async fn guild_time_zone(db: &SqlitePool, guild_id: i64) -> anyhow::Result<Tz> {
+    let name = sqlx::query_scalar!("SELECT time_zone FROM guilds WHERE id = ?", guild_id)
+        .fetch_optional(db)
+        .await?;
+    Ok(time_zone_or_default(name.as_deref()))
 }
 
-/// Get current time in JST as formatted string
-pub fn now_jst_string() -> String {
-    let now_utc = Utc::now();
-    utc_to_jst_string(now_utc)
+/// Format a UTC time as `YYYY/MM/DD HH:MM` in `tz`
+pub fn utc_to_local_string(utc_time: DateTime<Utc>, tz: Tz) -> String {
+    utc_time
+        .with_timezone(&tz)
+        .format("%Y/%m/%d %H:%M")
+        .to_string()
+}
+
+/// Format a UTC time as `YYYY/MM/DD` in `tz`
+pub fn utc_to_local_date_string(utc_time: DateTime<Utc>, tz: Tz) -> String {
+    utc_time.with_timezone(&tz).format("%Y/%m/%d").to_string()
+}
+
+/// Format a UTC time as `HH:MM` in `tz`
+pub fn utc_to_local_time_string(utc_time: DateTime<Utc>, tz: Tz) -> String {
+    utc_time.with_timezone(&tz).format("%H:%M").to_string()
+}
+
+/// Calendar date of a UTC time in `tz`
+pub fn local_date(utc_time: DateTime<Utc>, tz: Tz) -> NaiveDate {
+    utc_time.with_timezone(&tz).date_naive()
+}
+
+/// Convert a wall-clock time in `tz` to UTC. A time repeated when the clocks go back is
+/// the earlier of the two; a time skipped when they go forward is an error.
+pub fn local_to_utc(local: NaiveDateTime, tz: Tz) -> Result<DateTime<Utc>, TimeParseError> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(time) => Ok(time.with_timezone(&Utc)),
+        LocalResult::Ambiguous(earliest, _) => Ok(earliest.with_timezone(&Utc)),
+        LocalResult::None => Err(TimeParseError::NonexistentTime(local)),
+    }
 }
 
-/// Parse JST date/time and convert to UTC
-pub fn jst_to_utc(
+/// Offset label for `tz` at a given time, e.g. "JST (UTC+9)" or "CEST (UTC+2)"
+pub fn offset_string(tz: Tz, at: DateTime<Utc>) -> String {
+    let local = at.with_timezone(&tz);
+    let seconds = local.offset().fix().local_minus_utc();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let (hours, minutes) = (seconds.abs() / 3600, seconds.abs() % 3600 / 60);
+    let utc_offset = if minutes == 0 {
+        format!("UTC{}{}", sign, hours)
+    } else {
+        format!("UTC{}{}:{:02}", sign, hours, minutes)
+    };
+
+    // Zones without an abbreviation format as "+03"
+    let abbreviation = local.format("%Z").to_string();
+    if abbreviation.starts_with(['+', '-']) || abbreviation == "UTC" {
+        utc_offset
+    } else {
+        format!("{} ({})", abbreviation, utc_offset)
+    }
+}
+
+/// Convert a wall-clock date and time in `tz` to UTC.
+/// None for dates that do not exist or times skipped by a daylight saving change.
+pub fn local_ymd_hm_to_utc(
     year: i32,
     month: u32,
     day: u32,
     hour: u32,
     minute: u32,
+    tz: Tz,
 ) -> Option<DateTime<Utc>> {
-    let jst_naive = chrono::NaiveDate::from_ymd_opt(year, month, day)
+    let local = chrono::NaiveDate::from_ymd_opt(year, month, day)
         .and_then(|d| d.and_hms_opt(hour, minute, 0))?;
 
-    let jst_time = Tokyo.from_local_datetime(&jst_naive).single()?;
-
-    Some(jst_time.with_timezone(&Utc))
+    local_to_utc(local, tz).ok()
 }
 
-/// Check if a time is in the past (JST comparison)
-pub fn is_past_jst(utc_time: DateTime<Utc>) -> bool {
+/// Check if a time is in the past
+pub fn is_past(utc_time: DateTime<Utc>) -> bool {
     let now_utc = Utc::now();
     utc_time < now_utc
 }
 
-/// Get JST timezone offset string for display
-pub fn jst_offset_string() -> &'static str {
-    "JST (UTC+9)"
+/// Why a typed date/time could not be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeParseError {
+    Empty,
+    UnrecognizedFormat(String),
+    MissingTime,
+    InvalidDate { year: i32, month: u32, day: u32 },
+    InvalidTime { hour: u32, minute: u32 },
+    InvalidDuration(String),
+    ZeroDuration,
+    NonexistentTime(NaiveDateTime),
 }
 
-/// Parse JST date/time string and convert to UTC
-/// Format: YYYY-MM-DD HH:MM
-pub fn parse_jst_string(jst_str: &str) -> Option<DateTime<Utc>> {
-    let parts: Vec<&str> = jst_str.split(&[' ', ':', '-'][..]).collect();
-    if parts.len() != 5 {
-        return None;
+impl TimeParseError {
+    /// User-facing explanation
+    pub fn message(&self, lang: Language) -> String {
+        match self {
+            TimeParseError::Empty => t(lang, "time_error.empty").to_string(),
+            TimeParseError::UnrecognizedFormat(input) => {
+                tf(lang, "time_error.unrecognized", &[("input", input)])
+            }
+            TimeParseError::MissingTime => t(lang, "time_error.missing_time").to_string(),
+            TimeParseError::InvalidDate { year, month, day } => {
+                let date = format!("{:04}-{:02}-{:02}", year, month, day);
+                tf(lang, "time_error.invalid_date", &[("date", &date)])
+            }
+            TimeParseError::InvalidTime { hour, minute } => {
+                let time = format!("{:02}:{:02}", hour, minute);
+                tf(lang, "time_error.invalid_time", &[("time", &time)])
+            }
+            TimeParseError::InvalidDuration(input) => {
+                tf(lang, "time_error.invalid_duration", &[("input", input)])
+            }
+            TimeParseError::ZeroDuration => t(lang, "time_error.zero_duration").to_string(),
+            TimeParseError::NonexistentTime(local) => {
+                let time = local.format("%Y/%m/%d %H:%M");
+                tf(lang, "time_error.nonexistent", &[("time", &time)])
+            }
+        }
     }
+}
 
-    let year: i32 = parts[0].parse().ok()?;
-    let month: u32 = parts[1].parse().ok()?;
-    let day: u32 = parts[2].parse().ok()?;
-    let hour: u32 = parts[3].parse().ok()?;
-    let minute: u32 = parts[4].parse().ok()?;
+/// Words for days relative to today, in English and Japanese
+const RELATIVE_DAYS: [(&str, i64); 7] = [
+    ("today", 0),
+    ("tomorrow", 1),
+    ("tmr", 1),
+    ("今日", 0),
+    ("明日", 1),
+    ("あした", 1),
+    ("明後日", 2),
+];
 
-    jst_to_utc(year, month, day, hour, minute)
+/// Parse a length such as `90m`, `2h30m`, `1d 12h` or `2時間30分`
+pub fn parse_duration_input(input: &str) -> Result<Duration, TimeParseError> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(TimeParseError::Empty);
+    }
+    let invalid = || TimeParseError::InvalidDuration(trimmed.to_string());
+
+    let mut total = Duration::zero();
+    let mut rest = trimmed.trim_start_matches('+').trim_start();
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+    while !rest.is_empty() {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 {
+            return Err(invalid());
+        }
+        let amount: i64 = rest[..digits].parse().map_err(|_| invalid())?;
+        rest = rest[digits..].trim_start();
+
+        let unit_len = rest.len()
+            - rest
+                .trim_start_matches(|c: char| !c.is_ascii_digit() && !c.is_whitespace())
+                .len();
+        let minutes_per_unit = match rest[..unit_len].to_lowercase().as_str() {
+            "d" | "day" | "days" | "日" => 24 * 60,
+            "h" | "hr" | "hrs" | "hour" | "hours" | "時間" => 60,
+            "m" | "min" | "mins" | "minute" | "minutes" | "分" => 1,
+            _ => return Err(invalid()),
+        };
+        total += Duration::minutes(amount.checked_mul(minutes_per_unit).ok_or_else(invalid)?);
+        rest = rest[unit_len..].trim_start();
+    }
+
+    if total <= Duration::zero() {
+        return Err(TimeParseError::ZeroDuration);
+    }
+    Ok(total)
+}
+
+/// Parse `HH:MM` or `H:MM`
+fn parse_clock_time(input: &str) -> Result<NaiveTime, TimeParseError> {
+    let unrecognized = || TimeParseError::UnrecognizedFormat(input.to_string());
+    let (hour, minute) = input.split_once(':').ok_or_else(unrecognized)?;
+    let is_number = |part: &str, max_len: usize| {
+        (1..=max_len).contains(&part.len()) && part.chars().all(|c| c.is_ascii_digit())
+    };
+    if !is_number(hour, 2) || !is_number(minute, 2) || minute.len() != 2 {
+        return Err(unrecognized());
+    }
+
+    let hour: u32 = hour.parse().map_err(|_| unrecognized())?;
+    let minute: u32 = minute.parse().map_err(|_| unrecognized())?;
+    NaiveTime::from_hms_opt(hour, minute, 0).ok_or(TimeParseError::InvalidTime { hour, minute })
+}
+
+/// Parse the date part: `YYYY-MM-DD`, `YYYY/MM/DD`, or `MM/DD` / `MM-DD` for the next such date
+fn parse_calendar_date(input: &str, today: NaiveDate) -> Result<NaiveDate, TimeParseError> {
+    let unrecognized = || TimeParseError::UnrecognizedFormat(input.to_string());
+    let parts: Vec<&str> = input.split(['-', '/']).collect();
+    let numbers = parts
+        .iter()
+        .map(|part| part.parse::<u32>().map_err(|_| unrecognized()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match numbers[..] {
+        [year, month, day] if parts[0].len() == 4 => {
+            let year = year as i32;
+            NaiveDate::from_ymd_opt(year, month, day).ok_or(TimeParseError::InvalidDate {
+                year,
+                month,
+                day,
+            })
+        }
+        [month, day] => {
+            // Without a year, a date earlier than today means next year
+            let year = today.year();
+            let date = NaiveDate::from_ymd_opt(year, month, day)
+                .or_else(|| NaiveDate::from_ymd_opt(year + 1, month, day))
+                .ok_or(TimeParseError::InvalidDate { year, month, day })?;
+            if date < today {
+                NaiveDate::from_ymd_opt(year + 1, month, day).ok_or(TimeParseError::InvalidDate {
+                    year: year + 1,
+                    month,
+                    day,
+                })
+            } else {
+                Ok(date)
+            }
+        }
+        _ => Err(unrecognized()),
+    }
+}
+
+/// Parse a date and time typed by a member, in the guild's time zone `tz`. Accepts
+/// - `2025-01-15 14:30` and `2025/01/15 14:30`
+/// - `1/15 14:30` or `01-15 14:30`, meaning the next January 15th
+/// - `today 18:00`, `tomorrow 9:00`, `明日 14:00` and a bare `18:00` for today
+/// - `+2h`, `+30m` or `+1d` from `now`
+pub fn parse_time_input(
+    input: &str,
+    now: DateTime<Utc>,
+    tz: Tz,
+) -> Result<DateTime<Utc>, TimeParseError> {
+    let normalized = input.trim().replace('：', ":").replace('　', " ");
+    if normalized.is_empty() {
+        return Err(TimeParseError::Empty);
+    }
+    if normalized.starts_with('+') {
+        return Ok(now + parse_duration_input(&normalized)?);
+    }
+
+    // Report the whole input rather than the part that failed to parse
+    parse_date_and_time(&normalized, local_date(now, tz), tz).map_err(|err| match err {
+        TimeParseError::UnrecognizedFormat(_) => {
+            TimeParseError::UnrecognizedFormat(normalized.clone())
+        }
+        other => other,
+    })
+}
+
+/// Parse a date (absolute, without a year, or relative to `today`) followed by a time
+fn parse_date_and_time(
+    input: &str,
+    today: NaiveDate,
+    tz: Tz,
+) -> Result<DateTime<Utc>, TimeParseError> {
+    let lowered = input.to_lowercase();
+    if let Some((word, days)) = RELATIVE_DAYS
+        .iter()
+        .find(|(word, _)| lowered.starts_with(word))
+    {
+        let time_part = lowered[word.len()..].trim();
+        if time_part.is_empty() {
+            return Err(TimeParseError::MissingTime);
+        }
+        let date = today + Duration::days(*days);
+        return local_to_utc(date.and_time(parse_clock_time(time_part)?), tz);
+    }
+
+    let parts: Vec<&str> = input.split_whitespace().collect();
+    match parts[..] {
+        [time] if time.contains(':') => local_to_utc(today.and_time(parse_clock_time(time)?), tz),
+        [date] if date.contains(['-', '/']) => {
+            parse_calendar_date(date, today)?;
+            Err(TimeParseError::MissingTime)
+        }
+        [date, time] => {
+            let date = parse_calendar_date(date, today)?;
+            local_to_utc(date.and_time(parse_clock_time(time)?), tz)
+        }
+        _ => Err(TimeParseError::UnrecognizedFormat(input.to_string())),
+    }
+}
+
+/// Parse a date without a time: `2025-01-15`, `1/15` for the next January 15th,
+/// or `today`, `tomorrow`, `明日`
+pub fn parse_date_input(input: &str, today: NaiveDate) -> Result<NaiveDate, TimeParseError> {
+    let normalized = input.trim().to_lowercase();
+    if normalized.is_empty() {
+        return Err(TimeParseError::Empty);
+    }
+    if let Some((_, days)) = RELATIVE_DAYS.iter().find(|(word, _)| *word == normalized) {
+        return Ok(today + Duration::days(*days));
+    }
+    parse_calendar_date(&normalized, today).map_err(|err| match err {
+        TimeParseError::UnrecognizedFormat(_) => {
+            TimeParseError::UnrecognizedFormat(input.trim().to_string())
+        }
+        other => other,
+    })
+}
+
+/// Parse the end of a reservation: either a date and time as in [`parse_time_input`],
+/// or a length from `start_time` such as `2h30m` or `+90m`
+pub fn parse_end_time_input(
+    input: &str,
+    start_time: DateTime<Utc>,
+    now: DateTime<Utc>,
+    tz: Tz,
+) -> Result<DateTime<Utc>, TimeParseError> {
+    let trimmed = input.trim();
+    let looks_like_length = !trimmed.is_empty()
+        && !trimmed.contains([':', '：', '-', '/'])
+        && trimmed
+            .trim_start_matches('+')
+            .starts_with(|c: char| c.is_ascii_digit());
+    if looks_like_length {
+        return Ok(start_time + parse_duration_input(trimmed)?);
+    }
+    parse_time_input(trimmed, now, tz)
 }
 
 /// Convert NaiveDateTime to UTC DateTime
@@ -0,0 +1,497 @@
+use std::io::Write;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use sqlx::SqlitePool;
+
+use crate::buffers::{BufferService, TurnaroundBuffer};
+use crate::models::Reservation;
+use crate::operating_hours::{OperatingHoursService, OperatingSchedule};
+
+pub type Rgb = [u8; 3];
+
+const BACKGROUND: Rgb = [255, 255, 255];
+const ROW_SHADE: Rgb = [246, 246, 248];
+const GRID: Rgb = [214, 214, 220];
+const TEXT: Rgb = [64, 64, 72];
+const CLOSED: Rgb = [200, 200, 206];
+const BUFFER: Rgb = [255, 221, 120];
+const RESERVED: Rgb = [88, 101, 242];
+const IN_USE: Rgb = [250, 166, 26];
+const NOW_MARKER: Rgb = [237, 66, 69];
+
+const WIDTH: u32 = 720;
+const LABEL_WIDTH: u32 = 56;
+const HEADER_HEIGHT: u32 = 20;
+const ROW_HEIGHT: u32 = 22;
+const BAR_INSET: u32 = 4;
+const MARGIN: u32 = 6;
+const FONT_SCALE: u32 = 2;
+
+/// What a stretch of a timeline row shows, in drawing order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SpanKind {
+    Closed,
+    Buffer,
+    Reserved,
+    InUse,
+}
+
+impl SpanKind {
+    fn colour(self) -> Rgb {
+        match self {
+            SpanKind::Closed => CLOSED,
+            SpanKind::Buffer => BUFFER,
+            SpanKind::Reserved => RESERVED,
+            SpanKind::InUse => IN_USE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineSpan {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub kind: SpanKind,
+}
+
+/// One bar of the chart covering [start, end)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineRow {
+    pub label: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub spans: Vec<TimelineSpan>,
+}
+
+/// A Gantt-style chart: rows of the same length with ticks at minute offsets from each
+/// row's start, and a marker at the current time
+#[derive(Debug, Clone)]
+pub struct Timeline {
+    pub rows: Vec<TimelineRow>,
+    pub ticks: Vec<(i64, String)>,
+    pub now: DateTime<Utc>,
+}
+
+/// Closed hours, prep time and reservations of one equipment within [from, until)
+fn equipment_spans(
+    schedule: &OperatingSchedule,
+    buffer: &TurnaroundBuffer,
+    reservations: &[Reservation],
+    from: NaiveDate,
+    days: u64,
+    now: DateTime<Utc>,
+) -> Vec<TimelineSpan> {
+    let mut spans: Vec<TimelineSpan> = from
+        .iter_days()
+        .take(days as usize)
+        .flat_map(|date| schedule.closed_periods(date))
+        .map(|(start, end)| TimelineSpan {
+            start,
+            end,
+            kind: SpanKind::Closed,
+        })
+        .collect();
+
+    for reservation in reservations {
+        let end = reservation
+            .returned_at
+            .map_or(reservation.end_time, |returned| {
+                returned.min(reservation.end_time)
+            });
+        if !buffer.is_zero() {
+            let (blocked_from, blocked_until) = buffer.blocked_range(reservation.start_time, end);
+            spans.push(TimelineSpan {
+                start: blocked_from,
+                end: blocked_until,
+                kind: SpanKind::Buffer,
+            });
+        }
+        let in_use = reservation.start_time <= now && now < end;
+        spans.push(TimelineSpan {
+            start: reservation.start_time,
+            end,
+            kind: if in_use {
+                SpanKind::InUse
+            } else {
+                SpanKind::Reserved
+            },
+        });
+    }
+
+    spans.sort_by_key(|span| (span.kind, span.start));
+    spans
+}
+
+impl Timeline {
+    /// Week of one equipment: a row per local day from `from`, hours across
+    pub fn equipment_week(
+        schedule: &OperatingSchedule,
+        buffer: &TurnaroundBuffer,
+        reservations: &[Reservation],
+        from: NaiveDate,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let spans = equipment_spans(schedule, buffer, reservations, from, 7, now);
+        let rows = from
+            .iter_days()
+            .take(7)
+            .map(|date| {
+                let start = schedule.local_minute(date, 0);
+                let end = schedule.local_minute(date, 24 * 60);
+                TimelineRow {
+                    label: date.format("%m/%d").to_string(),
+                    start,
+                    end,
+                    spans: spans
+                        .iter()
+                        .filter(|span| span.start < end && span.end > start)
+                        .cloned()
+                        .collect(),
+                }
+            })
+            .collect();
+        let ticks = (0..24)
+            .step_by(3)
+            .map(|hour| (hour * 60, hour.to_string()))
+            .collect();
+
+        Self { rows, ticks, now }
+    }
+
+    /// Week of several equipment, e.g. those of a tag: a row per equipment labelled by
+    /// its position in `equipment` (from 1), days across
+    pub fn tag_week(
+        schedule: &OperatingSchedule,
+        equipment: &[(TurnaroundBuffer, Vec<Reservation>)],
+        from: NaiveDate,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let start = schedule.local_minute(from, 0);
+        let end = schedule.local_minute(from + Duration::days(7), 0);
+        let rows = equipment
+            .iter()
+            .enumerate()
+            .map(|(index, (buffer, reservations))| TimelineRow {
+                label: (index + 1).to_string(),
+                start,
+                end,
+                spans: equipment_spans(schedule, buffer, reservations, from, 7, now),
+            })
+            .collect();
+        let ticks = from
+            .iter_days()
+            .take(7)
+            .map(|date| {
+                let offset = schedule.local_minute(date, 0) - start;
+                (offset.num_minutes(), date.format("%m/%d").to_string())
+            })
+            .collect();
+
+        Self { rows, ticks, now }
+    }
+
+    /// Draw the chart. Labels use a built-in digit font, so only digits, '/', ':' and '-'
+    /// are drawn.
+    pub fn render(&self) -> Canvas {
+        let rows = self.rows.len().max(1) as u32;
+        let height = HEADER_HEIGHT + rows * ROW_HEIGHT + MARGIN;
+        let mut canvas = Canvas::new(WIDTH, height, BACKGROUND);
+        let plot_left = LABEL_WIDTH;
+        let plot_width = WIDTH - LABEL_WIDTH - MARGIN;
+
+        for (index, row) in self.rows.iter().enumerate() {
+            let top = HEADER_HEIGHT + index as u32 * ROW_HEIGHT;
+            if index % 2 == 1 {
+                canvas.fill_rect(0, top, WIDTH, ROW_HEIGHT, ROW_SHADE);
+            }
+            canvas.draw_text(
+                MARGIN,
+                top + (ROW_HEIGHT - 5 * FONT_SCALE) / 2,
+                &row.label,
+                TEXT,
+            );
+        }
+
+        let row_minutes = |row: &TimelineRow| (row.end - row.start).num_minutes().max(1);
+        let x_at = |offset_minutes: i64, total_minutes: i64| -> u32 {
+            let offset = offset_minutes.clamp(0, total_minutes);
+            plot_left + (offset * plot_width as i64 / total_minutes) as u32
+        };
+
+        // Closed hours first so that tick lines stay visible over them
+        for closed in [true, false] {
+            for (index, row) in self.rows.iter().enumerate() {
+                let top = HEADER_HEIGHT + index as u32 * ROW_HEIGHT;
+                let total = row_minutes(row);
+                for span in row
+                    .spans
+                    .iter()
+                    .filter(|span| (span.kind == SpanKind::Closed) == closed)
+                {
+                    let x0 = x_at((span.start - row.start).num_minutes(), total);
+                    let x1 = x_at((span.end - row.start).num_minutes(), total);
+                    if x1 <= x0 {
+                        continue;
+                    }
+                    let (y, h) = match span.kind {
+                        SpanKind::Closed => (top, ROW_HEIGHT),
+                        _ => (top + BAR_INSET, ROW_HEIGHT - 2 * BAR_INSET),
+                    };
+                    canvas.fill_rect(x0, y, x1 - x0, h, span.kind.colour());
+                }
+            }
+
+            if closed {
+                // Ticks follow the first row; rows share their length
+                let total = self.rows.first().map_or(24 * 60, row_minutes);
+                for (offset, label) in &self.ticks {
+                    let x = x_at(*offset, total);
+                    canvas.fill_rect(x, HEADER_HEIGHT - 4, 1, rows * ROW_HEIGHT + 4, GRID);
+                    canvas.draw_text(x + 2, 3, label, TEXT);
+                }
+            }
+        }
+
+        for (index, row) in self.rows.iter().enumerate() {
+            let top = HEADER_HEIGHT + index as u32 * ROW_HEIGHT;
+            if row.start <= self.now && self.now < row.end {
+                let x = x_at((self.now - row.start).num_minutes(), row_minutes(row));
+                canvas.fill_rect(x.saturating_sub(1), top, 2, ROW_HEIGHT, NOW_MARKER);
+            }
+        }
+
+        canvas
+    }
+}
+
+/// An RGB image in memory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    pub width: u32,
+    pub height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    pub fn new(width: u32, height: u32, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: background.repeat((width * height) as usize),
+        }
+    }
+
+    /// Rows of RGB bytes, top to bottom
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Option<Self> {
+        (pixels.len() == (width * height * 3) as usize).then_some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Option<Rgb> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let i = ((y * self.width + x) * 3) as usize;
+        Some([self.pixels[i], self.pixels[i + 1], self.pixels[i + 2]])
+    }
+
+    /// Fill a rectangle, clipped to the canvas
+    pub fn fill_rect(&mut self, x: u32, y: u32, width: u32, height: u32, colour: Rgb) {
+        let x_end = (x + width).min(self.width);
+        let y_end = (y + height).min(self.height);
+        for row in y..y_end {
+            for column in x..x_end {
+                let i = ((row * self.width + column) * 3) as usize;
+                self.pixels[i..i + 3].copy_from_slice(&colour);
+            }
+        }
+    }
+
+    /// Draw text with its top left corner at (x, y); unsupported characters leave a gap
+    pub fn draw_text(&mut self, x: u32, y: u32, text: &str, colour: Rgb) {
+        for (index, c) in text.chars().enumerate() {
+            let left = x + index as u32 * 4 * FONT_SCALE;
+            let Some(glyph) = glyph(c) else {
+                continue;
+            };
+            for (row, bits) in glyph.iter().enumerate() {
+                for column in 0..3 {
+                    if bits & (0b100 >> column) != 0 {
+                        self.fill_rect(
+                            left + column * FONT_SCALE,
+                            y + row as u32 * FONT_SCALE,
+                            FONT_SCALE,
+                            FONT_SCALE,
+                            colour,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Encode as an 8-bit RGB PNG without filtering
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        let stride = (self.width * 3) as usize;
+        let mut raw = Vec::with_capacity((stride + 1) * self.height as usize);
+        for line in self.pixels.chunks(stride) {
+            raw.push(0);
+            raw.extend_from_slice(line);
+        }
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&raw)?;
+        let data = encoder.finish()?;
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&self.width.to_be_bytes());
+        header.extend_from_slice(&self.height.to_be_bytes());
+        // Bit depth 8, colour type 2 (RGB), default compression, filter and no interlace
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut png = PNG_SIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &data);
+        write_chunk(&mut png, b"IEND", &[]);
+        Ok(png)
+    }
+}
+
+pub const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let mut crc = crc32fast::Hasher::new();
+    crc.update(kind);
+    crc.update(data);
+    png.extend_from_slice(&crc.finalize().to_be_bytes());
+}
+
+/// 3x5 pixel glyphs, one row of bits per line
+fn glyph(c: char) -> Option<[u8; 5]> {
+    Some(match c {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        '/' => [0b001, 0b001, 0b010, 0b100, 0b100],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        _ => return None,
+    })
+}
+
+/// Loads what a timeline shows from the database
+pub struct TimelineService {
+    db: SqlitePool,
+}
+
+impl TimelineService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// Confirmed reservations of an equipment overlapping [from, until)
+    async fn reservations_between(
+        &self,
+        equipment_id: i64,
+        from: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<Reservation>> {
+        let reservations = sqlx::query_as::<_, Reservation>(
+            "SELECT id, equipment_id, user_id, start_time, end_time, location, status,
+                    created_at, updated_at, returned_at, return_location
+             FROM reservations
+             WHERE equipment_id = ? AND status = 'Confirmed'
+               AND end_time > ? AND start_time < ?
+             ORDER BY start_time ASC",
+        )
+        .bind(equipment_id)
+        .bind(from)
+        .bind(until)
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(reservations)
+    }
+
+    async fn week_schedule(&self, guild_id: i64, now: DateTime<Utc>) -> Result<OperatingSchedule> {
+        let tz = crate::time::guild_time_zone(&self.db, guild_id).await?;
+        let from = crate::time::local_date(now, tz);
+        OperatingHoursService::new(self.db.clone())
+            .schedule_between(guild_id, from, from + Duration::days(6))
+            .await
+    }
+
+    /// This week of one equipment, starting today
+    pub async fn equipment_week(
+        &self,
+        guild_id: i64,
+        equipment_id: i64,
+        now: DateTime<Utc>,
+    ) -> Result<Timeline> {
+        let schedule = self.week_schedule(guild_id, now).await?;
+        let from = crate::time::local_date(now, schedule.time_zone);
+        let buffer = BufferService::new(self.db.clone())
+            .buffer_for_equipment(equipment_id)
+            .await?;
+        let reservations = self
+            .reservations_between(
+                equipment_id,
+                schedule.local_minute(from, 0),
+                schedule.local_minute(from + Duration::days(7), 0),
+            )
+            .await?;
+
+        Ok(Timeline::equipment_week(
+            &schedule,
+            &buffer,
+            &reservations,
+            from,
+            now,
+        ))
+    }
+
+    /// This week of several equipment, starting today; rows follow `equipment_ids`
+    pub async fn tag_week(
+        &self,
+        guild_id: i64,
+        equipment_ids: &[i64],
+        now: DateTime<Utc>,
+    ) -> Result<Timeline> {
+        let schedule = self.week_schedule(guild_id, now).await?;
+        let from = crate::time::local_date(now, schedule.time_zone);
+        let buffers = BufferService::new(self.db.clone());
+        let mut equipment = Vec::with_capacity(equipment_ids.len());
+        for equipment_id in equipment_ids {
+            let buffer = buffers.buffer_for_equipment(*equipment_id).await?;
+            let reservations = self
+                .reservations_between(
+                    *equipment_id,
+                    schedule.local_minute(from, 0),
+                    schedule.local_minute(from + Duration::days(7), 0),
+                )
+                .await?;
+            equipment.push((buffer, reservations));
+        }
+
+        Ok(Timeline::tag_week(&schedule, &equipment, from, now))
+    }
+}
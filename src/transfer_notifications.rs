@@ -4,6 +4,7 @@ use serenity::all::{ChannelId, Context, UserId};
 use sqlx::SqlitePool;
 use tracing::{error, info, warn};
 
+use crate::i18n::{tf, Language};
 use crate::traits::DiscordApi;
 
 /// Delivery method for notifications
@@ -41,57 +42,60 @@ pub enum TransferNotificationType {
 
 impl TransferNotificationType {
     /// Get the DM message content for this notification type
-    pub fn dm_message(&self) -> String {
+    pub fn dm_message(&self, lang: Language) -> String {
         match self {
-            TransferNotificationType::RequestSent { equipment_name, requester_id, .. } => {
-                format!(
-                    "📤 **予約移譲依頼**\n\n<@{}>から「{}」の予約移譲依頼があります。\n\nDMで詳細を確認して承認・拒否を選択してください。\n\n⚠️ この依頼は3時間後に自動的に期限切れになります。",
-                    requester_id, equipment_name
-                )
-            }
-            TransferNotificationType::Approved { equipment_name } => {
-                format!("✅ **移譲承認通知**\n\n「{}」の予約移譲依頼が承認されました。", equipment_name)
-            }
-            TransferNotificationType::Denied { equipment_name, reason } => {
-                format!(
-                    "❌ **移譲拒否通知**\n\n「{}」の予約移譲依頼が拒否されました。\n\n理由: {}",
-                    equipment_name, reason
-                )
-            }
-            TransferNotificationType::Cancelled { equipment_name, .. } => {
-                format!("🚫 **移譲キャンセル通知**\n\n「{}」の予約移譲依頼がキャンセルされました。", equipment_name)
-            }
-            TransferNotificationType::Expired { equipment_name } => {
-                format!(
-                    "⏰ **移譲期限切れ通知**\n\n「{}」の予約移譲依頼が3時間以内に承認されなかったため、自動的にキャンセルされました。",
-                    equipment_name
-                )
-            }
+            TransferNotificationType::RequestSent {
+                equipment_name,
+                requester_id,
+                ..
+            } => tf(
+                lang,
+                "transfer_notice.request_dm",
+                &[("requester", requester_id), ("equipment", equipment_name)],
+            ),
+            TransferNotificationType::Approved { equipment_name } => tf(
+                lang,
+                "transfer_notice.approved_dm",
+                &[("equipment", equipment_name)],
+            ),
+            TransferNotificationType::Denied {
+                equipment_name,
+                reason,
+            } => tf(
+                lang,
+                "transfer_notice.denied_dm",
+                &[("equipment", equipment_name), ("reason", reason)],
+            ),
+            TransferNotificationType::Cancelled { equipment_name, .. } => tf(
+                lang,
+                "transfer_notice.cancelled_dm",
+                &[("equipment", equipment_name)],
+            ),
+            TransferNotificationType::Expired { equipment_name } => tf(
+                lang,
+                "transfer_notice.expired_dm",
+                &[("equipment", equipment_name)],
+            ),
         }
     }
 
     /// Get the generic public fallback message (no sensitive details)
-    pub fn fallback_message(&self, reservation_id: i64) -> String {
-        match self {
-            TransferNotificationType::RequestSent { equipment_name, .. } => {
-                format!(
-                    "「{}」の予約移譲に関する通知があります。予約ID: #{}\n\nDMを有効にして詳細を確認してください。",
-                    equipment_name, reservation_id
-                )
-            }
-            TransferNotificationType::Approved { equipment_name } => {
-                format!("「{}」の予約移譲に関する更新があります。予約ID: #{}", equipment_name, reservation_id)
-            }
-            TransferNotificationType::Denied { equipment_name, .. } => {
-                format!("「{}」の予約移譲に関する更新があります。予約ID: #{}", equipment_name, reservation_id)
-            }
-            TransferNotificationType::Cancelled { equipment_name, .. } => {
-                format!("「{}」の予約移譲に関する更新があります。予約ID: #{}", equipment_name, reservation_id)
-            }
-            TransferNotificationType::Expired { equipment_name } => {
-                format!("「{}」の予約移譲に関する更新があります。予約ID: #{}", equipment_name, reservation_id)
-            }
-        }
+    pub fn fallback_message(&self, lang: Language, reservation_id: i64) -> String {
+        let key = match self {
+            TransferNotificationType::RequestSent { .. } => "transfer_notice.request_fallback",
+            TransferNotificationType::Approved { .. }
+            | TransferNotificationType::Denied { .. }
+            | TransferNotificationType::Cancelled { .. }
+            | TransferNotificationType::Expired { .. } => "transfer_notice.update_fallback",
+        };
+        tf(
+            lang,
+            key,
+            &[
+                ("equipment", &self.equipment_name()),
+                ("reservation_id", &reservation_id),
+            ],
+        )
     }
 
     /// Get the equipment name for logging purposes
@@ -127,7 +131,8 @@ impl TransferNotificationService {
         notification: TransferNotificationType,
     ) -> Result<DeliveryMethod> {
         let user_id_discord = UserId::new(user_id as u64);
-        let dm_message = notification.dm_message();
+        let lang = crate::i18n::user_language(&self.db, Some(guild_id), user_id).await?;
+        let dm_message = notification.dm_message(lang);
 
         // Try sending DM first
         match self.try_send_dm(ctx, user_id_discord, &dm_message).await {
@@ -151,7 +156,7 @@ impl TransferNotificationService {
         if let Some(config) = guild_config {
             if config.dm_fallback_channel_enabled.unwrap_or(true) {
                 if let Some(channel_id) = config.reservation_channel_id {
-                    let fallback_message = notification.fallback_message(reservation_id);
+                    let fallback_message = notification.fallback_message(lang, reservation_id);
                     let channel_message = format!("<@{}> {}", user_id, fallback_message);
                     
                     match self.try_send_channel_message(ctx, channel_id, &channel_message).await {
@@ -184,7 +189,8 @@ impl TransferNotificationService {
         notification: TransferNotificationType,
     ) -> Result<DeliveryMethod> {
         let user_id_discord = UserId::new(user_id as u64);
-        let dm_message = notification.dm_message();
+        let lang = crate::i18n::user_language(&self.db, Some(guild_id), user_id).await?;
+        let dm_message = notification.dm_message(lang);
 
         // Try sending DM first
         match discord_api.send_dm(user_id_discord, &dm_message).await {
@@ -208,7 +214,7 @@ impl TransferNotificationService {
         if let Some(config) = guild_config {
             if config.dm_fallback_channel_enabled.unwrap_or(true) {
                 if let Some(channel_id) = config.reservation_channel_id {
-                    let fallback_message = notification.fallback_message(reservation_id);
+                    let fallback_message = notification.fallback_message(lang, reservation_id);
                     let channel_message = format!("<@{}> {}", user_id, fallback_message);
                     let channel_id_discord = ChannelId::new(channel_id as u64);
                     
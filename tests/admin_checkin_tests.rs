@@ -0,0 +1,103 @@
+use anyhow::Result;
+use chrono::Duration;
+use oucc_kizai_bot::returns::ReturnService;
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+/// Test listing started, unreturned loans with overdue ones first
+#[tokio::test]
+async fn test_active_loans() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ReturnService::new(ctx.db.clone());
+    let now = ctx.clock.now_utc();
+
+    let projector = common::EquipmentBuilder::new(guild.id, "Projector")
+        .with_tag(tag.id)
+        .with_default_return_location("Club Room")
+        .build(&ctx.db)
+        .await?;
+
+    let active = common::ReservationBuilder::new(
+        equipment.id,
+        111,
+        now - Duration::hours(1),
+        now + Duration::hours(2),
+    )
+    .build(&ctx.db)
+    .await?;
+    let overdue = common::ReservationBuilder::new(
+        projector.id,
+        222,
+        now - Duration::hours(5),
+        now - Duration::hours(1),
+    )
+    .build(&ctx.db)
+    .await?;
+    // Not started yet
+    common::ReservationBuilder::new(
+        equipment.id,
+        333,
+        now + Duration::hours(3),
+        now + Duration::hours(4),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let loans = service.active_loans(guild.id, now, 25).await?;
+    assert_eq!(loans.len(), 2);
+    assert_eq!(loans[0].reservation_id, overdue.id);
+    assert!(loans[0].is_overdue(now));
+    assert_eq!(
+        loans[0].default_return_location.as_deref(),
+        Some("Club Room")
+    );
+    assert_eq!(loans[1].reservation_id, active.id);
+    assert!(!loans[1].is_overdue(now));
+
+    Ok(())
+}
+
+/// Test that returned or cancelled reservations are not offered for check-in
+#[tokio::test]
+async fn test_active_loan_excludes_returned() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (_guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ReturnService::new(ctx.db.clone());
+    let now = ctx.clock.now_utc();
+
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        111,
+        now - Duration::hours(1),
+        now + Duration::hours(2),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let loan = service.active_loan(equipment.id, now).await?;
+    assert_eq!(loan.map(|loan| loan.user_id), Some(111));
+
+    sqlx::query!(
+        "UPDATE reservations SET returned_at = ?, return_location = 'Club Room' WHERE id = ?",
+        now,
+        reservation.id
+    )
+    .execute(&ctx.db)
+    .await?;
+    assert!(service.active_loan(equipment.id, now).await?.is_none());
+
+    common::ReservationBuilder::new(
+        equipment.id,
+        222,
+        now - Duration::minutes(30),
+        now + Duration::hours(1),
+    )
+    .with_status("Cancelled")
+    .build(&ctx.db)
+    .await?;
+    assert!(service.active_loan(equipment.id, now).await?.is_none());
+
+    Ok(())
+}
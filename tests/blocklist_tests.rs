@@ -0,0 +1,182 @@
+use anyhow::Result;
+use chrono::Duration;
+use oucc_kizai_bot::blocklist::BlocklistService;
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+/// Test that a guild-wide block applies to every tag
+#[tokio::test]
+async fn test_guild_wide_block() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = BlocklistService::new(ctx.db.clone());
+
+    let user_id = 12345i64;
+    let admin_id = 99999i64;
+
+    assert!(service
+        .find_active_block_for_equipment(user_id, equipment.id)
+        .await?
+        .is_none());
+
+    service
+        .block_user(
+            guild.id,
+            user_id,
+            None,
+            Some("Damaged a lens".to_string()),
+            None,
+            admin_id,
+        )
+        .await?;
+
+    let block = service
+        .find_active_block_for_equipment(user_id, equipment.id)
+        .await?
+        .expect("block should apply");
+    assert_eq!(block.tag_id, None);
+    assert_eq!(block.reason.as_deref(), Some("Damaged a lens"));
+
+    assert!(service
+        .find_active_block(guild.id, user_id, Some(tag.id))
+        .await?
+        .is_some());
+    assert!(service
+        .find_active_block(guild.id, user_id, None)
+        .await?
+        .is_some());
+
+    // Other members are unaffected
+    assert!(service
+        .find_active_block_for_equipment(54321, equipment.id)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+/// Test that a tag-scoped block only applies to that tag
+#[tokio::test]
+async fn test_tag_scoped_block() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = BlocklistService::new(ctx.db.clone());
+
+    let other_tag = common::TagBuilder::new(guild.id, "Audio")
+        .build(&ctx.db)
+        .await?;
+    let other_equipment = common::EquipmentBuilder::new(guild.id, "Zoom H6")
+        .with_tag(other_tag.id)
+        .build(&ctx.db)
+        .await?;
+
+    let user_id = 12345i64;
+    service
+        .block_user(guild.id, user_id, Some(tag.id), None, None, 99999)
+        .await?;
+
+    let block = service
+        .find_active_block_for_equipment(user_id, equipment.id)
+        .await?
+        .expect("tag block should apply");
+    assert_eq!(block.tag_name.as_deref(), Some("Camera"));
+
+    assert!(service
+        .find_active_block_for_equipment(user_id, other_equipment.id)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+/// Test that expired and lifted blocks no longer apply
+#[tokio::test]
+async fn test_block_expiry_and_lift() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = BlocklistService::new(ctx.db.clone());
+
+    let user_id = 12345i64;
+    let admin_id = 99999i64;
+
+    // Already expired
+    service
+        .block_user(
+            guild.id,
+            user_id,
+            None,
+            None,
+            Some(ctx.clock.now_utc() - Duration::hours(1)),
+            admin_id,
+        )
+        .await?;
+    assert!(service
+        .find_active_block_for_equipment(user_id, equipment.id)
+        .await?
+        .is_none());
+
+    let block_id = service
+        .block_user(
+            guild.id,
+            user_id,
+            None,
+            None,
+            Some(ctx.clock.now_utc() + Duration::days(7)),
+            admin_id,
+        )
+        .await?;
+    assert_eq!(service.list_active_blocks(guild.id, 25).await?.len(), 1);
+
+    assert!(service.lift_block(guild.id, block_id, admin_id).await?);
+    assert!(!service.lift_block(guild.id, block_id, admin_id).await?);
+
+    assert!(service
+        .find_active_block_for_equipment(user_id, equipment.id)
+        .await?
+        .is_none());
+    assert!(service.list_active_blocks(guild.id, 25).await?.is_empty());
+
+    // Every change is audited
+    let actions = sqlx::query_scalar!(
+        "SELECT action FROM user_block_events WHERE guild_id = ? AND user_id = ? ORDER BY id",
+        guild.id,
+        user_id
+    )
+    .fetch_all(&ctx.db)
+    .await?;
+    assert_eq!(actions, vec!["Blocked", "Blocked", "Lifted"]);
+
+    Ok(())
+}
+
+/// Test that a blocked transfer target is detected from the reservation
+#[tokio::test]
+async fn test_block_applies_to_transfer_target() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = BlocklistService::new(ctx.db.clone());
+
+    let owner_id = 11111i64;
+    let target_id = 22222i64;
+    let start = ctx.clock.now_utc() + Duration::hours(1);
+    let reservation =
+        common::ReservationBuilder::new(equipment.id, owner_id, start, start + Duration::hours(2))
+            .build(&ctx.db)
+            .await?;
+
+    service
+        .block_user(guild.id, target_id, None, None, None, 99999)
+        .await?;
+
+    assert!(service
+        .find_active_block_for_reservation(target_id, reservation.id)
+        .await?
+        .is_some());
+    assert!(service
+        .find_active_block_for_reservation(owner_id, reservation.id)
+        .await?
+        .is_none());
+
+    Ok(())
+}
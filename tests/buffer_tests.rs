@@ -0,0 +1,127 @@
+use anyhow::Result;
+use chrono::Duration;
+use oucc_kizai_bot::buffers::{
+    conflicts_with_buffer, parse_buffer_minutes, reservation_conflict_message, BufferService,
+    TurnaroundBuffer,
+};
+use oucc_kizai_bot::i18n::Language;
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+/// Test parsing prep time entered in a modal
+#[test]
+fn test_parse_buffer_minutes() {
+    assert_eq!(parse_buffer_minutes("15"), Some(15));
+    assert_eq!(parse_buffer_minutes(" 0 "), Some(0));
+    assert_eq!(parse_buffer_minutes("240"), Some(240));
+    assert_eq!(parse_buffer_minutes("241"), None);
+    assert_eq!(parse_buffer_minutes("-5"), None);
+    assert_eq!(parse_buffer_minutes("abc"), None);
+}
+
+/// Test that reservations closer than the prep time conflict
+#[tokio::test]
+async fn test_conflicts_with_buffer() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let now = ctx.clock.now_utc();
+    let existing_start = now + Duration::hours(2);
+    let existing_end = now + Duration::hours(4);
+    let buffer = TurnaroundBuffer::new(15, 30);
+
+    // Back-to-back is fine without a buffer
+    assert!(!conflicts_with_buffer(
+        &TurnaroundBuffer::default(),
+        existing_start,
+        existing_end,
+        existing_end,
+        existing_end + Duration::hours(1),
+    ));
+    // ...but needs 45 minutes with one
+    assert!(conflicts_with_buffer(
+        &buffer,
+        existing_start,
+        existing_end,
+        existing_end + Duration::minutes(30),
+        existing_end + Duration::hours(2),
+    ));
+    assert!(!conflicts_with_buffer(
+        &buffer,
+        existing_start,
+        existing_end,
+        existing_end + Duration::minutes(45),
+        existing_end + Duration::hours(2),
+    ));
+    // Same for a reservation ending before the existing one
+    assert!(conflicts_with_buffer(
+        &buffer,
+        existing_start,
+        existing_end,
+        now,
+        existing_start - Duration::minutes(20),
+    ));
+
+    assert_eq!(
+        buffer.blocked_range(existing_start, existing_end),
+        (
+            existing_start - Duration::minutes(15),
+            existing_end + Duration::minutes(30)
+        )
+    );
+
+    // Overlaps keep the usual message; prep time conflicts explain the buffer
+    let existing = [(existing_start, existing_end)];
+    assert_eq!(
+        reservation_conflict_message(
+            &buffer,
+            &existing,
+            now,
+            existing_start + Duration::hours(1),
+            Language::En
+        ),
+        "This time slot is already reserved. Please select another time."
+    );
+    assert!(reservation_conflict_message(
+        &buffer,
+        &existing,
+        existing_end + Duration::minutes(10),
+        existing_end + Duration::hours(1),
+        Language::En
+    )
+    .contains("45 minutes of prep time"));
+
+    Ok(())
+}
+
+/// Test that equipment falls back to its tag's prep time unless overridden
+#[tokio::test]
+async fn test_buffer_for_equipment() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = BufferService::new(ctx.db.clone());
+
+    assert!(service.buffer_for_equipment(equipment.id).await?.is_zero());
+
+    service.set_tag_buffer(tag.id, 10, 20).await?;
+    assert_eq!(
+        service.buffer_for_equipment(equipment.id).await?,
+        TurnaroundBuffer::new(10, 20)
+    );
+
+    // Overriding one side keeps the tag's value for the other
+    service
+        .set_equipment_buffer(equipment.id, None, Some(60))
+        .await?;
+    assert_eq!(
+        service.buffer_for_equipment(equipment.id).await?,
+        TurnaroundBuffer::new(10, 60)
+    );
+
+    // Untagged equipment only uses its own values
+    let untagged = common::EquipmentBuilder::new(guild.id, "Tripod")
+        .build(&ctx.db)
+        .await?;
+    assert!(service.buffer_for_equipment(untagged.id).await?.is_zero());
+
+    Ok(())
+}
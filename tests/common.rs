@@ -90,6 +90,12 @@ impl GuildBuilder {
             overdue_max_count: Some(3),
             pre_start_minutes: Some(15),
             pre_end_minutes: Some(15),
+            no_show_grace_minutes: Some(0),
+            penalty_threshold_points: Some(0),
+            penalty_suspension_days: Some(0),
+            time_zone: Some("Asia/Tokyo".to_string()),
+            language: Some("ja".to_string()),
+            header_timeline: Some(false),
         })
     }
 }
@@ -133,6 +139,8 @@ impl TagBuilder {
             guild_id: self.guild_id,
             name: self.name,
             sort_order: self.sort_order,
+            allowed_roles: None,
+            manager_roles: None,
             created_at: now,
         })
     }
@@ -0,0 +1,156 @@
+use anyhow::Result;
+use chrono::Duration;
+use oucc_kizai_bot::condition_reports::{
+    damage_unavailable_reason, is_damage_report, parse_condition_rating, ConditionReportService,
+};
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+/// Test parsing condition ratings from the return modal
+#[test]
+fn test_parse_condition_rating() {
+    assert_eq!(parse_condition_rating("5"), Some(5));
+    assert_eq!(parse_condition_rating(" 3 "), Some(3));
+    assert_eq!(parse_condition_rating("4/5"), Some(4));
+    assert_eq!(parse_condition_rating("0"), None);
+    assert_eq!(parse_condition_rating("6"), None);
+    assert_eq!(parse_condition_rating("good"), None);
+    assert_eq!(parse_condition_rating(""), None);
+}
+
+/// Test which returns count as damage reports
+#[test]
+fn test_is_damage_report() {
+    assert!(!is_damage_report(5, None));
+    assert!(!is_damage_report(3, Some("  ")));
+    assert!(is_damage_report(4, Some("Scratched lens")));
+    assert!(is_damage_report(2, None));
+    assert!(is_damage_report(1, None));
+}
+
+/// Test filing reports and attaching DM photos
+#[tokio::test]
+async fn test_damage_report_with_photos() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ConditionReportService::new(ctx.db.clone());
+
+    let user_id = 12345i64;
+    let now = ctx.clock.now_utc();
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        now - Duration::hours(2),
+        now - Duration::minutes(10),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    // A clean return does not take photos
+    let clean = service
+        .create_report(reservation.id, user_id, 5, Some("   ".to_string()))
+        .await?;
+    assert!(clean.photos_accepted_until.is_none());
+    assert!(clean.damage_note.is_none());
+    assert!(service
+        .find_report_accepting_photos(user_id, now)
+        .await?
+        .is_none());
+
+    let report = service
+        .create_report(reservation.id, user_id, 2, Some("Cracked LCD".to_string()))
+        .await?;
+    assert_eq!(report.guild_id, guild.id);
+    assert!(report.photos_accepted_until.is_some());
+
+    let open = service
+        .find_report_accepting_photos(user_id, now)
+        .await?
+        .expect("damage report should accept photos");
+    assert_eq!(open.id, report.id);
+
+    let total = service
+        .attach_images(report.id, &["https://cdn.example/a.png".to_string()])
+        .await?;
+    assert_eq!(total, 1);
+    let total = service
+        .attach_images(report.id, &["https://cdn.example/b.png".to_string()])
+        .await?;
+    assert_eq!(total, 2);
+
+    let stored = service.get_report(report.id).await?.unwrap();
+    assert_eq!(stored.image_urls.len(), 2);
+
+    // Photos are no longer accepted after the window
+    assert!(service
+        .find_report_accepting_photos(user_id, now + Duration::hours(1))
+        .await?
+        .is_none());
+
+    let logged = sqlx::query!(
+        "SELECT COUNT(*) as count FROM equipment_logs WHERE equipment_id = ? AND action = 'DamageReported'",
+        equipment.id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert_eq!(logged, 1);
+
+    Ok(())
+}
+
+/// Test taking damaged equipment out of service from a report
+#[tokio::test]
+async fn test_mark_equipment_unavailable() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (_guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ConditionReportService::new(ctx.db.clone());
+
+    let user_id = 12345i64;
+    let admin_id = 99999i64;
+    let now = ctx.clock.now_utc();
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        now - Duration::hours(2),
+        now - Duration::minutes(10),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let report = service
+        .create_report(
+            reservation.id,
+            user_id,
+            1,
+            Some("Won't power on".to_string()),
+        )
+        .await?;
+
+    assert!(
+        service
+            .mark_equipment_unavailable(report.id, admin_id)
+            .await?
+    );
+    assert!(
+        !service
+            .mark_equipment_unavailable(report.id, admin_id)
+            .await?
+    );
+
+    let row = sqlx::query!(
+        "SELECT status, unavailable_reason FROM equipment WHERE id = ?",
+        equipment.id
+    )
+    .fetch_one(&ctx.db)
+    .await?;
+    assert_eq!(row.status, "Unavailable");
+    assert_eq!(
+        row.unavailable_reason,
+        Some(damage_unavailable_reason(&report))
+    );
+    assert!(row.unavailable_reason.unwrap().contains("Won't power on"));
+
+    Ok(())
+}
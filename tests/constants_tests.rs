@@ -37,6 +37,7 @@ fn test_log_action_constants() {
     assert_eq!(Constants::LOG_ACTION_TRANSFER, "transfer");
     assert_eq!(Constants::LOG_ACTION_EDIT, "edit");
     assert_eq!(Constants::LOG_ACTION_FORCE_STATE, "force_state");
+    assert_eq!(Constants::LOG_ACTION_PICKUP, "PickedUp");
 
     // Equipment management actions
     assert_eq!(Constants::LOG_ACTION_CREATE_EQUIPMENT, "eq_create");
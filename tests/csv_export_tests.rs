@@ -1,6 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc, Duration};
 use oucc_kizai_bot::models::*;
+use oucc_kizai_bot::time::{utc_to_local_string, DEFAULT_TIME_ZONE};
 
 mod common;
 
@@ -106,7 +107,7 @@ fn test_csv_time_formatting() {
     let utc_time = Utc.with_ymd_and_hms(2024, 1, 15, 5, 30, 0).unwrap();
     
     // Test JST conversion (UTC+9)
-    let jst_string = oucc_kizai_bot::time::utc_to_jst_string(utc_time);
+    let jst_string = utc_to_local_string(utc_time, DEFAULT_TIME_ZONE);
     assert_eq!(jst_string, "2024/01/15 14:30"); // Should be 5:30 UTC + 9 hours = 14:30 JST
 
     // Test UTC formatting for CSV
@@ -127,7 +128,7 @@ fn test_csv_missing_data_handling() {
     assert_eq!(location_value, "Not specified");
 
     let returned_jst = returned_at
-        .map(|dt| oucc_kizai_bot::time::utc_to_jst_string(dt))
+        .map(|dt| utc_to_local_string(dt, DEFAULT_TIME_ZONE))
         .unwrap_or_default();
     assert_eq!(returned_jst, "");
 
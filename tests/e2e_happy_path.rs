@@ -77,8 +77,8 @@ async fn test_e2e_happy_path() -> Result<()> {
     let reservation_end = reservation_start + Duration::hours(2); // 15:00 JST
 
     // Verify JST formatting
-    let start_jst = utc_to_jst_string(reservation_start);
-    let end_jst = utc_to_jst_string(reservation_end);
+    let start_jst = utc_to_local_string(reservation_start, DEFAULT_TIME_ZONE);
+    let end_jst = utc_to_local_string(reservation_end, DEFAULT_TIME_ZONE);
     println!("Reservation time: {} - {} JST", start_jst, end_jst);
     assert_eq!(start_jst, "2024/01/15 13:00");
     assert_eq!(end_jst, "2024/01/15 15:00");
@@ -223,7 +223,7 @@ async fn test_e2e_happy_path() -> Result<()> {
         "✅ 返却完了: 「{}」を「{}」に返却しました。\n返却時刻: {}",
         sony_a7.name,
         return_location,
-        utc_to_jst_string(return_time)
+        utc_to_local_string(return_time, DEFAULT_TIME_ZONE)
     );
 
     ctx.discord_api
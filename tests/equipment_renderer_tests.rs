@@ -1,7 +1,13 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
-use chrono::Utc;
-use oucc_kizai_bot::equipment::{EditAction, EquipmentRenderer};
+use chrono::{Duration, Utc};
+use oucc_kizai_bot::equipment::{
+    fuzzy_score, reservation_list_field, tagged_name, EditAction, EquipmentRenderer,
+};
+use oucc_kizai_bot::i18n::Language;
 use oucc_kizai_bot::models::{Equipment, ManagedMessage, Tag};
+use oucc_kizai_bot::traits::Clock;
 
 mod common;
 
@@ -29,6 +35,8 @@ async fn test_edit_plan_computation() -> Result<()> {
                 guild_id: 123,
                 name: "Cameras".to_string(),
                 sort_order: 1,
+                allowed_roles: None,
+                manager_roles: None,
                 created_at: Utc::now(),
             }),
         ),
@@ -51,14 +59,18 @@ async fn test_edit_plan_computation() -> Result<()> {
                 guild_id: 123,
                 name: "Cameras".to_string(),
                 sort_order: 1,
+                allowed_roles: None,
+                manager_roles: None,
                 created_at: Utc::now(),
             }),
         ),
     ];
+    let content_hashes = HashMap::from([(1, "hash-1".to_string()), (2, "hash-2".to_string())]);
 
     // Test case 1: Empty existing messages - should create header + 2 equipment messages
     let existing_messages = vec![];
-    let edit_plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list);
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
     assert_eq!(edit_plan.creates, 3); // 1 header + 2 equipment
     assert_eq!(edit_plan.edits, 0);
@@ -74,8 +86,10 @@ async fn test_edit_plan_computation() -> Result<()> {
         equipment_id: None,
         sort_order: Some(0),
         created_at: Utc::now(),
+        content_hash: None,
     }];
-    let edit_plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list);
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
     assert_eq!(edit_plan.creates, 2); // 2 equipment only
     assert_eq!(edit_plan.edits, 0);
@@ -92,6 +106,7 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: None,
             sort_order: Some(0),
             created_at: Utc::now(),
+            content_hash: None,
         },
         ManagedMessage {
             id: 2,
@@ -102,6 +117,7 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: Some(1),
             sort_order: Some(1),
             created_at: Utc::now(),
+            content_hash: Some("hash-1".to_string()),
         },
         ManagedMessage {
             id: 3,
@@ -112,9 +128,11 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: Some(2),
             sort_order: Some(2),
             created_at: Utc::now(),
+            content_hash: Some("hash-2".to_string()),
         },
     ];
-    let edit_plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list);
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
     assert_eq!(edit_plan.creates, 0);
     assert_eq!(edit_plan.edits, 0);
@@ -131,6 +149,7 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: None,
             sort_order: Some(0),
             created_at: Utc::now(),
+            content_hash: None,
         },
         ManagedMessage {
             id: 2,
@@ -141,6 +160,7 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: Some(99), // Wrong equipment ID
             sort_order: Some(1),
             created_at: Utc::now(),
+            content_hash: Some("hash-99".to_string()),
         },
         ManagedMessage {
             id: 3,
@@ -151,9 +171,11 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: Some(2),
             sort_order: Some(2),
             created_at: Utc::now(),
+            content_hash: Some("hash-2".to_string()),
         },
     ];
-    let edit_plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list);
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
     assert_eq!(edit_plan.creates, 0);
     assert_eq!(edit_plan.edits, 1); // Should edit the first equipment message
@@ -170,6 +192,7 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: None,
             sort_order: Some(0),
             created_at: Utc::now(),
+            content_hash: None,
         },
         ManagedMessage {
             id: 2,
@@ -180,6 +203,7 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: Some(1),
             sort_order: Some(1),
             created_at: Utc::now(),
+            content_hash: Some("hash-1".to_string()),
         },
         ManagedMessage {
             id: 3,
@@ -190,6 +214,7 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: Some(2),
             sort_order: Some(2),
             created_at: Utc::now(),
+            content_hash: Some("hash-2".to_string()),
         },
         ManagedMessage {
             id: 4,
@@ -200,13 +225,33 @@ async fn test_edit_plan_computation() -> Result<()> {
             equipment_id: Some(3), // Extra message
             sort_order: Some(3),
             created_at: Utc::now(),
+            content_hash: Some("hash-3".to_string()),
         },
     ];
-    let edit_plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list);
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
     assert_eq!(edit_plan.creates, 0);
     assert_eq!(edit_plan.edits, 0);
     assert_eq!(edit_plan.deletes, 1); // Should delete the extra message
+    assert_eq!(edit_plan.skipped, 2);
+
+    // Test case 6: Same equipment but changed or unknown content - should edit
+    let mut existing_messages = existing_messages[..3].to_vec();
+    existing_messages[1].content_hash = Some("stale".to_string());
+    existing_messages[2].content_hash = None; // Stored before hashes existed
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
+
+    assert_eq!(edit_plan.edits, 2);
+    assert_eq!(edit_plan.skipped, 0);
+    assert_eq!(
+        edit_plan.actions,
+        vec![
+            EditAction::EditEquipment(790, 1),
+            EditAction::EditEquipment(791, 2)
+        ]
+    );
 
     println!("All edit plan computation tests passed!");
     Ok(())
@@ -231,6 +276,7 @@ async fn test_self_healing_scenarios() -> Result<()> {
         },
         None,
     )];
+    let content_hashes = HashMap::from([(1, "hash-1".to_string())]);
 
     // Test case: Missing header - should create it
     let existing_messages = vec![ManagedMessage {
@@ -242,9 +288,11 @@ async fn test_self_healing_scenarios() -> Result<()> {
         equipment_id: Some(1),
         sort_order: Some(1),
         created_at: Utc::now(),
+        content_hash: Some("hash-1".to_string()),
     }];
 
-    let edit_plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list);
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
     assert_eq!(edit_plan.creates, 1); // Should create header
     assert_eq!(edit_plan.edits, 0);
@@ -259,3 +307,163 @@ async fn test_self_healing_scenarios() -> Result<()> {
     println!("Self-healing scenarios tests passed!");
     Ok(())
 }
+
+/// Test fuzzy matching: exact, then substrings by position, then spread-out characters
+#[test]
+fn test_fuzzy_score() {
+    assert_eq!(fuzzy_score("sony a7", "Sony A7"), Some(0));
+    assert_eq!(fuzzy_score("", "Tripod"), Some(0));
+    assert_eq!(fuzzy_score("sony", "Sony A7"), Some(1));
+    assert_eq!(fuzzy_score("a7", "Sony A7"), Some(6));
+    assert!(fuzzy_score("sna7", "Sony A7").unwrap() > fuzzy_score("a7", "Sony A7").unwrap());
+    assert!(fuzzy_score("s a7", "Sony A7").is_some());
+    assert_eq!(fuzzy_score("7a", "Sony A7"), None);
+    assert_eq!(fuzzy_score("drone", "Sony A7"), None);
+}
+
+/// Test /equipment search across names, tags and locations, and the detail card data
+#[test]
+fn test_reservation_list_field() {
+    assert_eq!(tagged_name("PC-A", Some("PC")), "[PC] PC-A");
+    assert_eq!(tagged_name("PC-A", None), "PC-A");
+
+    let lines: Vec<String> = (1..=3).map(|n| format!("• reservation {}", n)).collect();
+    assert_eq!(
+        reservation_list_field(&lines, 3, Language::En),
+        "• reservation 1\n• reservation 2\n• reservation 3"
+    );
+    let value = reservation_list_field(&lines, 7, Language::En);
+    assert!(value.starts_with("• reservation 1\n• reservation 2\n• reservation 3\n"));
+    assert!(value.ends_with("+4 more (📋 All Reservations below)"));
+
+    // Lines past the field limit are folded into the overflow count
+    let long: Vec<String> = (0..10)
+        .map(|n| format!("{}{}", n, "x".repeat(199)))
+        .collect();
+    let value = reservation_list_field(&long, 12, Language::En);
+    assert!(value.chars().count() <= 1024);
+    assert_eq!(value.lines().count(), 5);
+    assert!(value.ends_with("+8 more (📋 All Reservations below)"));
+}
+
+#[tokio::test]
+async fn test_equipment_search_and_detail() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let renderer = EquipmentRenderer::new(ctx.db.clone());
+    let now = ctx.clock.now_utc();
+
+    let audio = common::TagBuilder::new(guild.id, "Audio")
+        .with_sort_order(2)
+        .build(&ctx.db)
+        .await?;
+    common::EquipmentBuilder::new(guild.id, "Sony ECM Mic")
+        .with_tag(audio.id)
+        .build(&ctx.db)
+        .await?;
+    common::EquipmentBuilder::new(guild.id, "Tripod")
+        .with_tag(tag.id)
+        .with_default_return_location("Storage Room")
+        .build(&ctx.db)
+        .await?;
+
+    let names = |matches: Vec<(Equipment, Option<Tag>)>| {
+        matches.into_iter().map(|(e, _)| e.name).collect::<Vec<_>>()
+    };
+    // Name matches first, then tag matches
+    assert_eq!(
+        names(
+            renderer
+                .fuzzy_search_equipment(guild.id, "sony", 25)
+                .await?
+        ),
+        vec!["Sony A7", "Sony ECM Mic"]
+    );
+    assert_eq!(
+        names(
+            renderer
+                .fuzzy_search_equipment(guild.id, "camera", 25)
+                .await?
+        ),
+        vec!["Sony A7", "Tripod"]
+    );
+    assert_eq!(
+        names(
+            renderer
+                .fuzzy_search_equipment(guild.id, "storage", 25)
+                .await?
+        ),
+        vec!["Tripod"]
+    );
+    assert_eq!(
+        names(renderer.fuzzy_search_equipment(guild.id, "", 2).await?).len(),
+        2
+    );
+    assert!(renderer
+        .fuzzy_search_equipment(guild.id, "drone", 25)
+        .await?
+        .is_empty());
+
+    // Ended, returned and cancelled reservations are left out
+    for hours in [1, 3, 5, 7, 9, 11] {
+        common::ReservationBuilder::new(
+            equipment.id,
+            111,
+            now + Duration::hours(hours),
+            now + Duration::hours(hours + 1),
+        )
+        .build(&ctx.db)
+        .await?;
+    }
+    common::ReservationBuilder::new(
+        equipment.id,
+        222,
+        now - Duration::hours(3),
+        now - Duration::hours(2),
+    )
+    .build(&ctx.db)
+    .await?;
+    common::ReservationBuilder::new(equipment.id, 333, now, now + Duration::minutes(30))
+        .with_status("Cancelled")
+        .build(&ctx.db)
+        .await?;
+    let upcoming = renderer
+        .upcoming_reservations(equipment.id, now, 0, 5)
+        .await?;
+    assert_eq!(upcoming.len(), 5);
+    assert!(upcoming.iter().all(|r| r.user_id == 111));
+    assert!(upcoming
+        .windows(2)
+        .all(|w| w[0].start_time < w[1].start_time));
+    assert_eq!(
+        renderer
+            .count_upcoming_reservations(equipment.id, now)
+            .await?,
+        6
+    );
+    let last_page = renderer
+        .upcoming_reservations(equipment.id, now, 5, 5)
+        .await?;
+    assert_eq!(last_page.len(), 1);
+    assert_eq!(last_page[0].start_time, now + Duration::hours(11));
+
+    for (minutes, action) in [(30, "Reserved"), (20, "Loaned"), (10, "Returned")] {
+        let timestamp = now - Duration::minutes(minutes);
+        sqlx::query!(
+            "INSERT INTO equipment_logs (equipment_id, user_id, action, timestamp)
+             VALUES (?, 111, ?, ?)",
+            equipment.id,
+            action,
+            timestamp
+        )
+        .execute(&ctx.db)
+        .await?;
+    }
+    let logs = renderer.recent_logs(equipment.id, 2).await?;
+    assert_eq!(
+        logs.iter().map(|l| l.action.as_str()).collect::<Vec<_>>(),
+        vec!["Returned", "Loaned"]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anyhow::Result;
+use oucc_kizai_bot::i18n::*;
+use std::collections::BTreeSet;
+
+mod common;
+
+fn placeholders(message: &str) -> BTreeSet<&str> {
+    message
+        .split('{')
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+        .collect()
+}
+
+/// Test that both bundles define the same keys in the same order
+#[test]
+fn test_bundles_have_same_keys() {
+    let ja = bundle_keys(Language::Ja);
+    let en = bundle_keys(Language::En);
+    assert!(!ja.is_empty());
+    assert_eq!(ja, en);
+
+    let unique: BTreeSet<_> = en.iter().collect();
+    assert_eq!(unique.len(), en.len(), "duplicate catalog keys");
+}
+
+/// Test that every translation uses the same placeholders as its English source
+#[test]
+fn test_bundles_have_same_placeholders() {
+    for key in bundle_keys(Language::En) {
+        assert_eq!(
+            placeholders(t(Language::Ja, key)),
+            placeholders(t(Language::En, key)),
+            "placeholder mismatch for {}",
+            key
+        );
+    }
+}
+
+/// Test lookup, placeholder substitution and unknown keys
+#[test]
+fn test_lookup_and_format() {
+    assert_eq!(t(Language::En, "no.such.key"), "no.such.key");
+    assert_eq!(
+        tf(Language::En, "common.error_prefix", &[("message", &"boom")]),
+        "❌ boom"
+    );
+    assert_eq!(status_label(Language::En, "Retired"), "Retired");
+    assert_ne!(
+        status_label(Language::Ja, "Available"),
+        status_label(Language::En, "Available")
+    );
+}
+
+/// Test parsing stored and Discord locale codes
+#[test]
+fn test_language_codes() {
+    assert_eq!(Language::from_code("en-US"), Some(Language::En));
+    assert_eq!(Language::from_code("ja"), Some(Language::Ja));
+    assert_eq!(Language::from_code("fr"), None);
+    assert_eq!(language_or_default(None), DEFAULT_LANGUAGE);
+    assert_eq!(language_or_default(Some("fr")), DEFAULT_LANGUAGE);
+    for lang in Language::ALL {
+        assert_eq!(Language::from_code(lang.code()), Some(lang));
+    }
+}
+
+/// Test that a user preference overrides the guild default and can be cleared
+#[tokio::test]
+async fn test_user_language_resolution() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, _equipment) = common::create_test_setup(&ctx).await?;
+    let user_id = 12345;
+
+    assert_eq!(
+        user_language(&ctx.db, Some(guild.id), user_id).await?,
+        Language::Ja
+    );
+    assert_eq!(
+        user_language(&ctx.db, None, user_id).await?,
+        DEFAULT_LANGUAGE
+    );
+
+    set_user_language(&ctx.db, guild.id, user_id, Some(Language::En)).await?;
+    assert_eq!(
+        user_preference(&ctx.db, guild.id, user_id).await?,
+        Some(Language::En)
+    );
+    assert_eq!(
+        user_language(&ctx.db, Some(guild.id), user_id).await?,
+        Language::En
+    );
+
+    // The preference only applies to the guild it was set in
+    let other_guild = common::GuildBuilder::new(guild.id + 1)
+        .build(&ctx.db)
+        .await?;
+    assert_eq!(
+        user_language(&ctx.db, Some(other_guild.id), user_id).await?,
+        Language::Ja
+    );
+
+    sqlx::query!("UPDATE guilds SET language = 'en' WHERE id = ?", guild.id)
+        .execute(&ctx.db)
+        .await?;
+    set_user_language(&ctx.db, guild.id, user_id, Some(Language::Ja)).await?;
+    assert_eq!(
+        user_language(&ctx.db, Some(guild.id), user_id).await?,
+        Language::Ja
+    );
+
+    set_user_language(&ctx.db, guild.id, user_id, None).await?;
+    assert_eq!(user_preference(&ctx.db, guild.id, user_id).await?, None);
+    assert_eq!(
+        user_language(&ctx.db, Some(guild.id), user_id).await?,
+        Language::En
+    );
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 use chrono::Utc;
 use oucc_kizai_bot::equipment::EquipmentRenderer;
@@ -48,10 +50,12 @@ async fn test_reconcile_integration() -> Result<()> {
     assert_eq!(equipment_list.len(), 1);
     assert_eq!(equipment_list[0].0.name, "Test Equipment");
     assert_eq!(equipment_list[0].1.as_ref().unwrap().name, "Test Tag");
+    let content_hashes = HashMap::from([(1, "hash-1".to_string())]);
 
     // Test 2: Verify edit plan computation for fresh start
     let existing_messages = vec![];
-    let edit_plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list);
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
     // Should create header + 1 equipment message
     assert_eq!(edit_plan.creates, 2);
@@ -69,6 +73,7 @@ async fn test_reconcile_integration() -> Result<()> {
             equipment_id: None,
             sort_order: Some(0),
             created_at: Utc::now(),
+            content_hash: None,
         },
         ManagedMessage {
             id: 2,
@@ -79,10 +84,12 @@ async fn test_reconcile_integration() -> Result<()> {
             equipment_id: Some(1),
             sort_order: Some(1),
             created_at: Utc::now(),
+            content_hash: Some("hash-1".to_string()),
         },
     ];
 
-    let edit_plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list);
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
     // Should do nothing since everything matches
     assert_eq!(edit_plan.creates, 0);
@@ -128,6 +135,8 @@ async fn test_equipment_reordering() -> Result<()> {
                 guild_id: 123,
                 name: "Beta Tag".to_string(),
                 sort_order: 1, // Lower sort order = first
+                allowed_roles: None,
+                manager_roles: None,
                 created_at: Utc::now(),
             }),
         ),
@@ -150,11 +159,15 @@ async fn test_equipment_reordering() -> Result<()> {
                 guild_id: 123,
                 name: "Alpha Tag".to_string(),
                 sort_order: 2, // Higher sort order = second
+                allowed_roles: None,
+                manager_roles: None,
                 created_at: Utc::now(),
             }),
         ),
     ];
 
+    let content_hashes = HashMap::from([(1, "hash-1".to_string()), (2, "hash-2".to_string())]);
+
     // Existing messages in wrong order (Alpha first, Beta second)
     let existing_messages = vec![
         ManagedMessage {
@@ -166,6 +179,7 @@ async fn test_equipment_reordering() -> Result<()> {
             equipment_id: None,
             sort_order: Some(0),
             created_at: Utc::now(),
+            content_hash: None,
         },
         ManagedMessage {
             id: 2,
@@ -176,6 +190,7 @@ async fn test_equipment_reordering() -> Result<()> {
             equipment_id: Some(1), // Alpha equipment in position 1
             sort_order: Some(1),
             created_at: Utc::now(),
+            content_hash: Some("hash-1".to_string()),
         },
         ManagedMessage {
             id: 3,
@@ -186,10 +201,12 @@ async fn test_equipment_reordering() -> Result<()> {
             equipment_id: Some(2), // Beta equipment in position 2
             sort_order: Some(2),
             created_at: Utc::now(),
+            content_hash: Some("hash-2".to_string()),
         },
     ];
 
-    let edit_plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list);
+    let edit_plan =
+        EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &content_hashes);
 
     // Should edit both messages since equipment order changed
     // (Beta should be first now due to lower tag sort_order)
@@ -7,13 +7,13 @@ fn test_jst_conversion() {
         .unwrap()
         .with_timezone(&Utc);
 
-    let jst_string = utc_to_jst_string(utc_time);
+    let jst_string = utc_to_local_string(utc_time, DEFAULT_TIME_ZONE);
     assert_eq!(jst_string, "2024/01/01 09:00");
 }
 
 #[test]
 fn test_jst_to_utc_conversion() {
-    let utc_result = jst_to_utc(2024, 1, 1, 9, 0);
+    let utc_result = local_ymd_hm_to_utc(2024, 1, 1, 9, 0, DEFAULT_TIME_ZONE);
     assert!(utc_result.is_some());
 
     let utc_time = utc_result.unwrap();
@@ -22,12 +22,12 @@ fn test_jst_to_utc_conversion() {
 }
 
 #[test]
-fn test_is_past_jst() {
+fn test_is_past() {
     let past_time = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
         .unwrap()
         .with_timezone(&Utc);
 
-    assert!(is_past_jst(past_time));
+    assert!(is_past(past_time));
 }
 
 #[test]
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use chrono::{DateTime, Duration, Utc};
+use oucc_kizai_bot::time::{utc_to_local_string, DEFAULT_TIME_ZONE};
 use oucc_kizai_bot::{models::*, traits::*};
 use serenity::model::prelude::*;
 use std::sync::Arc;
@@ -115,12 +116,12 @@ impl TestJobWorker {
             "pre_end" => format!(
                 "📅 リマインダー: 「{}」の貸出期限まで15分です。\n返却時刻: {}",
                 equipment.name,
-                oucc_kizai_bot::time::utc_to_jst_string(reservation.end_time)
+                utc_to_local_string(reservation.end_time, DEFAULT_TIME_ZONE)
             ),
             "return_delay" => format!(
                 "⚠️ 返却遅延: 「{}」の返却期限が過ぎています。\n期限: {}",
                 equipment.name,
-                oucc_kizai_bot::time::utc_to_jst_string(reservation.end_time)
+                utc_to_local_string(reservation.end_time, DEFAULT_TIME_ZONE)
             ),
             _ => return Err(anyhow::anyhow!("Unknown reminder type: {}", reminder_type)),
         };
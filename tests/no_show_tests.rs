@@ -0,0 +1,269 @@
+use anyhow::Result;
+use chrono::Duration;
+use oucc_kizai_bot::jobs::JobWorker;
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+/// Test no-show check scheduling uses the guild grace period
+#[tokio::test]
+async fn test_no_show_check_scheduling() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+
+    let user_id = 12345i64;
+    let reservation_start = ctx.clock.now_utc() + Duration::hours(1);
+    let reservation_end = reservation_start + Duration::hours(2);
+
+    sqlx::query!(
+        "UPDATE guilds SET no_show_grace_minutes = 30 WHERE id = ?",
+        guild.id
+    )
+    .execute(&ctx.db)
+    .await?;
+
+    let reservation =
+        common::ReservationBuilder::new(equipment.id, user_id, reservation_start, reservation_end)
+            .build(&ctx.db)
+            .await?;
+
+    let grace_minutes = JobWorker::get_no_show_grace_minutes(&ctx.db, guild.id).await?;
+    assert_eq!(grace_minutes, 30);
+
+    JobWorker::schedule_no_show_check(&ctx.db, reservation.id, reservation_start, grace_minutes)
+        .await?;
+
+    let jobs =
+        sqlx::query!("SELECT payload, scheduled_for FROM jobs WHERE job_type = 'no_show_check'")
+            .fetch_all(&ctx.db)
+            .await?;
+
+    assert_eq!(jobs.len(), 1);
+    assert!(jobs[0]
+        .payload
+        .contains(&format!("\"reservation_id\":{}", reservation.id)));
+    assert_eq!(
+        jobs[0].scheduled_for.and_utc().timestamp(),
+        (reservation_start + Duration::minutes(30)).timestamp()
+    );
+
+    Ok(())
+}
+
+/// Test that no-show detection stays off until a grace period is set
+#[tokio::test]
+async fn test_no_show_check_disabled() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+
+    let reservation_start = ctx.clock.now_utc() + Duration::hours(1);
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        12345,
+        reservation_start,
+        reservation_start + Duration::hours(2),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let grace_minutes = JobWorker::get_no_show_grace_minutes(&ctx.db, guild.id).await?;
+    assert_eq!(grace_minutes, 0);
+    JobWorker::schedule_no_show_check(&ctx.db, reservation.id, reservation_start, grace_minutes)
+        .await?;
+
+    let job_count =
+        sqlx::query!("SELECT COUNT(*) as count FROM jobs WHERE job_type = 'no_show_check'")
+            .fetch_one(&ctx.db)
+            .await?
+            .count;
+
+    assert_eq!(job_count, 0);
+
+    Ok(())
+}
+
+/// Test that enabling a grace period schedules checks for reservations booked while it was off
+#[tokio::test]
+async fn test_enabling_grace_period_reschedules_checks() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+
+    let now = ctx.clock.now_utc();
+    let upcoming_start = now + Duration::hours(1);
+    let upcoming = common::ReservationBuilder::new(
+        equipment.id,
+        12345,
+        upcoming_start,
+        upcoming_start + Duration::hours(2),
+    )
+    .build(&ctx.db)
+    .await?;
+    // Already running reservations are not released retroactively
+    common::ReservationBuilder::new(
+        equipment.id,
+        67890,
+        now - Duration::hours(1),
+        now - Duration::minutes(30),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    sqlx::query!(
+        "UPDATE guilds SET no_show_grace_minutes = 15 WHERE id = ?",
+        guild.id
+    )
+    .execute(&ctx.db)
+    .await?;
+    let scheduled = JobWorker::reschedule_no_show_checks(&ctx.db, guild.id, 15).await?;
+    assert_eq!(scheduled, 1);
+
+    // Changing the grace period again replaces the pending check instead of adding one
+    let scheduled = JobWorker::reschedule_no_show_checks(&ctx.db, guild.id, 30).await?;
+    assert_eq!(scheduled, 1);
+
+    let jobs =
+        sqlx::query!("SELECT payload, scheduled_for FROM jobs WHERE job_type = 'no_show_check'")
+            .fetch_all(&ctx.db)
+            .await?;
+    assert_eq!(jobs.len(), 1);
+    assert!(jobs[0]
+        .payload
+        .contains(&format!("\"reservation_id\":{}", upcoming.id)));
+    assert_eq!(
+        jobs[0].scheduled_for.and_utc().timestamp(),
+        (upcoming_start + Duration::minutes(30)).timestamp()
+    );
+
+    // Turning detection off clears the pending checks
+    let scheduled = JobWorker::reschedule_no_show_checks(&ctx.db, guild.id, 0).await?;
+    assert_eq!(scheduled, 0);
+    let job_count =
+        sqlx::query!("SELECT COUNT(*) as count FROM jobs WHERE job_type = 'no_show_check'")
+            .fetch_one(&ctx.db)
+            .await?
+            .count;
+    assert_eq!(job_count, 0);
+
+    Ok(())
+}
+
+/// Test releasing an unclaimed reservation
+#[tokio::test]
+async fn test_release_no_show_reservation() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+
+    let user_id = 12345i64;
+    let reservation_start = ctx.clock.now_utc() - Duration::hours(1);
+    let reservation_end = reservation_start + Duration::hours(3);
+
+    let reservation =
+        common::ReservationBuilder::new(equipment.id, user_id, reservation_start, reservation_end)
+            .build(&ctx.db)
+            .await?;
+
+    JobWorker::schedule_reservation_reminders(
+        &ctx.db,
+        reservation.id,
+        reservation_start,
+        reservation_end,
+        guild.id,
+    )
+    .await?;
+
+    assert!(JobWorker::release_no_show_reservation(&ctx.db, reservation.id).await?);
+
+    // Reservation is released
+    let status = sqlx::query_scalar!(
+        "SELECT status FROM reservations WHERE id = ?",
+        reservation.id
+    )
+    .fetch_one(&ctx.db)
+    .await?;
+    assert_eq!(status, "NoShow");
+
+    // Owner's no-show count is incremented
+    let count = sqlx::query_scalar!(
+        "SELECT no_show_count FROM user_no_show_counts WHERE guild_id = ? AND user_id = ?",
+        guild.id,
+        user_id
+    )
+    .fetch_one(&ctx.db)
+    .await?;
+    assert_eq!(count, 1);
+
+    // Release is logged
+    let log_count = sqlx::query!(
+        "SELECT COUNT(*) as count FROM equipment_logs WHERE equipment_id = ? AND action = 'NoShow'",
+        equipment.id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert_eq!(log_count, 1);
+
+    // Remaining reminders are cancelled
+    let pending_reminders = sqlx::query!(
+        "SELECT COUNT(*) as count FROM jobs WHERE job_type = 'reminder' AND status = 'Pending'
+         AND JSON_EXTRACT(payload, '$.reservation_id') = ?",
+        reservation.id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert_eq!(pending_reminders, 0);
+
+    // Releasing again is a no-op
+    assert!(!JobWorker::release_no_show_reservation(&ctx.db, reservation.id).await?);
+
+    Ok(())
+}
+
+/// Test that picked-up reservations are never released
+#[tokio::test]
+async fn test_picked_up_reservation_not_released() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+
+    let user_id = 12345i64;
+    let reservation_start = ctx.clock.now_utc() - Duration::hours(1);
+
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        reservation_start,
+        reservation_start + Duration::hours(3),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let picked_up_at = reservation_start + Duration::minutes(5);
+    sqlx::query!(
+        "UPDATE reservations SET picked_up_at = ? WHERE id = ?",
+        picked_up_at,
+        reservation.id
+    )
+    .execute(&ctx.db)
+    .await?;
+
+    assert!(!JobWorker::release_no_show_reservation(&ctx.db, reservation.id).await?);
+
+    let status = sqlx::query_scalar!(
+        "SELECT status FROM reservations WHERE id = ?",
+        reservation.id
+    )
+    .fetch_one(&ctx.db)
+    .await?;
+    assert_eq!(status, "Confirmed");
+
+    let count = sqlx::query!(
+        "SELECT COUNT(*) as count FROM user_no_show_counts WHERE guild_id = ?",
+        guild.id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert_eq!(count, 0);
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+use anyhow::Result;
+use chrono::{NaiveDate, Weekday};
+use oucc_kizai_bot::i18n::Language;
+use oucc_kizai_bot::operating_hours::{
+    format_weekly_hours, parse_closed_dates, parse_ics_closed_dates, parse_weekly_hours,
+    ClosedDate, InputProblem, OperatingHoursService, OperatingSchedule, ScheduleViolation,
+};
+use oucc_kizai_bot::time::{local_ymd_hm_to_utc, DEFAULT_TIME_ZONE};
+
+mod common;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// Test parsing weekly hours with day ranges, split windows and closed days
+#[test]
+fn test_parse_weekly_hours() {
+    let hours = parse_weekly_hours("Mon-Fri 09:00-12:00, 13:00-21:00\nsat 10:00-17:00\nSun closed")
+        .unwrap();
+    assert_eq!(hours.len(), 11);
+    assert!(hours.iter().all(|h| h.weekday != Weekday::Sun));
+
+    // Formatting round-trips through the parser
+    let formatted = format_weekly_hours(&hours);
+    assert!(formatted.contains("Wed 09:00-12:00, 13:00-21:00"));
+    assert!(formatted.contains("Sun closed"));
+    assert_eq!(parse_weekly_hours(&formatted).unwrap().len(), hours.len());
+
+    assert!(parse_weekly_hours("").unwrap().is_empty());
+    assert!(parse_weekly_hours("Mon 21:00-09:00").is_err());
+    assert!(parse_weekly_hours("Fri-Mon 09:00-21:00").is_err());
+    assert!(parse_weekly_hours("Someday 09:00-21:00").is_err());
+    assert!(parse_weekly_hours("Mon 09:00-24:30").is_err());
+}
+
+/// Test parsing closed dates and date ranges
+#[test]
+fn test_parse_closed_dates() {
+    let dates =
+        parse_closed_dates("2025-01-01 New Year's Day\n2024/12/28..2024-12-30 Winter break\n")
+            .unwrap();
+    assert_eq!(dates.len(), 4);
+    assert_eq!(dates[0].date, date(2025, 1, 1));
+    assert_eq!(dates[0].reason.as_deref(), Some("New Year's Day"));
+    assert_eq!(dates[3].date, date(2024, 12, 30));
+    assert_eq!(dates[3].reason.as_deref(), Some("Winter break"));
+
+    assert!(parse_closed_dates("2025-13-01").is_err());
+    assert!(parse_closed_dates("2025-01-05..2025-01-01").is_err());
+}
+
+/// Test that input errors point at the line and read in the admin's language
+#[test]
+fn test_input_error_message() {
+    let error = parse_weekly_hours("Mon 09:00-21:00\nSomeday 09:00-21:00").unwrap_err();
+    assert_eq!(error.line, 2);
+    assert_eq!(error.problem, InputProblem::UnknownWeekday);
+    assert_eq!(
+        error.message(Language::En),
+        "Line 2: unknown weekday (\"Someday 09:00-21:00\")"
+    );
+    assert!(error.message(Language::Ja).starts_with("2 行目"));
+
+    let error = parse_closed_dates("2025-01-01..2026-06-01").unwrap_err();
+    assert_eq!(error.problem, InputProblem::DateRange);
+    assert!(error.message(Language::En).contains("366 days"));
+}
+
+/// Test that ending at midnight counts as 24:00 of the previous day
+#[test]
+fn test_end_at_midnight() {
+    // 2025-01-06 is a Monday
+    let at = |day: u32, hour: u32, minute: u32| {
+        local_ymd_hm_to_utc(2025, 1, day, hour, minute, DEFAULT_TIME_ZONE).unwrap()
+    };
+    let mut schedule = OperatingSchedule {
+        weekly_hours: parse_weekly_hours("Mon 18:00-24:00\nTue 09:00-17:00").unwrap(),
+        closed_dates: parse_closed_dates("2025-01-07 Exams").unwrap(),
+        time_zone: DEFAULT_TIME_ZONE,
+    };
+
+    // The next day being closed does not matter
+    assert!(schedule
+        .check_reservation(at(6, 20, 0), at(7, 0, 0))
+        .is_ok());
+    // Midnight after a day that closes earlier is outside the hours
+    assert!(matches!(
+        schedule.check_reservation(at(8, 10, 0), at(9, 0, 0)),
+        Err(ScheduleViolation::ClosedWeekday { .. })
+    ));
+
+    // Without weekly hours only the previous day's closed date matters
+    schedule.weekly_hours.clear();
+    assert!(schedule
+        .check_reservation(at(6, 20, 0), at(7, 0, 0))
+        .is_ok());
+    assert!(matches!(
+        schedule.check_reservation(at(5, 20, 0), at(8, 0, 0)),
+        Err(ScheduleViolation::ClosedDate { .. })
+    ));
+}
+
+/// Test closed dates from an ICS calendar with all-day and timed events
+#[test]
+fn test_parse_ics_closed_dates() {
+    let ics = "BEGIN:VCALENDAR\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART;VALUE=DATE:20250101\r\n\
+DTEND;VALUE=DATE:20250104\r\n\
+SUMMARY:Winter\r\n  holidays\r\n\
+END:VEVENT\r\n\
+BEGIN:VEVENT\r\n\
+DTSTART:20250211T000000Z\r\n\
+SUMMARY:Foundation Day\\, closed\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n";
+
+    let dates = parse_ics_closed_dates(ics, DEFAULT_TIME_ZONE);
+    assert_eq!(
+        dates,
+        vec![
+            ClosedDate {
+                date: date(2025, 1, 1),
+                reason: Some("Winter holidays".to_string())
+            },
+            ClosedDate {
+                date: date(2025, 1, 2),
+                reason: Some("Winter holidays".to_string())
+            },
+            ClosedDate {
+                date: date(2025, 1, 3),
+                reason: Some("Winter holidays".to_string())
+            },
+            ClosedDate {
+                date: date(2025, 2, 11),
+                reason: Some("Foundation Day, closed".to_string())
+            },
+        ]
+    );
+}
+
+/// Test that reservations must start and end within opening hours and not on closed dates
+#[tokio::test]
+async fn test_check_reservation() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, _equipment) = common::create_test_setup(&ctx).await?;
+    let service = OperatingHoursService::new(ctx.db.clone());
+
+    // 2025-01-06 is a Monday
+    let at = |day: u32, hour: u32, minute: u32| {
+        local_ymd_hm_to_utc(2025, 1, day, hour, minute, DEFAULT_TIME_ZONE).unwrap()
+    };
+
+    // No hours configured: always open
+    assert!(service
+        .check_reservation(guild.id, at(6, 3, 0), at(6, 4, 0))
+        .await?
+        .is_none());
+
+    service
+        .set_weekly_hours(
+            guild.id,
+            &parse_weekly_hours("Mon-Fri 09:00-21:00").unwrap(),
+        )
+        .await?;
+    service
+        .add_closed_dates(guild.id, &parse_closed_dates("2025-01-08 Exams").unwrap())
+        .await?;
+
+    assert!(service
+        .check_reservation(guild.id, at(6, 9, 0), at(6, 21, 0))
+        .await?
+        .is_none());
+    // Multi-day loans only need open start and end times
+    assert!(service
+        .check_reservation(guild.id, at(6, 18, 0), at(7, 10, 0))
+        .await?
+        .is_none());
+
+    assert!(matches!(
+        service
+            .check_reservation(guild.id, at(6, 3, 0), at(6, 10, 0))
+            .await?,
+        Some(ScheduleViolation::OutsideHours { .. })
+    ));
+    // Starting at closing time is not allowed
+    assert!(service
+        .check_reservation(guild.id, at(6, 21, 0), at(7, 10, 0))
+        .await?
+        .is_some());
+    assert_eq!(
+        service
+            .check_reservation(guild.id, at(7, 10, 0), at(8, 10, 0))
+            .await?,
+        Some(ScheduleViolation::ClosedDate {
+            date: date(2025, 1, 8),
+            reason: Some("Exams".to_string())
+        })
+    );
+    assert_eq!(
+        service
+            .check_reservation(guild.id, at(11, 10, 0), at(11, 12, 0))
+            .await?,
+        Some(ScheduleViolation::ClosedWeekday {
+            weekday: Weekday::Sat
+        })
+    );
+
+    // Reopening a date
+    service
+        .remove_closed_dates(guild.id, &[date(2025, 1, 8)])
+        .await?;
+    assert!(service
+        .check_reservation(guild.id, at(7, 10, 0), at(8, 10, 0))
+        .await?
+        .is_none());
+
+    Ok(())
+}
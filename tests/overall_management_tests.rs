@@ -1,5 +1,6 @@
 use anyhow::Result;
 use oucc_kizai_bot::handlers::{Handler, LogTimeFilter, LogViewerState, StatusFilter, TimeFilter};
+use oucc_kizai_bot::i18n::Language;
 use oucc_kizai_bot::models::Reservation;
 
 mod common;
@@ -159,7 +160,7 @@ async fn test_reservation_status_display() -> Result<()> {
         return_location: None,
     };
 
-    let status = handler.get_reservation_display_status(&upcoming).await;
+    let status = handler.get_reservation_display_status(&upcoming, Language::En).await;
     assert_eq!(
         status, "Upcoming",
         "Should show Upcoming for future reservation"
@@ -180,7 +181,7 @@ async fn test_reservation_status_display() -> Result<()> {
         return_location: None,
     };
 
-    let status = handler.get_reservation_display_status(&returned).await;
+    let status = handler.get_reservation_display_status(&returned, Language::En).await;
     assert_eq!(
         status, "Returned",
         "Should show Returned when returned_at is set"
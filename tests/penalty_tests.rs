@@ -0,0 +1,272 @@
+use anyhow::Result;
+use chrono::Duration;
+use oucc_kizai_bot::penalties::{late_return_points, PenaltyPolicy, PenaltyService};
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+/// Test point calculation for late returns
+#[tokio::test]
+async fn test_late_return_points() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let end_time = ctx.clock.now_utc();
+
+    assert_eq!(
+        late_return_points(end_time, end_time - Duration::minutes(5)),
+        0
+    );
+    assert_eq!(late_return_points(end_time, end_time), 0);
+    assert_eq!(
+        late_return_points(end_time, end_time + Duration::minutes(1)),
+        1
+    );
+    assert_eq!(
+        late_return_points(end_time, end_time + Duration::minutes(60)),
+        1
+    );
+    assert_eq!(
+        late_return_points(end_time, end_time + Duration::minutes(61)),
+        2
+    );
+    assert_eq!(
+        late_return_points(end_time, end_time + Duration::hours(5)),
+        5
+    );
+
+    Ok(())
+}
+
+/// Test that late returns accumulate points without suspending below the threshold
+#[tokio::test]
+async fn test_record_late_return_accumulates_points() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = PenaltyService::new(ctx.db.clone());
+
+    // Suspensions stay off until an admin sets a policy
+    assert!(!service.get_policy(guild.id).await?.suspends());
+
+    let user_id = 12345i64;
+    let end_time = ctx.clock.now_utc() - Duration::hours(3);
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        end_time - Duration::hours(2),
+        end_time,
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let outcome = service
+        .record_late_return(
+            guild.id,
+            user_id,
+            equipment.id,
+            reservation.id,
+            end_time,
+            end_time + Duration::minutes(90),
+        )
+        .await?;
+
+    assert_eq!(outcome.points_added, 2);
+    assert_eq!(outcome.total_points, 2);
+    assert!(outcome.suspended_until.is_none());
+    assert!(service
+        .active_suspension(guild.id, user_id)
+        .await?
+        .is_none());
+
+    // On-time returns add nothing
+    let outcome = service
+        .record_late_return(
+            guild.id,
+            user_id,
+            equipment.id,
+            reservation.id,
+            end_time,
+            end_time,
+        )
+        .await?;
+    assert_eq!(outcome.points_added, 0);
+    assert_eq!(outcome.total_points, 2);
+
+    let events = sqlx::query!(
+        "SELECT COUNT(*) as count FROM penalty_events WHERE guild_id = ? AND user_id = ?",
+        guild.id,
+        user_id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert_eq!(events, 1);
+
+    Ok(())
+}
+
+/// Test that crossing the threshold suspends the member and logs it
+#[tokio::test]
+async fn test_threshold_triggers_suspension() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = PenaltyService::new(ctx.db.clone());
+
+    service
+        .set_policy(
+            guild.id,
+            PenaltyPolicy {
+                threshold_points: 3,
+                suspension_days: 2,
+            },
+        )
+        .await?;
+
+    let user_id = 12345i64;
+    let end_time = ctx.clock.now_utc() - Duration::hours(5);
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        end_time - Duration::hours(1),
+        end_time,
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let outcome = service
+        .record_late_return(
+            guild.id,
+            user_id,
+            equipment.id,
+            reservation.id,
+            end_time,
+            end_time + Duration::hours(4),
+        )
+        .await?;
+
+    let until = outcome.suspended_until.expect("member should be suspended");
+    assert!(until > ctx.clock.now_utc() + Duration::days(1));
+    assert_eq!(outcome.total_points, 0);
+    assert_eq!(
+        service.active_suspension(guild.id, user_id).await?,
+        Some(until)
+    );
+
+    let log_count = sqlx::query!(
+        "SELECT COUNT(*) as count FROM equipment_logs WHERE equipment_id = ? AND action = 'Suspended'",
+        equipment.id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert_eq!(log_count, 1);
+
+    Ok(())
+}
+
+/// Test that an admin adjustment reaching the threshold suspends the member too
+#[tokio::test]
+async fn test_adjustment_triggers_suspension() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, _equipment) = common::create_test_setup(&ctx).await?;
+    let service = PenaltyService::new(ctx.db.clone());
+
+    service
+        .set_policy(
+            guild.id,
+            PenaltyPolicy {
+                threshold_points: 3,
+                suspension_days: 2,
+            },
+        )
+        .await?;
+
+    let user_id = 12345i64;
+    let outcome = service
+        .adjust_points(guild.id, user_id, 5, 99999, None)
+        .await?;
+
+    let until = outcome.suspended_until.expect("member should be suspended");
+    assert_eq!(outcome.total_points, 0);
+    assert_eq!(
+        service.active_suspension(guild.id, user_id).await?,
+        Some(until)
+    );
+
+    // Already suspended members are not suspended again
+    let outcome = service
+        .adjust_points(guild.id, user_id, 5, 99999, None)
+        .await?;
+    assert!(outcome.suspended_until.is_none());
+    assert_eq!(outcome.total_points, 5);
+
+    Ok(())
+}
+
+/// Test admin adjustments and forgiveness
+#[tokio::test]
+async fn test_adjust_and_forgive() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, _equipment) = common::create_test_setup(&ctx).await?;
+    let service = PenaltyService::new(ctx.db.clone());
+
+    let user_id = 12345i64;
+    let admin_id = 99999i64;
+
+    let outcome = service
+        .adjust_points(
+            guild.id,
+            user_id,
+            4,
+            admin_id,
+            Some("Manual entry".to_string()),
+        )
+        .await?;
+    assert_eq!(outcome.total_points, 4);
+    assert!(outcome.suspended_until.is_none());
+
+    // Points never go below zero
+    let outcome = service
+        .adjust_points(guild.id, user_id, -10, admin_id, None)
+        .await?;
+    assert_eq!(outcome.points_added, -4);
+    assert_eq!(outcome.total_points, 0);
+
+    service
+        .adjust_points(guild.id, user_id, 3, admin_id, None)
+        .await?;
+    sqlx::query!(
+        "UPDATE user_penalties SET suspended_until = datetime('now', '+3 days')
+         WHERE guild_id = ? AND user_id = ?",
+        guild.id,
+        user_id
+    )
+    .execute(&ctx.db)
+    .await?;
+    assert!(service
+        .active_suspension(guild.id, user_id)
+        .await?
+        .is_some());
+
+    service.forgive(guild.id, user_id, admin_id, None).await?;
+
+    let penalty = service.get_user_penalty(guild.id, user_id).await?;
+    assert_eq!(penalty.points, 0);
+    assert!(penalty.suspended_until.is_none());
+    assert!(service
+        .list_penalized_members(guild.id, 20)
+        .await?
+        .is_empty());
+
+    let audited = sqlx::query!(
+        "SELECT COUNT(*) as count FROM penalty_events
+         WHERE guild_id = ? AND user_id = ? AND actor_user_id = ?",
+        guild.id,
+        user_id,
+        admin_id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert_eq!(audited, 4);
+
+    Ok(())
+}
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use chrono::Utc;
+use oucc_kizai_bot::equipment::{content_hash, EditAction, EquipmentRenderer};
+use oucc_kizai_bot::models::ManagedMessage;
+use oucc_kizai_bot::reconcile::{
+    find_channel_drift, find_managed_channel, is_display_message, ChannelDrift, ReconcileStats,
+    RefreshQueue, TokenBucket,
+};
+
+mod common;
+
+/// Test that the token bucket allows a burst, then paces at the refill rate
+#[test]
+fn test_token_bucket() {
+    let start = Instant::now();
+    let mut bucket = TokenBucket::new(3, 2.0, start);
+
+    for _ in 0..3 {
+        assert_eq!(bucket.try_take(start), Ok(()));
+    }
+    let wait = bucket.try_take(start).unwrap_err();
+    assert_eq!(wait, Duration::from_millis(500));
+
+    // Half a second buys one token back
+    assert_eq!(bucket.try_take(start + Duration::from_millis(500)), Ok(()));
+    assert!(bucket.try_take(start + Duration::from_millis(600)).is_err());
+
+    // Refills never exceed the capacity
+    let later = start + Duration::from_secs(60);
+    for _ in 0..3 {
+        assert_eq!(bucket.try_take(later), Ok(()));
+    }
+    assert!(bucket.try_take(later).is_err());
+}
+
+/// Test that refresh requests coalesce per channel
+#[test]
+fn test_refresh_queue_coalescing() {
+    let queue = RefreshQueue::new();
+
+    // A burst before the run starts needs only one worker
+    assert!(queue.request(1, false));
+    assert!(!queue.request(1, false));
+    assert!(!queue.request(1, false));
+    assert!(queue.request(2, false)); // Channels are independent
+
+    // No new requests while running: done after one run
+    assert!(!queue.start(2));
+    assert!(!queue.finish(2));
+    assert!(queue.is_idle(2));
+
+    // Requests during a run cause exactly one more run
+    assert!(!queue.start(1));
+    assert!(!queue.request(1, false));
+    assert!(!queue.request(1, false));
+    assert!(queue.finish(1));
+    assert!(!queue.start(1));
+    assert!(!queue.finish(1));
+    assert!(queue.is_idle(1));
+}
+
+/// Test that a repair request upgrades the run it is folded into
+#[test]
+fn test_refresh_queue_repair() {
+    let queue = RefreshQueue::new();
+
+    assert!(queue.request(1, false));
+    assert!(!queue.request(1, true));
+    assert!(!queue.request(1, false));
+    assert!(queue.start(1)); // The pending run repairs
+
+    // A repair asked for during a run applies to the next run only
+    assert!(!queue.request(1, true));
+    assert!(queue.finish(1));
+    assert!(queue.start(1));
+    assert!(!queue.finish(1));
+
+    assert!(queue.request(1, false));
+    assert!(!queue.start(1));
+    assert!(!queue.finish(1));
+}
+
+/// Hash every equipment message the way reconciliation does
+async fn rendered_hashes(
+    renderer: &EquipmentRenderer,
+    guild_id: i64,
+) -> Result<HashMap<i64, String>> {
+    let mut hashes = HashMap::new();
+    for (equipment, tag) in renderer.get_ordered_equipment(guild_id).await? {
+        let embed = renderer.create_equipment_embed(&equipment, &tag).await?;
+        let buttons = renderer.create_equipment_buttons(&equipment).await?;
+        hashes.insert(equipment.id, content_hash(&embed, &buttons));
+    }
+    Ok(hashes)
+}
+
+/// Test that unchanged messages are skipped and changed ones edited
+#[tokio::test]
+async fn test_content_hash_skips_unchanged_messages() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let renderer = EquipmentRenderer::new(ctx.db.clone());
+
+    let hashes = rendered_hashes(&renderer, guild.id).await?;
+    assert_eq!(hashes, rendered_hashes(&renderer, guild.id).await?);
+
+    let existing_messages = vec![
+        ManagedMessage {
+            id: 1,
+            guild_id: guild.id,
+            channel_id: 456,
+            message_id: 789,
+            message_type: "Header".to_string(),
+            equipment_id: None,
+            sort_order: Some(0),
+            created_at: Utc::now(),
+            content_hash: None,
+        },
+        ManagedMessage {
+            id: 2,
+            guild_id: guild.id,
+            channel_id: 456,
+            message_id: 790,
+            message_type: "EquipmentEmbed".to_string(),
+            equipment_id: Some(equipment.id),
+            sort_order: Some(1),
+            created_at: Utc::now(),
+            content_hash: hashes.get(&equipment.id).cloned(),
+        },
+    ];
+    let equipment_list = renderer.get_ordered_equipment(guild.id).await?;
+    let plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &hashes);
+    assert!(plan.actions.is_empty());
+    assert_eq!(
+        plan.stats(),
+        ReconcileStats {
+            skipped: 1,
+            ..Default::default()
+        }
+    );
+
+    // A status change shows on the embed, so the message needs an edit
+    sqlx::query("UPDATE equipment SET status = 'Unavailable' WHERE id = ?")
+        .bind(equipment.id)
+        .execute(&ctx.db)
+        .await?;
+    let changed = rendered_hashes(&renderer, guild.id).await?;
+    assert_ne!(changed, hashes);
+    let equipment_list = renderer.get_ordered_equipment(guild.id).await?;
+    let plan = EquipmentRenderer::compute_edit_plan(&existing_messages, &equipment_list, &changed);
+    assert_eq!(
+        plan.actions,
+        vec![EditAction::EditEquipment(790, equipment.id)]
+    );
+    assert_eq!(
+        plan.stats(),
+        ReconcileStats {
+            edited: 1,
+            ..Default::default()
+        }
+    );
+    Ok(())
+}
+
+/// Test detecting deleted and untracked messages from channel history
+#[test]
+fn test_find_channel_drift() {
+    let tracked = [100, 200, 300];
+
+    // Everything in place; other users' messages are ignored
+    let history = [(300, true), (250, false), (200, true), (100, true)];
+    assert!(find_channel_drift(&tracked, &history, true).is_clean());
+
+    // An untracked bot message that is not part of the display, such as a damage
+    // alert, does not call for a rebuild
+    let history = [(350, false), (300, true), (200, true), (100, true)];
+    assert!(find_channel_drift(&tracked, &history, true).is_clean());
+
+    // A tracked message was deleted and the bot has a display message it does not know
+    let history = [(400, true), (300, true), (100, true)];
+    assert_eq!(
+        find_channel_drift(&tracked, &history, true),
+        ChannelDrift {
+            missing: vec![200],
+            foreign: vec![400],
+        }
+    );
+
+    // With a cut-short history, messages older than the oldest fetched are not judged
+    let history = [(300, true), (250, true)];
+    let drift = find_channel_drift(&tracked, &history, false);
+    assert!(drift.missing.is_empty());
+    assert_eq!(drift.foreign, vec![250]);
+    let drift = find_channel_drift(&tracked, &history, true);
+    assert_eq!(drift.missing, vec![100, 200]);
+
+    // An empty channel has lost every tracked message
+    assert_eq!(
+        find_channel_drift(&tracked, &[], true).missing,
+        tracked.to_vec()
+    );
+}
+
+/// Test telling display messages from other bot messages by their buttons
+#[test]
+fn test_is_display_message() {
+    assert!(is_display_message(["overall_mgmt_open"]));
+    assert!(is_display_message([
+        "reserve_1",
+        "change_1",
+        "eq_settings_1"
+    ]));
+    // Damage alerts and notices carry other buttons or none
+    assert!(!is_display_message(["cond_unavailable:7"]));
+    assert!(!is_display_message([]));
+}
+
+/// Test reading back the latest logged channel rebuild
+#[tokio::test]
+async fn test_last_rebuild() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, _equipment) = common::create_test_setup(&ctx).await?;
+    let renderer = EquipmentRenderer::new(ctx.db.clone());
+
+    assert!(renderer.last_rebuild(guild.id).await?.is_none());
+
+    sqlx::query(
+        "INSERT INTO channel_rebuilds
+         (guild_id, channel_id, triggered_by, missing_count, foreign_count, deleted_count, created_count, rebuilt_at)
+         VALUES (?, 456, NULL, 1, 0, 4, 5, '2025-01-06 01:00:00'),
+                (?, 456, 999, 0, 0, 5, 5, '2025-01-07 01:00:00')",
+    )
+    .bind(guild.id)
+    .bind(guild.id)
+    .execute(&ctx.db)
+    .await?;
+
+    let rebuild = renderer.last_rebuild(guild.id).await?.unwrap();
+    assert_eq!(rebuild.triggered_by, Some(999));
+    assert_eq!((rebuild.deleted_count, rebuild.created_count), (5, 5));
+    assert_eq!(rebuild.rebuilt_at.to_rfc3339(), "2025-01-07T01:00:00+00:00");
+    assert!(renderer.last_rebuild(guild.id + 1).await?.is_none());
+    Ok(())
+}
+
+/// Test recognising deleted messages that the bot manages
+#[tokio::test]
+async fn test_find_managed_channel() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let channel_id = guild.reservation_channel_id.unwrap();
+    sqlx::query(
+        "INSERT INTO managed_messages (guild_id, channel_id, message_id, message_type, equipment_id, sort_order)
+         VALUES (?, ?, 789, 'Header', NULL, 0), (?, ?, 790, 'EquipmentEmbed', ?, 1)",
+    )
+    .bind(guild.id)
+    .bind(channel_id)
+    .bind(guild.id)
+    .bind(channel_id)
+    .bind(equipment.id)
+    .execute(&ctx.db)
+    .await?;
+
+    assert_eq!(
+        find_managed_channel(&ctx.db, channel_id, &[790]).await?,
+        Some(guild.id)
+    );
+    // Bulk deletions match on any managed ID
+    assert_eq!(
+        find_managed_channel(&ctx.db, channel_id, &[1, 2, 789]).await?,
+        Some(guild.id)
+    );
+    // User messages and other channels are left alone
+    assert_eq!(
+        find_managed_channel(&ctx.db, channel_id, &[1, 2]).await?,
+        None
+    );
+    assert_eq!(
+        find_managed_channel(&ctx.db, channel_id + 1, &[790]).await?,
+        None
+    );
+    assert_eq!(find_managed_channel(&ctx.db, channel_id, &[]).await?, None);
+    Ok(())
+}
@@ -1,27 +1,27 @@
 use anyhow::Result;
 use chrono::{Datelike, Duration, Timelike, Utc};
-use oucc_kizai_bot::time::jst_to_utc;
+use oucc_kizai_bot::time::{local_ymd_hm_to_utc, DEFAULT_TIME_ZONE};
 
 #[tokio::test]
 async fn test_jst_time_conversion() -> Result<()> {
     // Test JST to UTC conversion with known values
 
     // January 1, 2024 00:00 JST = December 31, 2023 15:00 UTC
-    let jst_new_year = jst_to_utc(2024, 1, 1, 0, 0).unwrap();
+    let jst_new_year = local_ymd_hm_to_utc(2024, 1, 1, 0, 0, DEFAULT_TIME_ZONE).unwrap();
     assert_eq!(jst_new_year.month(), 12);
     assert_eq!(jst_new_year.day(), 31);
     assert_eq!(jst_new_year.hour(), 15);
 
     // January 1, 2024 12:00 JST = January 1, 2024 03:00 UTC
-    let jst_noon = jst_to_utc(2024, 1, 1, 12, 0).unwrap();
+    let jst_noon = local_ymd_hm_to_utc(2024, 1, 1, 12, 0, DEFAULT_TIME_ZONE).unwrap();
     assert_eq!(jst_noon.month(), 1);
     assert_eq!(jst_noon.day(), 1);
     assert_eq!(jst_noon.hour(), 3);
 
     // Test invalid dates return None
-    assert!(jst_to_utc(2024, 13, 1, 0, 0).is_none()); // Invalid month
-    assert!(jst_to_utc(2024, 2, 30, 0, 0).is_none()); // Invalid day
-    assert!(jst_to_utc(2024, 1, 1, 25, 0).is_none()); // Invalid hour
+    assert!(local_ymd_hm_to_utc(2024, 13, 1, 0, 0, DEFAULT_TIME_ZONE).is_none()); // Invalid month
+    assert!(local_ymd_hm_to_utc(2024, 2, 30, 0, 0, DEFAULT_TIME_ZONE).is_none()); // Invalid day
+    assert!(local_ymd_hm_to_utc(2024, 1, 1, 25, 0, DEFAULT_TIME_ZONE).is_none()); // Invalid hour
 
     Ok(())
 }
@@ -0,0 +1,272 @@
+use anyhow::Result;
+use chrono::Duration;
+use oucc_kizai_bot::i18n::Language;
+use oucc_kizai_bot::returns::{return_correction_deadline, ReturnCorrectionCheck, ReturnService};
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+/// Mark a reservation as returned by `user_id` at `returned_at`
+async fn mark_returned(
+    ctx: &common::TestContext,
+    reservation_id: i64,
+    equipment_id: i64,
+    user_id: i64,
+    returned_at: chrono::DateTime<chrono::Utc>,
+) -> Result<()> {
+    sqlx::query!(
+        "UPDATE reservations SET returned_at = ?, return_location = 'Club Room', returned_by_user_id = ?
+         WHERE id = ?",
+        returned_at,
+        user_id,
+        reservation_id
+    )
+    .execute(&ctx.db)
+    .await?;
+    sqlx::query!(
+        "UPDATE equipment SET status = 'Available', current_location = 'Club Room' WHERE id = ?",
+        equipment_id
+    )
+    .execute(&ctx.db)
+    .await?;
+    Ok(())
+}
+
+/// Test the correction deadline is the earlier of the window end and the next reservation buffer
+#[tokio::test]
+async fn test_return_correction_deadline() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let returned_at = ctx.clock.now_utc();
+
+    assert_eq!(
+        return_correction_deadline(returned_at, None),
+        returned_at + Duration::hours(1)
+    );
+    assert_eq!(
+        return_correction_deadline(returned_at, Some(returned_at + Duration::hours(3))),
+        returned_at + Duration::hours(1)
+    );
+    assert_eq!(
+        return_correction_deadline(returned_at, Some(returned_at + Duration::minutes(30))),
+        returned_at + Duration::minutes(15)
+    );
+
+    Ok(())
+}
+
+/// Test that the returner can cancel a return inside the window
+#[tokio::test]
+async fn test_cancel_return_within_window() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (_guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ReturnService::new(ctx.db.clone());
+
+    let user_id = 12345i64;
+    let now = ctx.clock.now_utc();
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        now - Duration::hours(1),
+        now + Duration::hours(2),
+    )
+    .build(&ctx.db)
+    .await?;
+    mark_returned(
+        &ctx,
+        reservation.id,
+        equipment.id,
+        user_id,
+        now - Duration::minutes(10),
+    )
+    .await?;
+
+    assert!(matches!(
+        service.check_correction(reservation.id, user_id).await?,
+        ReturnCorrectionCheck::Allowed { .. }
+    ));
+
+    let check = service.cancel_return(reservation.id, user_id).await?;
+    assert!(check.denial_message(Language::En).is_none());
+
+    let row = sqlx::query!(
+        "SELECT returned_at, return_location, returned_by_user_id FROM reservations WHERE id = ?",
+        reservation.id
+    )
+    .fetch_one(&ctx.db)
+    .await?;
+    assert!(row.returned_at.is_none());
+    assert!(row.return_location.is_none());
+    assert!(row.returned_by_user_id.is_none());
+
+    let status = sqlx::query_scalar!("SELECT status FROM equipment WHERE id = ?", equipment.id)
+        .fetch_one(&ctx.db)
+        .await?;
+    assert_eq!(status, "Loaned");
+
+    let log_count = sqlx::query!(
+        "SELECT COUNT(*) as count FROM equipment_logs WHERE equipment_id = ? AND action = 'ReturnCancelled'",
+        equipment.id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert_eq!(log_count, 1);
+
+    // Overdue reminders are scheduled again
+    let overdue_jobs = sqlx::query!(
+        "SELECT COUNT(*) as count FROM jobs
+         WHERE job_type = 'reminder' AND status = 'Pending'
+           AND JSON_EXTRACT(payload, '$.reservation_id') = ?
+           AND JSON_EXTRACT(payload, '$.type') LIKE 'return_delay%'",
+        reservation.id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert!(overdue_jobs > 0);
+
+    // Once cancelled there is nothing left to correct
+    assert_eq!(
+        service.cancel_return(reservation.id, user_id).await?,
+        ReturnCorrectionCheck::NotReturned
+    );
+
+    Ok(())
+}
+
+/// Test that corrections are refused outside the window or for other members
+#[tokio::test]
+async fn test_correction_refused() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (_guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ReturnService::new(ctx.db.clone());
+
+    let user_id = 12345i64;
+    let now = ctx.clock.now_utc();
+
+    // Returned more than an hour ago
+    let old = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        now - Duration::hours(5),
+        now - Duration::hours(3),
+    )
+    .build(&ctx.db)
+    .await?;
+    mark_returned(
+        &ctx,
+        old.id,
+        equipment.id,
+        user_id,
+        now - Duration::hours(2),
+    )
+    .await?;
+    assert_eq!(
+        service.cancel_return(old.id, user_id).await?,
+        ReturnCorrectionCheck::Expired
+    );
+
+    // Returned recently, but the next reservation starts within the buffer
+    let recent = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        now - Duration::hours(2),
+        now - Duration::minutes(5),
+    )
+    .build(&ctx.db)
+    .await?;
+    mark_returned(
+        &ctx,
+        recent.id,
+        equipment.id,
+        user_id,
+        now - Duration::minutes(5),
+    )
+    .await?;
+    common::ReservationBuilder::new(
+        equipment.id,
+        54321,
+        now + Duration::minutes(10),
+        now + Duration::hours(2),
+    )
+    .build(&ctx.db)
+    .await?;
+    assert_eq!(
+        service
+            .correct_return_location(recent.id, user_id, "Storage")
+            .await?,
+        ReturnCorrectionCheck::Expired
+    );
+
+    // Only the returner may correct
+    assert_eq!(
+        service.check_correction(recent.id, 99999).await?,
+        ReturnCorrectionCheck::NotReturner
+    );
+
+    let status = sqlx::query_scalar!("SELECT status FROM equipment WHERE id = ?", equipment.id)
+        .fetch_one(&ctx.db)
+        .await?;
+    assert_eq!(status, "Available");
+
+    Ok(())
+}
+
+/// Test fixing the return location
+#[tokio::test]
+async fn test_correct_return_location() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (_guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ReturnService::new(ctx.db.clone());
+
+    let user_id = 12345i64;
+    let now = ctx.clock.now_utc();
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        now - Duration::hours(2),
+        now - Duration::minutes(30),
+    )
+    .build(&ctx.db)
+    .await?;
+    mark_returned(
+        &ctx,
+        reservation.id,
+        equipment.id,
+        user_id,
+        now - Duration::minutes(20),
+    )
+    .await?;
+
+    let check = service
+        .correct_return_location(reservation.id, user_id, "Storage")
+        .await?;
+    assert!(check.denial_message(Language::En).is_none());
+
+    let return_location = sqlx::query_scalar!(
+        "SELECT return_location FROM reservations WHERE id = ?",
+        reservation.id
+    )
+    .fetch_one(&ctx.db)
+    .await?;
+    assert_eq!(return_location.as_deref(), Some("Storage"));
+
+    let current_location = sqlx::query_scalar!(
+        "SELECT current_location FROM equipment WHERE id = ?",
+        equipment.id
+    )
+    .fetch_one(&ctx.db)
+    .await?;
+    assert_eq!(current_location.as_deref(), Some("Storage"));
+
+    let log_count = sqlx::query!(
+        "SELECT COUNT(*) as count FROM equipment_logs WHERE equipment_id = ? AND action = 'ReturnCorrected'",
+        equipment.id
+    )
+    .fetch_one(&ctx.db)
+    .await?
+    .count;
+    assert_eq!(log_count, 1);
+
+    Ok(())
+}
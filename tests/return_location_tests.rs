@@ -0,0 +1,127 @@
+use anyhow::Result;
+use chrono::Duration;
+use oucc_kizai_bot::returns::{is_default_return_location, ReturnService};
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+/// Test comparing a return location with the equipment default
+#[test]
+fn test_is_default_return_location() {
+    assert!(is_default_return_location("Club Room", Some("Club Room")));
+    assert!(is_default_return_location(" club room ", Some("Club Room")));
+    assert!(!is_default_return_location("Storage", Some("Club Room")));
+    assert!(is_default_return_location("Anywhere", None));
+}
+
+/// Test marking locations as restricted
+#[tokio::test]
+async fn test_restricted_locations() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, location, _equipment) = common::create_test_setup(&ctx).await?;
+    let service = ReturnService::new(ctx.db.clone());
+
+    let vault = common::LocationBuilder::new(guild.id, "Vault")
+        .build(&ctx.db)
+        .await?;
+
+    assert!(!service.is_restricted_location(guild.id, "Vault").await?);
+
+    service
+        .set_restricted_locations(guild.id, &[vault.id])
+        .await?;
+    assert!(service.is_restricted_location(guild.id, "vault ").await?);
+    assert!(
+        !service
+            .is_restricted_location(guild.id, &location.name)
+            .await?
+    );
+
+    // Replacing the set clears previous restrictions
+    service
+        .set_restricted_locations(guild.id, &[location.id])
+        .await?;
+    assert!(!service.is_restricted_location(guild.id, "Vault").await?);
+    assert!(
+        service
+            .is_restricted_location(guild.id, "Club Room")
+            .await?
+    );
+
+    // Other guilds are unaffected
+    assert!(!service.is_restricted_location(1, "Club Room").await?);
+
+    Ok(())
+}
+
+/// Test acknowledging a return to a restricted location
+#[tokio::test]
+async fn test_acknowledge_restricted_return() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (_guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ReturnService::new(ctx.db.clone());
+
+    let user_id = 12345i64;
+    let admin_id = 99999i64;
+    let now = ctx.clock.now_utc();
+    let reservation = common::ReservationBuilder::new(
+        equipment.id,
+        user_id,
+        now - Duration::hours(2),
+        now - Duration::minutes(30),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    assert!(service
+        .pending_acknowledgment(equipment.id)
+        .await?
+        .is_none());
+
+    sqlx::query!(
+        "UPDATE reservations SET returned_at = ?, return_location = 'Vault', returned_by_user_id = ?,
+             return_ack_required = TRUE
+         WHERE id = ?",
+        now,
+        user_id,
+        reservation.id
+    )
+    .execute(&ctx.db)
+    .await?;
+
+    let pending = service
+        .pending_acknowledgment(equipment.id)
+        .await?
+        .expect("return should await acknowledgment");
+    assert_eq!(pending.reservation_id, reservation.id);
+    assert_eq!(pending.user_id, user_id);
+    assert_eq!(pending.return_location.as_deref(), Some("Vault"));
+
+    assert!(service.acknowledge_return(reservation.id, admin_id).await?);
+    assert!(!service.acknowledge_return(reservation.id, admin_id).await?);
+    assert!(service
+        .pending_acknowledgment(equipment.id)
+        .await?
+        .is_none());
+
+    let acknowledged_by = sqlx::query_scalar!(
+        "SELECT return_acknowledged_by_user_id FROM reservations WHERE id = ?",
+        reservation.id
+    )
+    .fetch_one(&ctx.db)
+    .await?;
+    assert_eq!(acknowledged_by, Some(admin_id));
+
+    let audit = sqlx::query!(
+        "SELECT user_id, location FROM equipment_logs
+         WHERE equipment_id = ? AND action = 'ReturnAcknowledged'",
+        equipment.id
+    )
+    .fetch_all(&ctx.db)
+    .await?;
+    assert_eq!(audit.len(), 1);
+    assert_eq!(audit[0].user_id, admin_id);
+    assert_eq!(audit[0].location.as_deref(), Some("Vault"));
+
+    Ok(())
+}
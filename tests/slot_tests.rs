@@ -0,0 +1,285 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use oucc_kizai_bot::buffers::TurnaroundBuffer;
+use oucc_kizai_bot::i18n::Language;
+use oucc_kizai_bot::operating_hours::{parse_weekly_hours, ClosedDate, OperatingSchedule};
+use oucc_kizai_bot::slots::{describe_duration, FreeWindow, SlotPlanner, SlotService};
+use oucc_kizai_bot::time::{local_ymd_hm_to_utc, utc_to_local_time_string, DEFAULT_TIME_ZONE};
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+fn jst(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    local_ymd_hm_to_utc(2025, 1, day, hour, minute, DEFAULT_TIME_ZONE).unwrap()
+}
+
+fn times(slots: &[DateTime<Utc>]) -> Vec<String> {
+    slots
+        .iter()
+        .map(|s| utc_to_local_time_string(*s, DEFAULT_TIME_ZONE))
+        .collect()
+}
+
+/// Mornings only, Monday to Saturday
+fn morning_schedule() -> OperatingSchedule {
+    OperatingSchedule {
+        weekly_hours: parse_weekly_hours("Mon-Sat 09:00-12:00").unwrap(),
+        closed_dates: Vec::new(),
+        time_zone: DEFAULT_TIME_ZONE,
+    }
+}
+
+/// Test reservation lengths shown in the duration select
+#[test]
+fn test_describe_duration() {
+    assert_eq!(describe_duration(30, Language::En), "30 min");
+    assert_eq!(describe_duration(60, Language::En), "1 h");
+    assert_eq!(describe_duration(90, Language::En), "1 h 30 min");
+    assert_eq!(describe_duration(1440, Language::En), "1 day");
+    assert_eq!(describe_duration(4320, Language::En), "3 days");
+    assert_eq!(describe_duration(90, Language::Ja), "1時間30分");
+}
+
+/// Test that start slots skip reserved times, prep time and closing time
+#[test]
+fn test_start_slots_exclude_taken_times() {
+    // Monday 2025-01-06, 08:10 JST
+    let now = jst(6, 8, 10);
+    let reservations = vec![(jst(6, 10, 0), jst(6, 11, 0))];
+
+    let planner = SlotPlanner::new(
+        morning_schedule(),
+        TurnaroundBuffer::default(),
+        reservations.clone(),
+        now,
+    );
+    assert_eq!(
+        times(&planner.start_slots(NaiveDate::from_ymd_opt(2025, 1, 6).unwrap())),
+        vec!["09:00", "09:30", "11:00", "11:30"]
+    );
+    // Lengths stop at the next reservation; past closing only whole days end while open
+    assert_eq!(planner.durations(jst(6, 9, 0)), vec![30, 60]);
+    assert_eq!(
+        planner.durations(jst(6, 11, 0)),
+        vec![30, 60, 1440, 2880, 4320, 7200, 10080, 20160]
+    );
+    assert!(planner.durations(jst(6, 10, 30)).is_empty());
+
+    // 30 minutes of prep time after each reservation
+    let planner = SlotPlanner::new(
+        morning_schedule(),
+        TurnaroundBuffer::new(0, 30),
+        reservations,
+        now,
+    );
+    assert_eq!(
+        times(&planner.start_slots(NaiveDate::from_ymd_opt(2025, 1, 6).unwrap())),
+        vec!["09:00", "11:30"]
+    );
+    assert_eq!(planner.durations(jst(6, 9, 0)), vec![30]);
+}
+
+/// Test that the date select skips past, closed and fully booked dates
+#[test]
+fn test_dates_exclude_closed_and_full_days() {
+    // Monday 11:45 JST: nothing left to start today
+    let now = jst(6, 11, 45);
+    let mut schedule = morning_schedule();
+    schedule.closed_dates.push(ClosedDate {
+        date: NaiveDate::from_ymd_opt(2025, 1, 7).unwrap(),
+        reason: Some("Maintenance".to_string()),
+    });
+    // Wednesday is booked all morning
+    let reservations = vec![(jst(8, 9, 0), jst(8, 12, 0))];
+
+    let planner = SlotPlanner::new(schedule, TurnaroundBuffer::default(), reservations, now);
+    let dates = planner.dates(5);
+    let expected: Vec<NaiveDate> = [9, 10, 11, 13, 14]
+        .iter()
+        .map(|day| NaiveDate::from_ymd_opt(2025, 1, *day).unwrap())
+        .collect();
+    assert_eq!(dates, expected);
+
+    // Without opening hours every slot of the day can start
+    let planner = SlotPlanner::new(
+        OperatingSchedule::default(),
+        TurnaroundBuffer::default(),
+        Vec::new(),
+        jst(6, 0, 0),
+    );
+    assert_eq!(
+        planner
+            .start_slots(NaiveDate::from_ymd_opt(2025, 1, 6).unwrap())
+            .len(),
+        48
+    );
+    assert!(planner
+        .durations(jst(6, 0, 0))
+        .iter()
+        .all(|minutes| jst(6, 0, 0) + Duration::minutes(*minutes) <= planner.latest_end()));
+}
+
+/// Test that slots follow the guild time zone and skip times lost to daylight saving time
+#[test]
+fn test_start_slots_in_guild_time_zone() {
+    let new_york = chrono_tz::America::New_York;
+    let schedule = OperatingSchedule {
+        time_zone: new_york,
+        ..OperatingSchedule::default()
+    };
+    // Midnight in New York on the night the clocks skip from 02:00 to 03:00
+    let now = Utc.with_ymd_and_hms(2025, 3, 9, 5, 0, 0).unwrap();
+    let planner = SlotPlanner::new(schedule, TurnaroundBuffer::default(), Vec::new(), now);
+
+    let slots = planner.start_slots(NaiveDate::from_ymd_opt(2025, 3, 9).unwrap());
+    let labels: Vec<String> = slots
+        .iter()
+        .take(5)
+        .map(|slot| utc_to_local_time_string(*slot, new_york))
+        .collect();
+    assert_eq!(labels, vec!["00:00", "00:30", "01:00", "01:30", "03:00"]);
+    assert_eq!(slots.len(), 46);
+    assert_eq!(
+        planner.dates(1),
+        vec![NaiveDate::from_ymd_opt(2025, 3, 9).unwrap()]
+    );
+}
+
+/// Test loading the planner with the equipment's reservations and prep time
+#[tokio::test]
+async fn test_slot_service_planner() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let now = ctx.clock.now_utc();
+
+    sqlx::query!(
+        "UPDATE equipment SET buffer_before_minutes = 10, buffer_after_minutes = 20 WHERE id = ?",
+        equipment.id
+    )
+    .execute(&ctx.db)
+    .await?;
+    common::ReservationBuilder::new(
+        equipment.id,
+        12345,
+        now + Duration::hours(2),
+        now + Duration::hours(4),
+    )
+    .build(&ctx.db)
+    .await?;
+    common::ReservationBuilder::new(
+        equipment.id,
+        12345,
+        now - Duration::hours(5),
+        now - Duration::hours(3),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let planner = SlotService::new(ctx.db.clone())
+        .planner(guild.id, equipment.id, now)
+        .await?;
+    assert_eq!(planner.buffer, TurnaroundBuffer::new(10, 20));
+    assert_eq!(planner.reservations.len(), 1);
+    assert!(planner.durations(now + Duration::hours(2)).is_empty());
+
+    Ok(())
+}
+
+/// Test free windows for /availability around reservations and closed days
+#[test]
+fn test_free_windows() {
+    // Monday 2025-01-06, 08:10 JST
+    let now = jst(6, 8, 10);
+    let reservations = vec![(jst(6, 10, 0), jst(6, 11, 0))];
+    let planner = SlotPlanner::new(
+        morning_schedule(),
+        TurnaroundBuffer::default(),
+        reservations.clone(),
+        now,
+    );
+
+    let windows = planner.free_windows(jst(6, 0, 0), jst(8, 0, 0), Duration::hours(1));
+    assert_eq!(
+        windows,
+        vec![
+            FreeWindow {
+                start: jst(6, 9, 0),
+                end: jst(6, 10, 0),
+            },
+            FreeWindow {
+                start: jst(6, 11, 0),
+                end: jst(6, 12, 0),
+            },
+            FreeWindow {
+                start: jst(7, 9, 0),
+                end: jst(7, 12, 0),
+            },
+        ]
+    );
+    assert_eq!(windows[2].minutes(), 180);
+
+    // Nothing fits a length longer than the opening hours on the same day
+    assert!(planner
+        .free_windows(jst(6, 0, 0), jst(8, 0, 0), Duration::hours(4))
+        .is_empty());
+
+    // Closed dates are skipped
+    let mut schedule = morning_schedule();
+    schedule.closed_dates.push(ClosedDate {
+        date: NaiveDate::from_ymd_opt(2025, 1, 7).unwrap(),
+        reason: None,
+    });
+    let planner = SlotPlanner::new(schedule, TurnaroundBuffer::default(), reservations, now);
+    assert_eq!(
+        planner
+            .free_windows(jst(6, 0, 0), jst(8, 0, 0), Duration::hours(1))
+            .len(),
+        2
+    );
+}
+
+/// Test finding the earliest free windows across several equipment
+#[tokio::test]
+async fn test_find_available_slots() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let now = ctx.clock.now_utc();
+
+    let projector = common::EquipmentBuilder::new(guild.id, "Projector")
+        .with_tag(tag.id)
+        .build(&ctx.db)
+        .await?;
+    common::ReservationBuilder::new(
+        equipment.id,
+        12345,
+        now + Duration::hours(3),
+        now + Duration::hours(4),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let slots = SlotService::new(ctx.db.clone())
+        .find_available_slots(
+            guild.id,
+            &[
+                (equipment.id, equipment.name.clone()),
+                (projector.id, projector.name.clone()),
+            ],
+            now,
+            now + Duration::hours(8),
+            Duration::hours(2),
+            now,
+        )
+        .await?;
+
+    let names: Vec<&str> = slots.iter().map(|s| s.equipment_name.as_str()).collect();
+    assert_eq!(names, vec!["Projector", "Sony A7", "Sony A7"]);
+    assert_eq!(slots[0].window.start, slots[1].window.start);
+    assert!(slots[0].window.start >= now);
+    assert!(slots[1].window.end <= now + Duration::hours(3));
+    assert!(slots[2].window.start >= now + Duration::hours(4));
+    assert!(slots[2].window.end <= now + Duration::hours(8));
+    assert!(slots.iter().all(|s| s.window.minutes() >= 120));
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use anyhow::Result;
+use oucc_kizai_bot::i18n::Language;
+use oucc_kizai_bot::tag_access::{
+    has_any_role, parse_role_ids, role_ids_to_json, TagAccessService,
+};
+
+mod common;
+
+/// Test parsing stored role lists
+#[test]
+fn test_parse_role_ids() {
+    assert!(parse_role_ids(None).is_empty());
+    assert!(parse_role_ids(Some("[]")).is_empty());
+    assert!(parse_role_ids(Some("not json")).is_empty());
+    assert_eq!(parse_role_ids(Some("[\"111\",\"222\"]")), vec![111, 222]);
+    assert_eq!(parse_role_ids(Some("[333, \"444\"]")), vec![333, 444]);
+
+    let json = role_ids_to_json(&[555, 666]).unwrap();
+    assert_eq!(json, "[\"555\",\"666\"]");
+    assert_eq!(parse_role_ids(Some(&json)), vec![555, 666]);
+}
+
+/// Test role matching
+#[test]
+fn test_has_any_role() {
+    // Unrestricted tags allow everyone
+    assert!(has_any_role(&[], &[]));
+    assert!(has_any_role(&[], &[1, 2]));
+
+    assert!(has_any_role(&[10, 20], &[1, 20]));
+    assert!(!has_any_role(&[10, 20], &[1, 2]));
+    assert!(!has_any_role(&[10], &[]));
+}
+
+/// Test access checks against equipment tags
+#[tokio::test]
+async fn test_check_equipment_access() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = TagAccessService::new(ctx.db.clone());
+
+    let trained_role = 777i64;
+
+    // No restriction by default
+    assert!(service
+        .check_equipment_access(equipment.id, &[])
+        .await?
+        .is_none());
+
+    service
+        .set_allowed_roles(guild.id, tag.id, &[trained_role])
+        .await?;
+    assert_eq!(service.get_allowed_roles(tag.id).await?, vec![trained_role]);
+
+    let restriction = service
+        .check_equipment_access(equipment.id, &[1, 2])
+        .await?
+        .expect("member without the role should be restricted");
+    assert_eq!(restriction.tag_id, tag.id);
+    assert_eq!(restriction.allowed_roles, vec![trained_role]);
+    assert!(restriction.message(Language::En).contains("<@&777>"));
+
+    assert!(service
+        .check_equipment_access(equipment.id, &[1, trained_role])
+        .await?
+        .is_none());
+
+    // Untagged equipment is never restricted
+    let untagged = common::EquipmentBuilder::new(guild.id, "Tripod")
+        .build(&ctx.db)
+        .await?;
+    assert!(service
+        .check_equipment_access(untagged.id, &[])
+        .await?
+        .is_none());
+
+    // Clearing the list lifts the restriction
+    service.set_allowed_roles(guild.id, tag.id, &[]).await?;
+    assert!(service.get_allowed_roles(tag.id).await?.is_empty());
+    assert!(service
+        .check_equipment_access(equipment.id, &[])
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+/// Test that manager roles only grant management of equipment under their tag
+#[tokio::test]
+async fn test_tag_manager_roles() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = TagAccessService::new(ctx.db.clone());
+
+    let manager_role = 888i64;
+    assert!(
+        !service
+            .is_equipment_manager(equipment.id, &[manager_role])
+            .await?
+    );
+
+    service
+        .set_manager_roles(guild.id, tag.id, &[manager_role])
+        .await?;
+    assert_eq!(service.get_manager_roles(tag.id).await?, vec![manager_role]);
+
+    assert!(
+        service
+            .is_equipment_manager(equipment.id, &[1, manager_role])
+            .await?
+    );
+    assert!(!service.is_equipment_manager(equipment.id, &[1, 2]).await?);
+
+    // Managers of one tag do not manage another tag or untagged equipment
+    let other_tag = common::TagBuilder::new(guild.id, "Audio")
+        .build(&ctx.db)
+        .await?;
+    let other_equipment = common::EquipmentBuilder::new(guild.id, "Zoom H6")
+        .with_tag(other_tag.id)
+        .build(&ctx.db)
+        .await?;
+    let untagged = common::EquipmentBuilder::new(guild.id, "Tripod")
+        .build(&ctx.db)
+        .await?;
+    assert!(
+        !service
+            .is_equipment_manager(other_equipment.id, &[manager_role])
+            .await?
+    );
+    assert!(
+        !service
+            .is_equipment_manager(untagged.id, &[manager_role])
+            .await?
+    );
+
+    // Manager roles are independent of who may reserve
+    assert!(service.get_allowed_roles(tag.id).await?.is_empty());
+
+    service.set_manager_roles(guild.id, tag.id, &[]).await?;
+    assert!(
+        !service
+            .is_equipment_manager(equipment.id, &[manager_role])
+            .await?
+    );
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
-use chrono::{DateTime, Datelike, TimeZone, Timelike, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Timelike, Utc};
 use chrono_tz::Asia::Tokyo;
+use oucc_kizai_bot::i18n::Language;
 use oucc_kizai_bot::time::*;
 
 #[test]
@@ -9,14 +10,14 @@ fn test_utc_to_jst_conversion() {
         .unwrap()
         .with_timezone(&Utc);
 
-    let jst_string = utc_to_jst_string(utc_time);
+    let jst_string = utc_to_local_string(utc_time, Tokyo);
     assert_eq!(jst_string, "2024/01/01 09:00");
 }
 
 #[test]
 fn test_jst_to_utc_conversion() {
     // Test basic conversion
-    let utc_result = jst_to_utc(2024, 1, 1, 9, 0);
+    let utc_result = local_ymd_hm_to_utc(2024, 1, 1, 9, 0, Tokyo);
     assert!(utc_result.is_some());
 
     let utc_time = utc_result.unwrap();
@@ -40,7 +41,7 @@ fn test_round_trip_conversion() {
     let minute = jst_time.minute();
 
     // Convert back to UTC
-    let converted_back = jst_to_utc(year, month, day, hour, minute).unwrap();
+    let converted_back = local_ymd_hm_to_utc(year, month, day, hour, minute, Tokyo).unwrap();
 
     // Should match original (ignoring seconds)
     assert_eq!(original_utc.date_naive(), converted_back.date_naive());
@@ -53,8 +54,8 @@ fn test_jst_no_dst_transitions() {
     // JST doesn't observe daylight saving time, so conversions should be consistent
     // Test dates around traditional DST transition times
 
-    let spring_transition = jst_to_utc(2024, 3, 10, 12, 0).unwrap(); // March 10, 2024
-    let fall_transition = jst_to_utc(2024, 11, 3, 12, 0).unwrap(); // November 3, 2024
+    let spring_transition = local_ymd_hm_to_utc(2024, 3, 10, 12, 0, Tokyo).unwrap(); // March 10, 2024
+    let fall_transition = local_ymd_hm_to_utc(2024, 11, 3, 12, 0, Tokyo).unwrap(); // November 3, 2024
 
     // Both should have the same offset (9 hours)
     let spring_jst = Tokyo.from_utc_datetime(&spring_transition.naive_utc());
@@ -71,17 +72,17 @@ fn test_jst_no_dst_transitions() {
 #[test]
 fn test_edge_case_times() {
     // Test midnight boundaries
-    let jst_midnight = jst_to_utc(2024, 1, 1, 0, 0).unwrap();
+    let jst_midnight = local_ymd_hm_to_utc(2024, 1, 1, 0, 0, Tokyo).unwrap();
     assert_eq!(jst_midnight.hour(), 15); // Previous day 15:00 UTC
     assert_eq!(jst_midnight.day(), 31); // December 31, 2023
 
     // Test noon
-    let jst_noon = jst_to_utc(2024, 1, 1, 12, 0).unwrap();
+    let jst_noon = local_ymd_hm_to_utc(2024, 1, 1, 12, 0, Tokyo).unwrap();
     assert_eq!(jst_noon.hour(), 3); // 03:00 UTC same day
     assert_eq!(jst_noon.day(), 1); // January 1, 2024
 
     // Test end of day
-    let jst_late = jst_to_utc(2024, 1, 1, 23, 59).unwrap();
+    let jst_late = local_ymd_hm_to_utc(2024, 1, 1, 23, 59, Tokyo).unwrap();
     assert_eq!(jst_late.hour(), 14); // 14:59 UTC same day
     assert_eq!(jst_late.minute(), 59);
 }
@@ -89,30 +90,30 @@ fn test_edge_case_times() {
 #[test]
 fn test_invalid_jst_dates() {
     // Test invalid dates return None
-    assert!(jst_to_utc(2024, 13, 1, 12, 0).is_none()); // Invalid month
-    assert!(jst_to_utc(2024, 2, 30, 12, 0).is_none()); // Invalid day
-    assert!(jst_to_utc(2024, 1, 1, 25, 0).is_none()); // Invalid hour
-    assert!(jst_to_utc(2024, 1, 1, 12, 60).is_none()); // Invalid minute
+    assert!(local_ymd_hm_to_utc(2024, 13, 1, 12, 0, Tokyo).is_none()); // Invalid month
+    assert!(local_ymd_hm_to_utc(2024, 2, 30, 12, 0, Tokyo).is_none()); // Invalid day
+    assert!(local_ymd_hm_to_utc(2024, 1, 1, 25, 0, Tokyo).is_none()); // Invalid hour
+    assert!(local_ymd_hm_to_utc(2024, 1, 1, 12, 60, Tokyo).is_none()); // Invalid minute
 }
 
 #[test]
 fn test_leap_year_handling() {
     // Test February 29 in leap year
-    let leap_day = jst_to_utc(2024, 2, 29, 12, 0);
+    let leap_day = local_ymd_hm_to_utc(2024, 2, 29, 12, 0, Tokyo);
     assert!(leap_day.is_some());
 
     // Test February 29 in non-leap year
-    let non_leap_day = jst_to_utc(2023, 2, 29, 12, 0);
+    let non_leap_day = local_ymd_hm_to_utc(2023, 2, 29, 12, 0, Tokyo);
     assert!(non_leap_day.is_none());
 }
 
 #[test]
-fn test_is_past_jst() {
+fn test_is_past() {
     let future_time = Utc::now() + chrono::Duration::hours(1);
     let past_time = Utc::now() - chrono::Duration::hours(1);
 
-    assert!(!is_past_jst(future_time));
-    assert!(is_past_jst(past_time));
+    assert!(!is_past(future_time));
+    assert!(is_past(past_time));
 }
 
 #[test]
@@ -122,12 +123,336 @@ fn test_jst_formatting() {
         .with_timezone(&Utc);
 
     // 05:30 UTC + 9 hours = 14:30 JST
-    assert_eq!(utc_to_jst_string(utc_time), "2024/12/25 14:30");
-    assert_eq!(utc_to_jst_date_string(utc_time), "2024/12/25");
-    assert_eq!(utc_to_jst_time_string(utc_time), "14:30");
+    assert_eq!(utc_to_local_string(utc_time, Tokyo), "2024/12/25 14:30");
+    assert_eq!(utc_to_local_date_string(utc_time, Tokyo), "2024/12/25");
+    assert_eq!(utc_to_local_time_string(utc_time, Tokyo), "14:30");
+}
+
+/// Fixed "now" for the parser tests: 2025-01-15 10:20 JST
+fn parser_now() -> DateTime<Utc> {
+    local_ymd_hm_to_utc(2025, 1, 15, 10, 20, Tokyo).unwrap()
+}
+
+#[test]
+fn test_parse_time_input_accepted_formats() {
+    let cases: &[(&str, (i32, u32, u32, u32, u32))] = &[
+        ("2025-01-20 14:30", (2025, 1, 20, 14, 30)),
+        ("2025/01/20 14:30", (2025, 1, 20, 14, 30)),
+        ("2025/1/20 9:05", (2025, 1, 20, 9, 5)),
+        ("  2025-01-20   14:30 ", (2025, 1, 20, 14, 30)),
+        ("1/20 14:30", (2025, 1, 20, 14, 30)),
+        ("01-20 14:30", (2025, 1, 20, 14, 30)),
+        ("1/15 18:00", (2025, 1, 15, 18, 0)),
+        // A date earlier in the year means next year
+        ("1/10 14:30", (2026, 1, 10, 14, 30)),
+        ("today 18:00", (2025, 1, 15, 18, 0)),
+        ("Tomorrow 9:00", (2025, 1, 16, 9, 0)),
+        ("明日 14:00", (2025, 1, 16, 14, 0)),
+        ("明日14：00", (2025, 1, 16, 14, 0)),
+        ("明後日　8:30", (2025, 1, 17, 8, 30)),
+        ("今日 23:59", (2025, 1, 15, 23, 59)),
+        ("18:00", (2025, 1, 15, 18, 0)),
+        ("+2h", (2025, 1, 15, 12, 20)),
+        ("+30m", (2025, 1, 15, 10, 50)),
+        ("+1d 2h", (2025, 1, 16, 12, 20)),
+    ];
+
+    for (input, (year, month, day, hour, minute)) in cases {
+        assert_eq!(
+            parse_time_input(input, parser_now(), DEFAULT_TIME_ZONE),
+            Ok(local_ymd_hm_to_utc(*year, *month, *day, *hour, *minute, Tokyo).unwrap()),
+            "input: {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_time_input_errors() {
+    let cases: &[(&str, TimeParseError)] = &[
+        ("", TimeParseError::Empty),
+        ("   ", TimeParseError::Empty),
+        (
+            "next friday",
+            TimeParseError::UnrecognizedFormat("next friday".to_string()),
+        ),
+        (
+            "2025-01-20 1430",
+            TimeParseError::UnrecognizedFormat("2025-01-20 1430".to_string()),
+        ),
+        (
+            "20/2025/01 14:30",
+            TimeParseError::UnrecognizedFormat("20/2025/01 14:30".to_string()),
+        ),
+        ("2025-01-20", TimeParseError::MissingTime),
+        ("tomorrow", TimeParseError::MissingTime),
+        (
+            "2025-02-30 10:00",
+            TimeParseError::InvalidDate {
+                year: 2025,
+                month: 2,
+                day: 30,
+            },
+        ),
+        (
+            "13/01 10:00",
+            TimeParseError::InvalidDate {
+                year: 2025,
+                month: 13,
+                day: 1,
+            },
+        ),
+        (
+            "2025-01-20 25:00",
+            TimeParseError::InvalidTime {
+                hour: 25,
+                minute: 0,
+            },
+        ),
+        (
+            "today 10:75",
+            TimeParseError::InvalidTime {
+                hour: 10,
+                minute: 75,
+            },
+        ),
+        ("+2x", TimeParseError::InvalidDuration("+2x".to_string())),
+        ("+0m", TimeParseError::ZeroDuration),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            parse_time_input(input, parser_now(), DEFAULT_TIME_ZONE).as_ref(),
+            Err(expected),
+            "input: {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_duration_input() {
+    let cases: &[(&str, Result<i64, TimeParseError>)] = &[
+        ("90m", Ok(90)),
+        ("2h30m", Ok(150)),
+        ("2h 30min", Ok(150)),
+        ("1d", Ok(1440)),
+        ("1 day 12 hours", Ok(2160)),
+        ("2時間30分", Ok(150)),
+        ("3日", Ok(4320)),
+        ("+45m", Ok(45)),
+        ("", Err(TimeParseError::Empty)),
+        ("0h", Err(TimeParseError::ZeroDuration)),
+        ("h", Err(TimeParseError::InvalidDuration("h".to_string()))),
+        ("2", Err(TimeParseError::InvalidDuration("2".to_string()))),
+        (
+            "2 weeks",
+            Err(TimeParseError::InvalidDuration("2 weeks".to_string())),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            parse_duration_input(input).map(|d| d.num_minutes()),
+            *expected,
+            "input: {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_date_input() {
+    let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    let cases: &[(&str, Result<NaiveDate, TimeParseError>)] = &[
+        ("2025-04-01", Ok(NaiveDate::from_ymd_opt(2025, 4, 1).unwrap())),
+        ("2025/04/01", Ok(NaiveDate::from_ymd_opt(2025, 4, 1).unwrap())),
+        ("3/15", Ok(NaiveDate::from_ymd_opt(2025, 3, 15).unwrap())),
+        // Without a year, a date earlier than today means next year
+        ("1/5", Ok(NaiveDate::from_ymd_opt(2026, 1, 5).unwrap())),
+        (" Tomorrow ", Ok(NaiveDate::from_ymd_opt(2025, 3, 11).unwrap())),
+        ("明後日", Ok(NaiveDate::from_ymd_opt(2025, 3, 12).unwrap())),
+        ("", Err(TimeParseError::Empty)),
+        (
+            "next week",
+            Err(TimeParseError::UnrecognizedFormat("next week".to_string())),
+        ),
+        (
+            "2025-02-30",
+            Err(TimeParseError::InvalidDate {
+                year: 2025,
+                month: 2,
+                day: 30,
+            }),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            parse_date_input(input, today),
+            *expected,
+            "input: {:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_end_time_input() {
+    let start = local_ymd_hm_to_utc(2025, 1, 20, 14, 0, Tokyo).unwrap();
+    let cases: &[(&str, (i32, u32, u32, u32, u32))] = &[
+        // Lengths count from the start time
+        ("2h30m", (2025, 1, 20, 16, 30)),
+        ("+90m", (2025, 1, 20, 15, 30)),
+        ("1d", (2025, 1, 21, 14, 0)),
+        // Anything else is read as a date and time
+        ("2025/01/20 18:00", (2025, 1, 20, 18, 0)),
+        ("1/21 10:00", (2025, 1, 21, 10, 0)),
+        ("tomorrow 12:00", (2025, 1, 16, 12, 0)),
+    ];
+
+    for (input, (year, month, day, hour, minute)) in cases {
+        assert_eq!(
+            parse_end_time_input(input, start, parser_now(), DEFAULT_TIME_ZONE),
+            Ok(local_ymd_hm_to_utc(*year, *month, *day, *hour, *minute, Tokyo).unwrap()),
+            "input: {:?}",
+            input
+        );
+    }
+
+    assert_eq!(
+        parse_end_time_input("2x", start, parser_now(), DEFAULT_TIME_ZONE),
+        Err(TimeParseError::InvalidDuration("2x".to_string()))
+    );
 }
 
 #[test]
-fn test_jst_offset_string() {
-    assert_eq!(jst_offset_string(), "JST (UTC+9)");
+fn test_parse_time_input_full_dates() {
+    let parse = |input: &str| parse_time_input(input, Utc::now(), Tokyo).ok();
+    assert_eq!(
+        parse("2030/06/01 09:00"),
+        local_ymd_hm_to_utc(2030, 6, 1, 9, 0, Tokyo)
+    );
+    assert_eq!(
+        parse("2030-06-01 09:00"),
+        local_ymd_hm_to_utc(2030, 6, 1, 9, 0, Tokyo)
+    );
+    assert!(parse("not a time").is_none());
+}
+
+#[test]
+fn test_parse_time_zone() {
+    assert_eq!(
+        parse_time_zone("Europe/Paris"),
+        Some(chrono_tz::Europe::Paris)
+    );
+    assert_eq!(parse_time_zone(" UTC "), Some(chrono_tz::UTC));
+    assert_eq!(parse_time_zone("Mars/Olympus"), None);
+    assert_eq!(
+        time_zone_or_default(Some("Mars/Olympus")),
+        DEFAULT_TIME_ZONE
+    );
+    assert_eq!(time_zone_or_default(None), DEFAULT_TIME_ZONE);
+    for (name, _) in COMMON_TIME_ZONES {
+        assert!(parse_time_zone(name).is_some(), "{} should parse", name);
+    }
+}
+
+#[test]
+fn test_local_formatting_in_other_time_zones() {
+    let utc_time = DateTime::parse_from_rfc3339("2025-07-01T23:30:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+
+    let new_york = chrono_tz::America::New_York;
+    assert_eq!(utc_to_local_string(utc_time, new_york), "2025/07/01 19:30");
+    assert_eq!(utc_to_local_string(utc_time, Tokyo), "2025/07/02 08:30");
+    assert_eq!(utc_to_local_date_string(utc_time, Tokyo), "2025/07/02");
+    assert_eq!(utc_to_local_time_string(utc_time, new_york), "19:30");
+    assert_eq!(
+        local_date(utc_time, new_york),
+        NaiveDate::from_ymd_opt(2025, 7, 1).unwrap()
+    );
+}
+
+#[test]
+fn test_offset_string() {
+    let winter = Utc.with_ymd_and_hms(2025, 1, 15, 12, 0, 0).unwrap();
+    let summer = Utc.with_ymd_and_hms(2025, 7, 15, 12, 0, 0).unwrap();
+
+    assert_eq!(offset_string(Tokyo, winter), "JST (UTC+9)");
+    assert_eq!(
+        offset_string(chrono_tz::Europe::Paris, winter),
+        "CET (UTC+1)"
+    );
+    assert_eq!(
+        offset_string(chrono_tz::Europe::Paris, summer),
+        "CEST (UTC+2)"
+    );
+    assert_eq!(
+        offset_string(chrono_tz::Asia::Kolkata, winter),
+        "IST (UTC+5:30)"
+    );
+    assert_eq!(offset_string(chrono_tz::UTC, winter), "UTC+0");
+    assert_eq!(
+        offset_string(chrono_tz::America::Sao_Paulo, winter),
+        "UTC-3"
+    );
+}
+
+/// Clocks in New York go from 02:00 to 03:00 on 2025-03-09 and from 02:00 back to 01:00
+/// on 2025-11-02
+#[test]
+fn test_local_to_utc_across_dst_changes() {
+    let new_york = chrono_tz::America::New_York;
+    let local = |month, day, hour, minute| {
+        NaiveDate::from_ymd_opt(2025, month, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    };
+
+    assert_eq!(
+        local_to_utc(local(3, 9, 1, 30), new_york),
+        Ok(Utc.with_ymd_and_hms(2025, 3, 9, 6, 30, 0).unwrap())
+    );
+    assert_eq!(
+        local_to_utc(local(3, 9, 2, 30), new_york),
+        Err(TimeParseError::NonexistentTime(local(3, 9, 2, 30)))
+    );
+    assert_eq!(
+        local_to_utc(local(3, 9, 3, 30), new_york),
+        Ok(Utc.with_ymd_and_hms(2025, 3, 9, 7, 30, 0).unwrap())
+    );
+    // The repeated hour resolves to its first occurrence, still on daylight time
+    assert_eq!(
+        local_to_utc(local(11, 2, 1, 30), new_york),
+        Ok(Utc.with_ymd_and_hms(2025, 11, 2, 5, 30, 0).unwrap())
+    );
+}
+
+#[test]
+fn test_parse_time_input_in_guild_time_zone() {
+    let new_york = chrono_tz::America::New_York;
+    // 2025-03-08 12:00 in New York
+    let now = Utc.with_ymd_and_hms(2025, 3, 8, 17, 0, 0).unwrap();
+
+    assert_eq!(
+        parse_time_input("tomorrow 9:00", now, new_york),
+        Ok(Utc.with_ymd_and_hms(2025, 3, 9, 13, 0, 0).unwrap())
+    );
+    assert_eq!(
+        parse_time_input("18:00", now, new_york),
+        Ok(Utc.with_ymd_and_hms(2025, 3, 8, 23, 0, 0).unwrap())
+    );
+
+    let err = parse_time_input("2025-03-09 02:30", now, new_york).unwrap_err();
+    assert!(matches!(err, TimeParseError::NonexistentTime(_)));
+    assert!(err.message(Language::En).contains("daylight saving time"));
+
+    let start = Utc.with_ymd_and_hms(2025, 3, 9, 6, 0, 0).unwrap();
+    assert_eq!(
+        parse_end_time_input("2h", start, now, new_york),
+        Ok(start + chrono::Duration::hours(2))
+    );
 }
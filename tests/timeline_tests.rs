@@ -0,0 +1,288 @@
+use std::io::Read;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use flate2::read::ZlibDecoder;
+use oucc_kizai_bot::buffers::TurnaroundBuffer;
+use oucc_kizai_bot::models::Reservation;
+use oucc_kizai_bot::operating_hours::{parse_weekly_hours, ClosedDate, OperatingSchedule};
+use oucc_kizai_bot::time::{local_ymd_hm_to_utc, DEFAULT_TIME_ZONE};
+use oucc_kizai_bot::timeline::{Canvas, SpanKind, Timeline, TimelineService, PNG_SIGNATURE};
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn jst(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+    local_ymd_hm_to_utc(2025, 1, day, hour, minute, DEFAULT_TIME_ZONE).unwrap()
+}
+
+fn reservation(id: i64, start_time: DateTime<Utc>, end_time: DateTime<Utc>) -> Reservation {
+    Reservation {
+        id,
+        equipment_id: 1,
+        user_id: 111,
+        start_time,
+        end_time,
+        location: None,
+        status: "Confirmed".to_string(),
+        created_at: start_time,
+        updated_at: start_time,
+        returned_at: None,
+        return_location: None,
+    }
+}
+
+/// Weekdays 09:00-21:00, closed on weekends and on Wednesday 2025-01-08
+fn schedule() -> OperatingSchedule {
+    OperatingSchedule {
+        weekly_hours: parse_weekly_hours("Mon-Fri 09:00-21:00").unwrap(),
+        closed_dates: vec![ClosedDate {
+            date: date(2025, 1, 8),
+            reason: None,
+        }],
+        time_zone: DEFAULT_TIME_ZONE,
+    }
+}
+
+/// Week from Monday 2025-01-06, seen on Tuesday at 14:00 while the second loan is out
+fn sample_week() -> Timeline {
+    let reservations = vec![
+        reservation(1, jst(6, 10, 0), jst(6, 12, 0)),
+        reservation(2, jst(7, 13, 0), jst(7, 16, 0)),
+        reservation(3, jst(9, 18, 0), jst(10, 10, 0)),
+    ];
+    Timeline::equipment_week(
+        &schedule(),
+        &TurnaroundBuffer::new(30, 30),
+        &reservations,
+        date(2025, 1, 6),
+        jst(7, 14, 0),
+    )
+}
+
+fn sample_tag_week() -> Timeline {
+    let equipment = vec![
+        (
+            TurnaroundBuffer::new(0, 60),
+            vec![reservation(1, jst(6, 10, 0), jst(7, 18, 0))],
+        ),
+        (TurnaroundBuffer::default(), Vec::new()),
+        (
+            TurnaroundBuffer::default(),
+            vec![
+                reservation(2, jst(7, 9, 0), jst(7, 21, 0)),
+                reservation(3, jst(10, 12, 0), jst(12, 12, 0)),
+            ],
+        ),
+    ];
+    Timeline::tag_week(&schedule(), &equipment, date(2025, 1, 6), jst(7, 14, 0))
+}
+
+/// Read back a PNG written by `Canvas::to_png`, checking every chunk's CRC
+fn decode_png(png: &[u8]) -> Canvas {
+    assert_eq!(png[..8], PNG_SIGNATURE);
+    let (mut width, mut height, mut data) = (0, 0, Vec::new());
+    let mut pos = 8;
+    while pos < png.len() {
+        let len = u32::from_be_bytes(png[pos..pos + 4].try_into().unwrap()) as usize;
+        let kind = &png[pos + 4..pos + 8];
+        let body = &png[pos + 8..pos + 8 + len];
+        let crc = u32::from_be_bytes(png[pos + 8 + len..pos + 12 + len].try_into().unwrap());
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(kind);
+        hasher.update(body);
+        assert_eq!(hasher.finalize(), crc, "bad CRC in {:?}", kind);
+        match kind {
+            b"IHDR" => {
+                width = u32::from_be_bytes(body[0..4].try_into().unwrap());
+                height = u32::from_be_bytes(body[4..8].try_into().unwrap());
+                assert_eq!(body[8..], [8, 2, 0, 0, 0]);
+            }
+            b"IDAT" => data.extend_from_slice(body),
+            _ => {}
+        }
+        pos += 12 + len;
+    }
+
+    let mut raw = Vec::new();
+    ZlibDecoder::new(&data[..]).read_to_end(&mut raw).unwrap();
+    let pixels = raw
+        .chunks(width as usize * 3 + 1)
+        .flat_map(|line| {
+            assert_eq!(line[0], 0, "unexpected filter type");
+            line[1..].to_vec()
+        })
+        .collect();
+    Canvas::from_pixels(width, height, pixels).unwrap()
+}
+
+/// Compare with the image in tests/golden; `UPDATE_GOLDEN=1` rewrites it instead
+fn assert_golden(name: &str, canvas: &Canvas) -> Result<()> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(name);
+    if std::env::var("UPDATE_GOLDEN").is_ok_and(|value| value == "1") {
+        std::fs::write(&path, canvas.to_png()?)?;
+        return Ok(());
+    }
+    let golden = decode_png(&std::fs::read(&path)?);
+    assert!(
+        golden == *canvas,
+        "{} differs from the rendered timeline; rerun with UPDATE_GOLDEN=1 if intended",
+        name
+    );
+    Ok(())
+}
+
+/// Test closed stretches of a day from opening hours and closed dates
+#[test]
+fn test_closed_periods() {
+    let schedule = schedule();
+    assert_eq!(
+        schedule.closed_periods(date(2025, 1, 6)),
+        vec![(jst(6, 0, 0), jst(6, 9, 0)), (jst(6, 21, 0), jst(7, 0, 0))]
+    );
+    assert_eq!(
+        schedule.closed_periods(date(2025, 1, 8)),
+        vec![(jst(8, 0, 0), jst(9, 0, 0))]
+    );
+    assert_eq!(
+        schedule.closed_periods(date(2025, 1, 11)),
+        vec![(jst(11, 0, 0), jst(12, 0, 0))]
+    );
+    assert!(OperatingSchedule::default()
+        .closed_periods(date(2025, 1, 6))
+        .is_empty());
+}
+
+/// Test the rows and spans of an equipment's week
+#[test]
+fn test_equipment_week_spans() {
+    let timeline = sample_week();
+    assert_eq!(timeline.rows.len(), 7);
+    assert_eq!(timeline.rows[0].label, "01/06");
+    assert_eq!(timeline.rows[6].label, "01/12");
+    assert_eq!(timeline.rows[1].start, jst(7, 0, 0));
+    assert_eq!(timeline.rows[1].end, jst(8, 0, 0));
+    assert_eq!(timeline.ticks.len(), 8);
+    assert_eq!(timeline.ticks[3], (9 * 60, "9".to_string()));
+
+    let kinds = |row: usize| {
+        timeline.rows[row]
+            .spans
+            .iter()
+            .map(|span| span.kind)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        kinds(0),
+        vec![
+            SpanKind::Closed,
+            SpanKind::Closed,
+            SpanKind::Buffer,
+            SpanKind::Reserved
+        ]
+    );
+    // The loan running at 14:00 on Tuesday is in use
+    assert!(kinds(1).contains(&SpanKind::InUse));
+    assert_eq!(kinds(2), vec![SpanKind::Closed]);
+    // The overnight reservation shows on both days
+    assert!(kinds(3).contains(&SpanKind::Reserved));
+    assert!(kinds(4).contains(&SpanKind::Reserved));
+    assert_eq!(kinds(5), vec![SpanKind::Closed]);
+
+    let buffer = &timeline.rows[0].spans[2];
+    assert_eq!((buffer.start, buffer.end), (jst(6, 9, 30), jst(6, 12, 30)));
+}
+
+/// Test that PNG output decodes back to the same pixels
+#[test]
+fn test_png_round_trip() -> Result<()> {
+    let mut canvas = Canvas::new(9, 7, [255, 255, 255]);
+    canvas.fill_rect(2, 1, 4, 3, [88, 101, 242]);
+    canvas.fill_rect(7, 5, 10, 10, [237, 66, 69]);
+    canvas.draw_text(0, 0, "1", [0, 0, 0]);
+
+    let png = canvas.to_png()?;
+    let decoded = decode_png(&png);
+    assert_eq!(decoded, canvas);
+    assert_eq!(decoded.pixel(5, 2), Some([88, 101, 242]));
+    assert_eq!(decoded.pixel(0, 2), Some([0, 0, 0]));
+    assert_eq!(decoded.pixel(8, 6), Some([237, 66, 69]));
+    assert_eq!(decoded.pixel(9, 0), None);
+    Ok(())
+}
+
+/// Test the rendered images against the golden files
+#[test]
+fn test_timeline_golden_images() -> Result<()> {
+    let week = sample_week().render();
+    assert_eq!((week.width, week.height), (720, 20 + 7 * 22 + 6));
+    assert_golden("timeline_equipment_week.png", &week)?;
+
+    let tag_week = sample_tag_week().render();
+    assert_eq!((tag_week.width, tag_week.height), (720, 20 + 3 * 22 + 6));
+    assert_golden("timeline_tag_week.png", &tag_week)?;
+    Ok(())
+}
+
+/// Test loading an equipment's week from the database
+#[tokio::test]
+async fn test_timeline_service() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let now = ctx.clock.now_utc();
+
+    common::ReservationBuilder::new(
+        equipment.id,
+        111,
+        now + Duration::hours(1),
+        now + Duration::hours(2),
+    )
+    .build(&ctx.db)
+    .await?;
+    common::ReservationBuilder::new(
+        equipment.id,
+        222,
+        now + Duration::hours(3),
+        now + Duration::hours(4),
+    )
+    .with_status("Cancelled")
+    .build(&ctx.db)
+    .await?;
+    common::ReservationBuilder::new(
+        equipment.id,
+        333,
+        now + Duration::days(10),
+        now + Duration::days(11),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let service = TimelineService::new(ctx.db.clone());
+    let timeline = service.equipment_week(guild.id, equipment.id, now).await?;
+    assert_eq!(timeline.rows.len(), 7);
+    let reserved: Vec<_> = timeline
+        .rows
+        .iter()
+        .flat_map(|row| row.spans.iter())
+        .filter(|span| span.kind == SpanKind::Reserved)
+        .collect();
+    assert!(!reserved.is_empty());
+    assert!(reserved
+        .iter()
+        .all(|span| span.start == now + Duration::hours(1)));
+
+    let tag_week = service
+        .tag_week(guild.id, &[equipment.id, equipment.id], now)
+        .await?;
+    assert_eq!(tag_week.rows.len(), 2);
+    assert_eq!(tag_week.rows[1].label, "2");
+    assert!(tag_week.render().to_png()?.starts_with(&PNG_SIGNATURE));
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use oucc_kizai_bot::i18n::Language;
 use oucc_kizai_bot::transfer_notifications::{TransferNotificationService, TransferNotificationType};
 use oucc_kizai_bot::traits::DiscordApi;
 use serenity::all::{ChannelId, Message, UserId};
@@ -214,37 +215,37 @@ async fn test_notification_message_content() -> Result<()> {
     let approved = TransferNotificationType::Approved {
         equipment_name: "Camera A".to_string(),
     };
-    assert!(approved.dm_message().contains("移譲承認通知"));
-    assert!(approved.dm_message().contains("Camera A"));
+    assert!(approved.dm_message(Language::Ja).contains("移譲承認通知"));
+    assert!(approved.dm_message(Language::Ja).contains("Camera A"));
 
     let denied = TransferNotificationType::Denied {
         equipment_name: "Camera A".to_string(),
         reason: "Unavailable".to_string(),
     };
-    assert!(denied.dm_message().contains("移譲拒否通知"));
-    assert!(denied.dm_message().contains("Camera A"));
-    assert!(denied.dm_message().contains("Unavailable"));
+    assert!(denied.dm_message(Language::Ja).contains("移譲拒否通知"));
+    assert!(denied.dm_message(Language::Ja).contains("Camera A"));
+    assert!(denied.dm_message(Language::Ja).contains("Unavailable"));
 
     let expired = TransferNotificationType::Expired {
         equipment_name: "Camera A".to_string(),
     };
-    assert!(expired.dm_message().contains("移譲期限切れ通知"));
-    assert!(expired.dm_message().contains("Camera A"));
-    assert!(expired.dm_message().contains("3時間"));
+    assert!(expired.dm_message(Language::Ja).contains("移譲期限切れ通知"));
+    assert!(expired.dm_message(Language::Ja).contains("Camera A"));
+    assert!(expired.dm_message(Language::Ja).contains("3時間"));
 
     let request = TransferNotificationType::RequestSent {
         equipment_name: "Camera A".to_string(),
         requester_id: 123,
         reservation_id: 456,
     };
-    assert!(request.dm_message().contains("予約移譲依頼"));
-    assert!(request.dm_message().contains("Camera A"));
+    assert!(request.dm_message(Language::Ja).contains("予約移譲依頼"));
+    assert!(request.dm_message(Language::Ja).contains("Camera A"));
 
     // Test fallback messages don't contain sensitive info
-    assert!(!request.fallback_message(456).contains("期間"));
-    assert!(!request.fallback_message(456).contains("場所"));
-    assert!(request.fallback_message(456).contains("Camera A"));
-    assert!(request.fallback_message(456).contains("予約ID: #456"));
+    assert!(!request.fallback_message(Language::Ja, 456).contains("期間"));
+    assert!(!request.fallback_message(Language::Ja, 456).contains("場所"));
+    assert!(request.fallback_message(Language::Ja, 456).contains("Camera A"));
+    assert!(request.fallback_message(Language::Ja, 456).contains("予約ID: #456"));
 
     Ok(())
 }
\ No newline at end of file
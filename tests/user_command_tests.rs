@@ -0,0 +1,255 @@
+use anyhow::Result;
+use chrono::Duration;
+use oucc_kizai_bot::commands::{TransferToUserCommand, ViewLoansCommand};
+use oucc_kizai_bot::equipment::EquipmentRenderer;
+use oucc_kizai_bot::i18n::{t, Language};
+use oucc_kizai_bot::reservations::ReservationService;
+use oucc_kizai_bot::returns::ReturnService;
+use oucc_kizai_bot::traits::Clock;
+
+mod common;
+
+/// Test that /return only offers the member's own started, unreturned loans
+#[tokio::test]
+async fn test_user_active_loans() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ReturnService::new(ctx.db.clone());
+    let now = ctx.clock.now_utc();
+
+    let projector = common::EquipmentBuilder::new(guild.id, "Projector")
+        .with_tag(tag.id)
+        .build(&ctx.db)
+        .await?;
+
+    let overdue = common::ReservationBuilder::new(
+        projector.id,
+        111,
+        now - Duration::hours(5),
+        now - Duration::hours(1),
+    )
+    .build(&ctx.db)
+    .await?;
+    let active = common::ReservationBuilder::new(
+        equipment.id,
+        111,
+        now - Duration::hours(1),
+        now + Duration::hours(2),
+    )
+    .build(&ctx.db)
+    .await?;
+    // Not started yet
+    common::ReservationBuilder::new(
+        equipment.id,
+        111,
+        now + Duration::hours(3),
+        now + Duration::hours(4),
+    )
+    .build(&ctx.db)
+    .await?;
+    // Someone else's loan
+    common::ReservationBuilder::new(
+        projector.id,
+        222,
+        now - Duration::minutes(30),
+        now + Duration::hours(1),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let loans = service.user_active_loans(guild.id, 111, now).await?;
+    assert_eq!(
+        loans
+            .iter()
+            .map(|loan| loan.reservation_id)
+            .collect::<Vec<_>>(),
+        vec![overdue.id, active.id]
+    );
+    assert_eq!(loans[0].equipment_name, "Projector");
+
+    sqlx::query!(
+        "UPDATE reservations SET returned_at = ?, return_location = 'Club Room' WHERE id = ?",
+        now,
+        overdue.id
+    )
+    .execute(&ctx.db)
+    .await?;
+    let loans = service.user_active_loans(guild.id, 111, now).await?;
+    assert_eq!(loans.len(), 1);
+    assert_eq!(loans[0].reservation_id, active.id);
+
+    Ok(())
+}
+
+/// Test that /my-reservations lists open reservations, active before upcoming
+#[tokio::test]
+async fn test_open_reservations_for_user() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, equipment) = common::create_test_setup(&ctx).await?;
+    let service = ReservationService::new(ctx.db.clone());
+    let now = ctx.clock.now_utc();
+
+    let upcoming = common::ReservationBuilder::new(
+        equipment.id,
+        111,
+        now + Duration::days(1),
+        now + Duration::days(1) + Duration::hours(2),
+    )
+    .with_location("Studio")
+    .build(&ctx.db)
+    .await?;
+    let active = common::ReservationBuilder::new(
+        equipment.id,
+        111,
+        now - Duration::hours(1),
+        now + Duration::hours(1),
+    )
+    .build(&ctx.db)
+    .await?;
+    common::ReservationBuilder::new(
+        equipment.id,
+        111,
+        now + Duration::days(2),
+        now + Duration::days(2) + Duration::hours(1),
+    )
+    .with_status("Cancelled")
+    .build(&ctx.db)
+    .await?;
+    common::ReservationBuilder::new(
+        equipment.id,
+        222,
+        now + Duration::days(3),
+        now + Duration::days(3) + Duration::hours(1),
+    )
+    .build(&ctx.db)
+    .await?;
+
+    let reservations = service
+        .open_reservations_for_user(guild.id, 111, 10)
+        .await?;
+    assert_eq!(reservations.len(), 2);
+    assert_eq!(reservations[0].reservation_id, active.id);
+    assert_eq!(reservations[0].equipment_id, equipment.id);
+    assert!(reservations[0].is_active(now));
+    assert_eq!(reservations[1].reservation_id, upcoming.id);
+    assert!(!reservations[1].is_active(now));
+    assert_eq!(reservations[1].location.as_deref(), Some("Studio"));
+    assert_eq!(reservations[1].equipment_name, "Sony A7");
+
+    let limited = service.open_reservations_for_user(guild.id, 111, 1).await?;
+    assert_eq!(limited.len(), 1);
+    assert!(service
+        .open_reservations_for_user(guild.id, 333, 10)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+/// Test the equipment search behind /reserve autocomplete
+#[tokio::test]
+async fn test_search_equipment() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, tag, _location, _equipment) = common::create_test_setup(&ctx).await?;
+    let renderer = EquipmentRenderer::new(ctx.db.clone());
+
+    for name in ["Canon EOS", "Sony FX3", "Tripod"] {
+        common::EquipmentBuilder::new(guild.id, name)
+            .with_tag(tag.id)
+            .build(&ctx.db)
+            .await?;
+    }
+    common::GuildBuilder::new(42).build(&ctx.db).await?;
+    common::EquipmentBuilder::new(42, "Sony A7S")
+        .build(&ctx.db)
+        .await?;
+
+    let names = |equipment: Vec<oucc_kizai_bot::models::Equipment>| {
+        equipment.into_iter().map(|e| e.name).collect::<Vec<_>>()
+    };
+
+    // Case-insensitive, earlier matches first, other guilds excluded
+    assert_eq!(
+        names(renderer.search_equipment(guild.id, "sony", 25).await?),
+        vec!["Sony A7", "Sony FX3"]
+    );
+    assert_eq!(
+        names(renderer.search_equipment(guild.id, "o", 25).await?),
+        vec!["Sony A7", "Sony FX3", "Canon EOS", "Tripod"]
+    );
+    assert_eq!(
+        renderer.search_equipment(guild.id, "  ", 25).await?.len(),
+        4
+    );
+    assert_eq!(renderer.search_equipment(guild.id, "", 2).await?.len(), 2);
+    assert!(renderer
+        .search_equipment(guild.id, "drone", 25)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+/// Test the tag and location searches behind /availability and /reserve autocomplete
+#[tokio::test]
+async fn test_search_tags_and_locations() -> Result<()> {
+    let ctx = common::TestContext::new().await?;
+    let (guild, _tag, _location, _equipment) = common::create_test_setup(&ctx).await?;
+    let renderer = EquipmentRenderer::new(ctx.db.clone());
+
+    common::TagBuilder::new(guild.id, "Audio")
+        .with_sort_order(2)
+        .build(&ctx.db)
+        .await?;
+    common::TagBuilder::new(guild.id, "Camera Lens")
+        .with_sort_order(0)
+        .build(&ctx.db)
+        .await?;
+    common::LocationBuilder::new(guild.id, "Storage Room")
+        .build(&ctx.db)
+        .await?;
+    common::GuildBuilder::new(42).build(&ctx.db).await?;
+    common::TagBuilder::new(42, "Cameras")
+        .build(&ctx.db)
+        .await?;
+
+    // Tags follow their display order
+    let tags: Vec<String> = renderer
+        .search_tags(guild.id, "CAMERA", 25)
+        .await?
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect();
+    assert_eq!(tags, vec!["Camera Lens", "Camera"]);
+    assert_eq!(renderer.search_tags(guild.id, "", 25).await?.len(), 3);
+    assert_eq!(renderer.search_tags(guild.id, "", 1).await?.len(), 1);
+
+    // Locations put earlier matches first
+    let locations: Vec<String> = renderer
+        .search_locations(guild.id, "room", 25)
+        .await?
+        .into_iter()
+        .map(|location| location.name)
+        .collect();
+    assert_eq!(locations, vec!["Club Room", "Storage Room"]);
+    assert!(renderer
+        .search_locations(guild.id, "lab", 25)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
+/// Test that context menu names match the catalog and fit Discord's 32 character limit
+#[test]
+fn test_context_menu_names() {
+    for (name, key) in [
+        (ViewLoansCommand::NAME, "view_loans_cmd.name"),
+        (TransferToUserCommand::NAME, "transfer_to_user_cmd.name"),
+    ] {
+        assert_eq!(t(Language::En, key), name);
+        for lang in Language::ALL {
+            assert!(t(lang, key).chars().count() <= 32, "{} too long", key);
+        }
+    }
+}